@@ -0,0 +1,26 @@
+//! Vendor extension settings for home miners.
+//!
+//! Consumer units such as the Avalon Q and Nano ship with a front screen that
+//! has no counterpart on datacenter hardware. Every field is optional: a
+//! backend reads and writes only the settings its firmware exposes, and `None`
+//! leaves a setting as is.
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[cfg_attr(
+    feature = "python",
+    pyclass(name = "ApplianceConfig", from_py_object, get_all, module = "asic_rs")
+)]
+#[cfg_attr(
+    feature = "python",
+    asic_rs_pydantic::py_pydantic_model(new, name = "ApplianceConfig")
+)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+/// Appliance settings of a home miner.
+pub struct ApplianceConfig {
+    /// Whether the front screen is switched on.
+    #[cfg_attr(feature = "python", pydantic(default = None))]
+    pub screen_enabled: Option<bool>,
+}
@@ -6,7 +6,7 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
 /// Fan control mode.
 pub enum FanMode {
-    /// Firmware-managed fan control using a target temperature.
+    /// Firmware-managed fan control, optionally towards a target temperature.
     Auto,
     /// Fixed fan speed control.
     Manual,
@@ -17,35 +17,62 @@ pub enum FanMode {
 #[serde(tag = "mode", rename_all = "PascalCase")]
 /// Desired fan control configuration.
 ///
-/// Use [`Self::auto`] to target a temperature, or [`Self::manual`] to request a
-/// fixed fan speed.
+/// Use [`Self::auto`] to hand fan control to the firmware, optionally with a
+/// target temperature, or [`Self::manual`] to request a fixed fan speed.
 pub enum FanConfig {
-    /// Automatic fan control with a target chip/board temperature.
+    /// Automatic fan control, optionally with a target chip/board temperature.
     Auto {
-        /// Temperature target in Celsius.
-        target_temp: f64,
+        /// Temperature target in Celsius, or `None` when the firmware picks
+        /// (or does not report) its own target.
+        #[serde(default)]
+        target_temp: Option<f64>,
         /// Optional idle fan speed percentage.
         idle_speed: Option<u64>,
+        /// Minimum number of working fans required to keep mining, or `None`
+        /// to leave the firmware setting alone.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        min_fans: Option<u64>,
     },
     /// Manual fan control with a fixed fan speed percentage.
     Manual {
         /// Fan speed percentage.
         fan_speed: u64,
+        /// Minimum number of working fans required to keep mining, or `None`
+        /// to leave the firmware setting alone.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        min_fans: Option<u64>,
     },
 }
 
 impl FanConfig {
     /// Create an automatic fan configuration.
-    pub fn auto(target_temp: f64, idle_speed: Option<u64>) -> Self {
+    pub fn auto(target_temp: impl Into<Option<f64>>, idle_speed: Option<u64>) -> Self {
         Self::Auto {
-            target_temp,
+            target_temp: target_temp.into(),
             idle_speed,
+            min_fans: None,
         }
     }
 
     /// Create a manual fan configuration.
     pub fn manual(fan_speed: u64) -> Self {
-        Self::Manual { fan_speed }
+        Self::Manual {
+            fan_speed,
+            min_fans: None,
+        }
+    }
+
+    /// Require at least `min_fans` working fans to keep mining.
+    pub fn with_min_fans(mut self, min_fans: u64) -> Self {
+        match &mut self {
+            Self::Auto {
+                min_fans: field, ..
+            }
+            | Self::Manual {
+                min_fans: field, ..
+            } => *field = Some(min_fans),
+        }
+        self
     }
 
     /// Return the selected fan mode.
@@ -59,7 +86,7 @@ impl FanConfig {
     /// Return the automatic target temperature, if this is automatic mode.
     pub fn target_temp(&self) -> Option<f64> {
         match self {
-            Self::Auto { target_temp, .. } => Some(*target_temp),
+            Self::Auto { target_temp, .. } => *target_temp,
             Self::Manual { .. } => None,
         }
     }
@@ -76,9 +103,26 @@ impl FanConfig {
     pub fn fan_speed(&self) -> Option<u64> {
         match self {
             Self::Auto { .. } => None,
-            Self::Manual { fan_speed } => Some(*fan_speed),
+            Self::Manual { fan_speed, .. } => Some(*fan_speed),
         }
     }
+
+    /// Return the minimum number of working fans, if set.
+    pub fn min_fans(&self) -> Option<u64> {
+        match self {
+            Self::Auto { min_fans, .. } | Self::Manual { min_fans, .. } => *min_fans,
+        }
+    }
+
+    /// Fail when a minimum fan count is set, for firmwares that cannot apply
+    /// it and would otherwise drop it silently.
+    pub fn ensure_no_min_fans(&self) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            self.min_fans().is_none(),
+            "A minimum fan count is not supported on this platform"
+        );
+        Ok(())
+    }
 }
 
 #[cfg(feature = "python")]
@@ -86,8 +130,8 @@ impl FanConfig {
 impl FanConfig {
     #[staticmethod]
     #[pyo3(name = "auto")]
-    #[pyo3(signature = (target_temp, idle_speed = None))]
-    fn py_auto(target_temp: f64, idle_speed: Option<u64>) -> Self {
+    #[pyo3(signature = (target_temp = None, idle_speed = None))]
+    fn py_auto(target_temp: Option<f64>, idle_speed: Option<u64>) -> Self {
         Self::auto(target_temp, idle_speed)
     }
 
@@ -97,6 +141,11 @@ impl FanConfig {
         Self::manual(fan_speed)
     }
 
+    #[pyo3(name = "with_min_fans")]
+    fn py_with_min_fans(&self, min_fans: u64) -> Self {
+        self.clone().with_min_fans(min_fans)
+    }
+
     #[getter]
     #[pyo3(name = "mode")]
     fn py_mode(&self) -> &'static str {
@@ -124,18 +173,28 @@ impl FanConfig {
         self.fan_speed()
     }
 
+    #[getter]
+    #[pyo3(name = "min_fans")]
+    fn py_min_fans(&self) -> Option<u64> {
+        self.min_fans()
+    }
+
     fn __repr__(&self) -> String {
-        match self {
+        let repr = match self {
             Self::Auto {
                 target_temp,
                 idle_speed,
-            } => match idle_speed {
-                Some(idle_speed) => {
-                    format!("FanConfig.auto(target_temp={target_temp:?}, idle_speed={idle_speed})")
-                }
-                None => format!("FanConfig.auto(target_temp={target_temp:?}, idle_speed=None)"),
-            },
-            Self::Manual { fan_speed } => format!("FanConfig.manual(fan_speed={fan_speed})"),
+                ..
+            } => {
+                let target_temp = target_temp.map_or("None".to_string(), |t| format!("{t:?}"));
+                let idle_speed = idle_speed.map_or("None".to_string(), |s| s.to_string());
+                format!("FanConfig.auto(target_temp={target_temp}, idle_speed={idle_speed})")
+            }
+            Self::Manual { fan_speed, .. } => format!("FanConfig.manual(fan_speed={fan_speed})"),
+        };
+        match self.min_fans() {
+            Some(min_fans) => format!("{repr}.with_min_fans({min_fans})"),
+            None => repr,
         }
     }
 
@@ -240,9 +299,16 @@ mod python_impls {
             }
             let mode_value = get_required_field(&obj, "mode")?;
             let mode = mode_value.extract::<String>()?;
+            let min_fans: Option<u64> = get_optional_field(&obj, "min_fans")?
+                .map(|value| value.extract())
+                .transpose()?
+                .flatten();
             match mode.to_lowercase().as_str() {
                 "auto" => {
-                    let target_temp: f64 = get_required_field(&obj, "target_temp")?.extract()?;
+                    let target_temp: Option<f64> = get_optional_field(&obj, "target_temp")?
+                        .map(|value| value.extract())
+                        .transpose()?
+                        .flatten();
                     let idle_speed: Option<u64> = get_optional_field(&obj, "idle_speed")?
                         .map(|value| value.extract())
                         .transpose()?
@@ -250,11 +316,15 @@ mod python_impls {
                     Ok(FanConfig::Auto {
                         target_temp,
                         idle_speed,
+                        min_fans,
                     })
                 }
                 "manual" => {
                     let fan_speed: u64 = get_required_field(&obj, "fan_speed")?.extract()?;
-                    Ok(FanConfig::Manual { fan_speed })
+                    Ok(FanConfig::Manual {
+                        fan_speed,
+                        min_fans,
+                    })
                 }
                 _ => Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(format!(
                     "Unknown fan mode '{mode}', expected 'auto' or 'manual'"
@@ -271,17 +341,21 @@ mod python_impls {
             let auto_mode = asic_rs_pydantic::literal_schema(core_schema, &["auto"])?;
             let target_temp = core_schema.call_method0("float_schema")?;
             let idle_speed = core_schema.call_method0("int_schema")?;
+            let min_fans = core_schema.call_method0("int_schema")?;
             let auto_schema = asic_rs_pydantic::pydantic_typed_dict_schema!(core_schema, "asic_rs.FanConfigAuto", {
                 "mode" => required(auto_mode),
-                "target_temp" => required(target_temp),
+                "target_temp" => nullable_if(target_temp, false),
                 "idle_speed" => nullable_if(idle_speed, false),
+                "min_fans" => nullable_if(min_fans, false),
             })?;
 
             let manual_mode = asic_rs_pydantic::literal_schema(core_schema, &["manual"])?;
             let fan_speed = core_schema.call_method0("int_schema")?;
+            let min_fans = core_schema.call_method0("int_schema")?;
             let manual_schema = asic_rs_pydantic::pydantic_typed_dict_schema!(core_schema, "asic_rs.FanConfigManual", {
                 "mode" => required(manual_mode),
                 "fan_speed" => required(fan_speed),
+                "min_fans" => nullable_if(min_fans, false),
             })?;
 
             asic_rs_pydantic::tagged_union_schema(
@@ -302,14 +376,20 @@ mod python_impls {
                 FanConfig::Auto {
                     target_temp,
                     idle_speed,
+                    min_fans,
                 } => {
                     dict.set_item("mode", "auto")?;
                     dict.set_item("target_temp", target_temp)?;
                     dict.set_item("idle_speed", idle_speed)?;
+                    dict.set_item("min_fans", min_fans)?;
                 }
-                FanConfig::Manual { fan_speed } => {
+                FanConfig::Manual {
+                    fan_speed,
+                    min_fans,
+                } => {
                     dict.set_item("mode", "manual")?;
                     dict.set_item("fan_speed", fan_speed)?;
+                    dict.set_item("min_fans", min_fans)?;
                 }
             }
             Ok(dict.into_any().unbind())
@@ -341,6 +421,14 @@ mod tests {
         assert_eq!(config.fan_speed(), None);
     }
 
+    #[test]
+    fn auto_mode_allows_firmware_target() {
+        let config = FanConfig::auto(None, None);
+
+        assert_eq!(config.mode(), FanMode::Auto);
+        assert_eq!(config.target_temp(), None);
+    }
+
     #[test]
     fn manual_mode_has_fan_speed_and_no_auto_fields() {
         let config = FanConfig::manual(75);
@@ -350,4 +438,16 @@ mod tests {
         assert_eq!(config.idle_speed(), None);
         assert_eq!(config.fan_speed(), Some(75));
     }
+
+    #[test]
+    fn min_fans_applies_to_both_modes() {
+        let auto = FanConfig::auto(60.0, None).with_min_fans(2);
+        assert_eq!(auto.min_fans(), Some(2));
+        assert!(auto.ensure_no_min_fans().is_err());
+
+        let manual = FanConfig::manual(75);
+        assert_eq!(manual.min_fans(), None);
+        assert!(manual.ensure_no_min_fans().is_ok());
+        assert_eq!(manual.with_min_fans(1).min_fans(), Some(1));
+    }
 }
@@ -1,6 +1,9 @@
+pub mod appliance;
 pub mod collector;
 pub mod fan;
+pub mod network;
 pub mod pools;
 pub mod scaling;
+pub mod syslog;
 pub mod temperature;
 pub mod tuning;
@@ -0,0 +1,240 @@
+use std::net::{IpAddr, Ipv4Addr};
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+
+#[cfg_attr(
+    feature = "python",
+    pyclass(name = "NetworkConfig", from_py_object, get_all, module = "asic_rs")
+)]
+#[cfg_attr(
+    feature = "python",
+    asic_rs_pydantic::py_pydantic_model(new, name = "NetworkConfig")
+)]
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+/// Network configuration of the miner's management interface.
+///
+/// With `dhcp` set the address fields describe the current lease when read and
+/// are ignored when written. Otherwise they are the static configuration.
+pub struct NetworkConfig {
+    /// Whether the address is obtained via DHCP.
+    pub dhcp: bool,
+    /// IP address of the miner.
+    #[cfg_attr(feature = "python", pydantic(default = None))]
+    pub ip: Option<IpAddr>,
+    /// Subnet mask, e.g. `255.255.255.0`.
+    #[cfg_attr(feature = "python", pydantic(default = None))]
+    pub netmask: Option<IpAddr>,
+    /// Default gateway.
+    #[cfg_attr(feature = "python", pydantic(default = None))]
+    pub gateway: Option<IpAddr>,
+    /// DNS servers, in order of preference.
+    #[cfg_attr(feature = "python", pydantic(default = None))]
+    pub dns: Option<Vec<IpAddr>>,
+    /// Hostname of the miner.
+    #[cfg_attr(feature = "python", pydantic(default = None))]
+    pub hostname: Option<String>,
+}
+
+impl NetworkConfig {
+    /// Create a DHCP network configuration.
+    pub fn dhcp() -> Self {
+        Self {
+            dhcp: true,
+            ..Default::default()
+        }
+    }
+
+    /// Create a static network configuration.
+    pub fn static_ip(ip: IpAddr, netmask: IpAddr, gateway: IpAddr, dns: Vec<IpAddr>) -> Self {
+        Self {
+            dhcp: false,
+            ip: Some(ip),
+            netmask: Some(netmask),
+            gateway: Some(gateway),
+            dns: Some(dns),
+            hostname: None,
+        }
+    }
+
+    /// Set the hostname.
+    pub fn with_hostname(mut self, hostname: impl Into<String>) -> Self {
+        self.hostname = Some(hostname.into());
+        self
+    }
+
+    /// Prefix length of the netmask, e.g. `24` for `255.255.255.0`.
+    ///
+    /// Returns `None` when there is no netmask or it is not contiguous.
+    pub fn prefix_len(&self) -> Option<u8> {
+        let IpAddr::V4(mask) = self.netmask? else {
+            return None;
+        };
+        let bits = u32::from(mask);
+        (bits.leading_ones() + bits.trailing_zeros() == 32).then_some(bits.leading_ones() as u8)
+    }
+
+    /// Netmask for a prefix length, for firmwares that report CIDR notation.
+    pub fn netmask_from_prefix(prefix: u8) -> Option<IpAddr> {
+        let bits = u32::MAX
+            .checked_shl(32 - u32::from(prefix.min(32)))
+            .unwrap_or(0);
+        (prefix <= 32).then(|| IpAddr::V4(Ipv4Addr::from(bits)))
+    }
+
+    /// Check that this configuration is safe to apply to the miner currently
+    /// reachable at `current_ip`.
+    ///
+    /// A static configuration must be complete and self-consistent: a usable
+    /// IPv4 address, a contiguous netmask, a gateway inside the subnet and at
+    /// least one DNS server. Anything less would leave the miner unreachable
+    /// with no way to fix it remotely. Moving the miner to a different address,
+    /// including switching it to DHCP, is rejected unless `allow_readdress` is
+    /// set, since it may no longer answer at `current_ip`.
+    pub fn validate(&self, current_ip: IpAddr, allow_readdress: bool) -> anyhow::Result<()> {
+        if let Some(hostname) = &self.hostname {
+            anyhow::ensure!(
+                !hostname.is_empty()
+                    && hostname.len() <= 63
+                    && !hostname.starts_with('-')
+                    && !hostname.ends_with('-')
+                    && hostname
+                        .chars()
+                        .all(|c| c.is_ascii_alphanumeric() || c == '-'),
+                "Invalid hostname {hostname:?}"
+            );
+        }
+
+        if self.dhcp {
+            anyhow::ensure!(
+                allow_readdress,
+                "DHCP may move the miner away from {current_ip}; \
+                 use set_network_config_readdress to allow this"
+            );
+            return Ok(());
+        }
+
+        let (Some(ip), Some(netmask), Some(gateway)) = (self.ip, self.netmask, self.gateway) else {
+            anyhow::bail!("Static network config requires an IP address, netmask and gateway");
+        };
+        let (IpAddr::V4(ip), IpAddr::V4(gateway)) = (ip, gateway) else {
+            anyhow::bail!("Only IPv4 static addressing is supported");
+        };
+        let prefix = self
+            .prefix_len()
+            .filter(|prefix| (1..=30).contains(prefix))
+            .ok_or_else(|| anyhow::anyhow!("Invalid netmask {netmask}"))?;
+
+        anyhow::ensure!(
+            !(ip.is_unspecified() || ip.is_loopback() || ip.is_multicast() || ip.is_broadcast()),
+            "{ip} is not a usable host address"
+        );
+
+        let mask = u32::MAX << (32 - prefix);
+        let network = u32::from(ip) & mask;
+        let host = u32::from(ip) & !mask;
+        anyhow::ensure!(
+            host != 0 && host != !mask,
+            "{ip} is the network or broadcast address of its subnet"
+        );
+        anyhow::ensure!(
+            u32::from(gateway) & mask == network && gateway != ip,
+            "Gateway {gateway} is not a separate host in the subnet of {ip}/{prefix}"
+        );
+        anyhow::ensure!(
+            self.dns.as_ref().is_some_and(|dns| !dns.is_empty()),
+            "Static network config requires at least one DNS server"
+        );
+
+        anyhow::ensure!(
+            allow_readdress || IpAddr::V4(ip) == current_ip,
+            "Network config moves the miner from {current_ip} to {ip}; \
+             use set_network_config_readdress to allow this"
+        );
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ip(s: &str) -> IpAddr {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn prefix_len_round_trips() {
+        for prefix in [8, 16, 24, 30] {
+            let config = NetworkConfig {
+                netmask: NetworkConfig::netmask_from_prefix(prefix),
+                ..Default::default()
+            };
+            assert_eq!(config.prefix_len(), Some(prefix));
+        }
+
+        let config = NetworkConfig {
+            netmask: Some(ip("255.0.255.0")),
+            ..Default::default()
+        };
+        assert_eq!(config.prefix_len(), None);
+    }
+
+    #[test]
+    fn validate_rejects_configs_that_orphan_the_miner() {
+        let current = ip("10.0.0.20");
+        let valid = NetworkConfig::static_ip(
+            ip("10.0.0.20"),
+            ip("255.255.255.0"),
+            ip("10.0.0.1"),
+            vec![ip("1.1.1.1")],
+        );
+        assert!(valid.validate(current, false).is_ok());
+        assert!(NetworkConfig::dhcp().validate(current, false).is_err());
+        assert!(NetworkConfig::dhcp().validate(current, true).is_ok());
+
+        let missing_gateway = NetworkConfig {
+            gateway: None,
+            ..valid.clone()
+        };
+        assert!(missing_gateway.validate(current, false).is_err());
+
+        let foreign_gateway = NetworkConfig {
+            gateway: Some(ip("10.0.1.1")),
+            ..valid.clone()
+        };
+        assert!(foreign_gateway.validate(current, false).is_err());
+
+        let broadcast = NetworkConfig {
+            ip: Some(ip("10.0.0.255")),
+            ..valid.clone()
+        };
+        assert!(broadcast.validate(current, false).is_err());
+
+        let no_dns = NetworkConfig {
+            dns: Some(vec![]),
+            ..valid.clone()
+        };
+        assert!(no_dns.validate(current, false).is_err());
+
+        let bad_hostname = valid.with_hostname("miner_01");
+        assert!(bad_hostname.validate(current, false).is_err());
+    }
+
+    #[test]
+    fn validate_requires_opt_in_to_readdress() {
+        let current = ip("10.0.0.20");
+        let moved = NetworkConfig::static_ip(
+            ip("10.0.0.21"),
+            ip("255.255.255.0"),
+            ip("10.0.0.1"),
+            vec![ip("1.1.1.1")],
+        );
+        assert!(moved.validate(current, false).is_err());
+        assert!(moved.validate(current, true).is_ok());
+        assert!(NetworkConfig::dhcp().validate(current, false).is_err());
+        assert!(NetworkConfig::dhcp().validate(current, true).is_ok());
+    }
+}
@@ -0,0 +1,64 @@
+use std::net::IpAddr;
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+use strum::{Display, EnumString};
+
+#[cfg_attr(feature = "python", pyclass(from_py_object, str, module = "asic_rs"))]
+#[cfg_attr(feature = "python", derive(asic_rs_pydantic::PyPydanticEnum))]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Display, EnumString)]
+/// Transport used to forward syslog messages.
+pub enum SyslogProtocol {
+    /// Datagrams, one message per packet.
+    #[cfg_attr(feature = "python", pydantic(value = "Udp"))]
+    Udp,
+    /// A stream of newline-terminated messages.
+    #[cfg_attr(feature = "python", pydantic(value = "Tcp"))]
+    Tcp,
+}
+
+#[cfg_attr(
+    feature = "python",
+    pyclass(name = "SyslogTarget", from_py_object, get_all, module = "asic_rs")
+)]
+#[cfg_attr(
+    feature = "python",
+    asic_rs_pydantic::py_pydantic_model(new, name = "SyslogTarget")
+)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// Remote syslog receiver the miner forwards its system log to.
+pub struct SyslogTarget {
+    /// Address of the syslog receiver.
+    pub host: IpAddr,
+    /// Port of the syslog receiver, usually `514`.
+    pub port: u16,
+    /// Transport to forward messages over.
+    pub protocol: SyslogProtocol,
+}
+
+impl SyslogTarget {
+    /// Forward to `host` over UDP on the standard port.
+    pub fn udp(host: IpAddr) -> Self {
+        Self {
+            host,
+            port: 514,
+            protocol: SyslogProtocol::Udp,
+        }
+    }
+
+    /// Forward to `host` over TCP on the standard port.
+    pub fn tcp(host: IpAddr) -> Self {
+        Self {
+            host,
+            port: 514,
+            protocol: SyslogProtocol::Tcp,
+        }
+    }
+
+    /// Set the port of the receiver.
+    pub fn with_port(mut self, port: u16) -> Self {
+        self.port = port;
+        self
+    }
+}
@@ -1,3 +1,4 @@
+use measurements::{Frequency, Voltage};
 #[cfg(feature = "python")]
 use pyo3::prelude::*;
 use serde::{Deserialize, Serialize};
@@ -11,13 +12,24 @@ use crate::data::miner::TuningTarget;
 ///
 /// A tuning config can target a power limit, a hashrate, or a named mining
 /// mode. The optional algorithm field lets firmwares distinguish tuning
-/// profiles when they support more than one algorithm.
+/// profiles when they support more than one algorithm, and the optional
+/// voltage and frequency set the chip core voltage and clock on firmwares
+/// that expose them (AxeOS and VNish); every other firmware rejects a config
+/// that carries them.
 pub struct TuningConfig {
     /// Tuning target requested from the firmware.
     pub target: TuningTarget,
     /// Optional firmware-specific tuning algorithm/profile.
     #[cfg_attr(feature = "python", pydantic(default = None))]
     pub algorithm: Option<String>,
+    /// Optional chip core voltage set point.
+    #[serde(default)]
+    #[cfg_attr(feature = "python", pydantic(default = None))]
+    pub voltage: Option<Voltage>,
+    /// Optional chip frequency set point.
+    #[serde(default)]
+    #[cfg_attr(feature = "python", pydantic(default = None))]
+    pub frequency: Option<Frequency>,
 }
 
 impl TuningConfig {
@@ -26,6 +38,8 @@ impl TuningConfig {
         Self {
             target,
             algorithm: None,
+            voltage: None,
+            frequency: None,
         }
     }
 
@@ -35,6 +49,28 @@ impl TuningConfig {
         self
     }
 
+    /// Attach a chip core voltage set point.
+    pub fn with_voltage(mut self, voltage: Voltage) -> Self {
+        self.voltage = Some(voltage);
+        self
+    }
+
+    /// Attach a chip frequency set point.
+    pub fn with_frequency(mut self, frequency: Frequency) -> Self {
+        self.frequency = Some(frequency);
+        self
+    }
+
+    /// Fail when a chip voltage or frequency is set, for firmwares that can
+    /// only apply the tuning target and would otherwise drop them silently.
+    pub fn ensure_target_only(&self) -> anyhow::Result<()> {
+        anyhow::ensure!(
+            self.voltage.is_none() && self.frequency.is_none(),
+            "Chip voltage and frequency set points are not supported on this platform"
+        );
+        Ok(())
+    }
+
     /// Return `"power"`, `"hashrate"`, or `"mode"` for this config target.
     pub fn variant(&self) -> &'static str {
         match &self.target {
@@ -77,30 +113,38 @@ impl TuningConfig {
 #[pymethods]
 impl TuningConfig {
     #[classmethod]
-    #[pyo3(signature = (watts, algorithm = None))]
+    #[pyo3(signature = (watts, algorithm = None, voltage = None, frequency = None))]
     fn power(
         _cls: &Bound<'_, pyo3::types::PyType>,
         watts: f64,
         algorithm: Option<&Bound<'_, PyAny>>,
+        voltage: Option<f64>,
+        frequency: Option<f64>,
     ) -> PyResult<Self> {
         let mut config = Self::new(TuningTarget::Power(measurements::Power::from_watts(watts)));
         if let Some(algorithm) = algorithm {
             config.algorithm = Some(asic_rs_pydantic::py_to_string(algorithm)?);
         }
+        config.voltage = voltage.map(Voltage::from_volts);
+        config.frequency = frequency.map(Frequency::from_megahertz);
         Ok(config)
     }
 
     #[classmethod]
-    #[pyo3(signature = (hashrate, algorithm = None))]
+    #[pyo3(signature = (hashrate, algorithm = None, voltage = None, frequency = None))]
     fn hashrate(
         _cls: &Bound<'_, pyo3::types::PyType>,
         hashrate: crate::data::hashrate::HashRate,
         algorithm: Option<&Bound<'_, PyAny>>,
+        voltage: Option<f64>,
+        frequency: Option<f64>,
     ) -> PyResult<Self> {
         let mut config = Self::new(TuningTarget::HashRate(hashrate));
         if let Some(algorithm) = algorithm {
             config.algorithm = Some(asic_rs_pydantic::py_to_string(algorithm)?);
         }
+        config.voltage = voltage.map(Voltage::from_volts);
+        config.frequency = frequency.map(Frequency::from_megahertz);
         Ok(config)
     }
 
@@ -141,12 +185,26 @@ impl TuningConfig {
     fn py_algorithm(&self) -> Option<&str> {
         self.algorithm()
     }
+
+    /// Chip core voltage set point in volts, if any.
+    #[getter]
+    #[pyo3(name = "voltage")]
+    fn py_voltage(&self) -> Option<f64> {
+        self.voltage.map(|v| v.as_volts())
+    }
+
+    /// Chip frequency set point in MHz, if any.
+    #[getter]
+    #[pyo3(name = "frequency")]
+    fn py_frequency(&self) -> Option<f64> {
+        self.frequency.map(|f| f.as_megahertz())
+    }
 }
 
 #[cfg(feature = "python")]
 mod python_impls {
     use asic_rs_pydantic::{PyPydanticType, get_optional_field, get_required_field};
-    use measurements::Power;
+    use measurements::{Frequency, Power, Voltage};
     use pyo3::{Borrowed, PyAny, PyErr, PyResult, conversion::FromPyObject, types::PyAnyMethods};
 
     use super::TuningConfig;
@@ -166,6 +224,8 @@ mod python_impls {
                         .map(|value| value.extract())
                         .transpose()?
                         .flatten(),
+                    voltage: extract_voltage(&obj)?,
+                    frequency: extract_frequency(&obj)?,
                 });
             }
 
@@ -207,7 +267,50 @@ mod python_impls {
                 }
             };
 
-            Ok(TuningConfig { target, algorithm })
+            Ok(TuningConfig {
+                target,
+                algorithm,
+                voltage: extract_voltage(&obj)?,
+                frequency: extract_frequency(&obj)?,
+            })
         }
     }
+
+    fn extract_voltage(obj: &Borrowed<'_, '_, PyAny>) -> PyResult<Option<Voltage>> {
+        Ok(get_optional_field(obj, "voltage")?
+            .map(|value| value.extract::<Option<f64>>())
+            .transpose()?
+            .flatten()
+            .map(Voltage::from_volts))
+    }
+
+    fn extract_frequency(obj: &Borrowed<'_, '_, PyAny>) -> PyResult<Option<Frequency>> {
+        Ok(get_optional_field(obj, "frequency")?
+            .map(|value| value.extract::<Option<f64>>())
+            .transpose()?
+            .flatten()
+            .map(Frequency::from_megahertz))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use measurements::{Frequency, Power, Voltage};
+
+    use super::TuningConfig;
+    use crate::data::miner::TuningTarget;
+
+    #[test]
+    fn target_only_rejects_chip_set_points() {
+        let config = TuningConfig::new(TuningTarget::Power(Power::from_watts(3000.0)));
+        assert!(config.ensure_target_only().is_ok());
+
+        let with_voltage = config
+            .clone()
+            .with_voltage(Voltage::from_millivolts(1300.0));
+        assert!(with_voltage.ensure_target_only().is_err());
+
+        let with_frequency = config.with_frequency(Frequency::from_megahertz(650.0));
+        assert!(with_frequency.ensure_target_only().is_err());
+    }
 }
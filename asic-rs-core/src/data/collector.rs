@@ -35,6 +35,9 @@ pub enum DataField {
     /// Hostname assigned to the miner.
     #[cfg_attr(feature = "python", pydantic(value = "Hostname"))]
     Hostname,
+    /// Link and addressing state of the network interface.
+    #[cfg_attr(feature = "python", pydantic(value = "Network"))]
+    Network,
     /// Version of the miner's API.
     #[cfg_attr(feature = "python", pydantic(value = "ApiVersion"))]
     ApiVersion,
@@ -44,6 +47,9 @@ pub enum DataField {
     /// Control board version of the miner.
     #[cfg_attr(feature = "python", pydantic(value = "ControlBoardVersion"))]
     ControlBoardVersion,
+    /// Control board system statistics (CPU, memory, storage, OS).
+    #[cfg_attr(feature = "python", pydantic(value = "ControlBoard"))]
+    ControlBoard,
     /// Details about the hashboards (e.g., temperatures, chips, etc.).
     #[cfg_attr(feature = "python", pydantic(value = "Hashboards"))]
     Hashboards,
@@ -62,6 +68,9 @@ pub enum DataField {
     /// PSU fan speed or configuration.
     #[cfg_attr(feature = "python", pydantic(value = "PsuFans"))]
     PsuFans,
+    /// Power supply telemetry (model, input/output readings, temperature).
+    #[cfg_attr(feature = "python", pydantic(value = "Psus"))]
+    Psus,
     /// Average temperature reported by the miner.
     #[cfg_attr(feature = "python", pydantic(value = "AverageTemperature"))]
     AverageTemperature,
@@ -90,6 +99,9 @@ pub enum DataField {
     /// Whether the fault or alert light is flashing.
     #[cfg_attr(feature = "python", pydantic(value = "LightFlashing"))]
     LightFlashing,
+    /// Home-miner appliance settings (screen).
+    #[cfg_attr(feature = "python", pydantic(value = "Appliance"))]
+    Appliance,
     /// Messages reported by the miner (e.g., errors or warnings).
     #[cfg_attr(feature = "python", pydantic(value = "Messages"))]
     Messages,
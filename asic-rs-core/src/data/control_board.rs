@@ -0,0 +1,38 @@
+use measurements::Temperature;
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::serialize::serialize_temperature;
+
+#[cfg_attr(feature = "python", pyclass(from_py_object, module = "asic_rs"))]
+#[cfg_attr(feature = "python", asic_rs_pydantic::py_pydantic_model(getters))]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+/// System statistics of the control board.
+pub struct ControlBoardData {
+    /// The 1 minute CPU load average
+    pub cpu_load: Option<f64>,
+    /// Memory in use, in bytes
+    pub memory_used: Option<u64>,
+    /// Total memory, in bytes
+    pub memory_total: Option<u64>,
+    /// Storage in use on the data partition, in bytes
+    pub storage_used: Option<u64>,
+    /// Total size of the data partition, in bytes
+    pub storage_total: Option<u64>,
+    /// The control board or SoC temperature
+    #[serde(serialize_with = "serialize_temperature")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<Temperature>,
+    /// The operating system name and version
+    pub os_version: Option<String>,
+    /// The Linux kernel version
+    pub kernel_version: Option<String>,
+}
+
+impl ControlBoardData {
+    /// Whether no statistics were reported at all.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
@@ -1,7 +1,17 @@
-use std::path::Path;
+use std::{net::IpAddr, path::Path, time::Duration};
 
-use anyhow::Context;
-use tokio::io::AsyncReadExt;
+use anyhow::{Context, anyhow, bail};
+use futures::StreamExt;
+use reqwest::Body;
+use tokio::{
+    io::AsyncReadExt,
+    time::{Instant, sleep},
+};
+
+use crate::traits::miner::GetFirmwareVersion;
+
+const UPLOAD_CHUNK_SIZE: usize = 256 * 1024;
+const UPGRADE_POLL_INTERVAL: Duration = Duration::from_secs(15);
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct FirmwareImage {
@@ -53,3 +63,73 @@ impl FirmwareImage {
             .context("Firmware path must include a valid UTF-8 filename")
     }
 }
+
+/// Wrap firmware bytes in a streaming request body that logs upload progress
+/// as the request is written to the socket.
+pub fn upload_body(ip: IpAddr, bytes: Vec<u8>) -> Body {
+    let total = bytes.len();
+    let mut last_reported = 0;
+    let stream = futures::stream::iter((0..total).step_by(UPLOAD_CHUNK_SIZE)).map(move |start| {
+        let end = (start + UPLOAD_CHUNK_SIZE).min(total);
+        let percent = end * 100 / total;
+        if percent >= last_reported + 10 || end == total {
+            last_reported = percent;
+            tracing::info!(
+                miner_ip = %ip,
+                sent = end,
+                total,
+                percent,
+                "Firmware upload progress"
+            );
+        }
+        Ok::<_, std::io::Error>(bytes[start..end].to_vec())
+    });
+    Body::wrap_stream(stream)
+}
+
+/// Read the firmware version to compare against after an upgrade. Without it
+/// there is no way to tell whether the upgrade took effect.
+pub async fn version_before_upgrade<M: GetFirmwareVersion + ?Sized>(
+    miner: &M,
+) -> anyhow::Result<String> {
+    miner
+        .get_firmware_version()
+        .await
+        .ok_or_else(|| anyhow!("Could not read the firmware version before upgrading"))
+}
+
+/// Wait up to `timeout` for the miner to come back from an upgrade reporting
+/// a firmware version different from `previous`, and matching `expected`
+/// when the firmware named one.
+pub async fn wait_for_upgraded_version<M: GetFirmwareVersion + ?Sized>(
+    miner: &M,
+    previous: &str,
+    expected: Option<&str>,
+    timeout: Duration,
+) -> anyhow::Result<String> {
+    let deadline = Instant::now() + timeout;
+    let mut last_seen = None;
+    while Instant::now() < deadline {
+        sleep(UPGRADE_POLL_INTERVAL).await;
+        let Some(current) = miner.get_firmware_version().await else {
+            continue;
+        };
+        if current != previous {
+            if let Some(expected) = expected.filter(|expected| *expected != current) {
+                bail!("Firmware upgrade installed version {current}, expected {expected}");
+            }
+            return Ok(current);
+        }
+        last_seen = Some(current);
+    }
+
+    match last_seen {
+        Some(version) => {
+            bail!("Firmware upgrade did not take effect, miner still reports version {version}")
+        }
+        None => bail!(
+            "Miner did not come back online within {} seconds of the firmware upgrade",
+            timeout.as_secs()
+        ),
+    }
+}
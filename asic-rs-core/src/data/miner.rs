@@ -9,12 +9,16 @@ use serde::{Deserialize, Serialize};
 use super::{
     board::{BoardData, MinerControlBoard},
     capabilities::TuningCapabilities,
+    control_board::ControlBoardData,
     device::DeviceInfo,
     fan::FanData,
     hashrate::HashRate,
     message::MinerMessage,
+    network::NetworkData,
     pool::PoolGroupData,
+    psu::PsuData,
 };
+use crate::config::appliance::ApplianceConfig;
 use crate::data::{
     deserialize::deserialize_macaddr,
     serialize::{serialize_macaddr, serialize_power, serialize_temperature},
@@ -79,12 +83,16 @@ pub struct MinerData {
     pub serial_number: Option<String>,
     /// The network hostname of the miner
     pub hostname: Option<String>,
+    /// Link state, addressing and error counters of the network interface
+    pub network: Option<NetworkData>,
     /// The API version of the miner
     pub api_version: Option<String>,
     /// The firmware version of the miner
     pub firmware_version: Option<String>,
     /// The type of control board on the miner
     pub control_board_version: Option<MinerControlBoard>,
+    /// System statistics of the control board (CPU load, memory, storage, OS)
+    pub control_board: Option<ControlBoardData>,
     /// The expected number of boards in the miner.
     pub expected_hashboards: Option<u8>,
     /// Per-hashboard data for this miner
@@ -103,6 +111,8 @@ pub struct MinerData {
     pub fans: Vec<FanData>,
     /// The current PDU fan information for the miner
     pub psu_fans: Vec<FanData>,
+    /// The current power supply information for the miner
+    pub psus: Vec<PsuData>,
     /// The average temperature across all chips in the miner
     #[serde(serialize_with = "serialize_temperature")]
     pub average_temperature: Option<Temperature>,
@@ -128,6 +138,8 @@ pub struct MinerData {
     pub efficiency: Option<f64>,
     /// The state of the fault/alert light on the miner
     pub light_flashing: Option<bool>,
+    /// Appliance settings of home miners (screen), where supported
+    pub appliance: Option<ApplianceConfig>,
     /// Any message on the miner, including errors
     pub messages: Vec<MinerMessage>,
     /// The total uptime of the miner's system
@@ -6,6 +6,7 @@ pub mod board;
 pub mod capabilities;
 pub mod collector;
 pub mod command;
+pub mod control_board;
 pub(crate) mod deserialize;
 pub mod device;
 pub mod fan;
@@ -13,5 +14,7 @@ pub mod firmware;
 pub mod hashrate;
 pub mod message;
 pub mod miner;
+pub mod network;
 pub mod pool;
+pub mod psu;
 pub(crate) mod serialize;
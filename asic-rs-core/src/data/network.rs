@@ -0,0 +1,130 @@
+use std::net::IpAddr;
+
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::config::network::NetworkConfig;
+
+#[cfg_attr(feature = "python", pyclass(from_py_object, module = "asic_rs"))]
+#[cfg_attr(feature = "python", asic_rs_pydantic::py_pydantic_model(getters))]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+/// Link and addressing state of the miner's management interface.
+pub struct NetworkData {
+    /// The name of the interface, e.g. `eth0`
+    pub interface: Option<String>,
+    /// The negotiated link speed, in Mbit/s
+    pub link_speed: Option<u32>,
+    /// Whether the link negotiated full duplex
+    pub full_duplex: Option<bool>,
+    /// Whether the address is obtained via DHCP
+    pub dhcp: Option<bool>,
+    /// The subnet mask of the IPv4 address
+    pub netmask: Option<IpAddr>,
+    /// The default gateway
+    pub gateway: Option<IpAddr>,
+    /// The DNS servers, in order of preference
+    pub dns: Vec<IpAddr>,
+    /// The IPv6 addresses assigned to the interface
+    pub ipv6_addresses: Vec<IpAddr>,
+    /// Packets received with errors since boot
+    pub rx_errors: Option<u64>,
+    /// Packets that failed to transmit since boot
+    pub tx_errors: Option<u64>,
+    /// Received packets dropped since boot
+    pub rx_dropped: Option<u64>,
+    /// Transmitted packets dropped since boot
+    pub tx_dropped: Option<u64>,
+}
+
+/// Interface whose link state [`SYSFS_LINK_STATS_COMMAND`] reads.
+pub const SYSFS_LINK_INTERFACE: &str = "eth0";
+
+/// Shell command printing the link speed, duplex and error counters of
+/// [`SYSFS_LINK_INTERFACE`] from sysfs as `name=value` lines, for firmwares
+/// with SSH access.
+pub const SYSFS_LINK_STATS_COMMAND: &str = "cd /sys/class/net/eth0 && \
+    for f in speed duplex statistics/rx_errors statistics/tx_errors \
+    statistics/rx_dropped statistics/tx_dropped; do echo \"$f=$(cat $f 2>/dev/null)\"; done";
+
+impl NetworkData {
+    /// Whether nothing about the interface was reported.
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Fill in the link speed, duplex and error counters from the output of
+    /// [`SYSFS_LINK_STATS_COMMAND`]. Attributes the kernel could not read,
+    /// such as the speed of a link that is down, are left unset.
+    pub fn with_sysfs_link_stats(mut self, output: &str) -> Self {
+        for (name, value) in output
+            .lines()
+            .filter_map(|line| line.split_once('='))
+            .map(|(name, value)| (name.trim(), value.trim()))
+        {
+            let counter = || value.parse::<u64>().ok();
+            match name {
+                "speed" => self.link_speed = value.parse().ok(),
+                "duplex" => {
+                    self.full_duplex = match value {
+                        "full" => Some(true),
+                        "half" => Some(false),
+                        _ => None,
+                    }
+                }
+                "statistics/rx_errors" => self.rx_errors = counter(),
+                "statistics/tx_errors" => self.tx_errors = counter(),
+                "statistics/rx_dropped" => self.rx_dropped = counter(),
+                "statistics/tx_dropped" => self.tx_dropped = counter(),
+                _ => {}
+            }
+        }
+        if self.interface.is_none() {
+            self.interface = Some(SYSFS_LINK_INTERFACE.to_string());
+        }
+        self
+    }
+}
+
+impl From<NetworkConfig> for NetworkData {
+    /// Carry over the addressing fields of a network configuration read
+    /// from the miner.
+    fn from(config: NetworkConfig) -> Self {
+        Self {
+            dhcp: Some(config.dhcp),
+            netmask: config.netmask,
+            gateway: config.gateway,
+            dns: config.dns.unwrap_or_default(),
+            ipv6_addresses: config.ip.filter(IpAddr::is_ipv6).into_iter().collect(),
+            ..Default::default()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn sysfs_link_stats_are_parsed() {
+        let output = "speed=100\nduplex=full\nstatistics/rx_errors=3\nstatistics/tx_errors=0\n\
+                      statistics/rx_dropped=1742\nstatistics/tx_dropped=0\n";
+        let network = NetworkData::default().with_sysfs_link_stats(output);
+        assert_eq!(network.interface.as_deref(), Some("eth0"));
+        assert_eq!(network.link_speed, Some(100));
+        assert_eq!(network.full_duplex, Some(true));
+        assert_eq!(network.rx_errors, Some(3));
+        assert_eq!(network.tx_errors, Some(0));
+        assert_eq!(network.rx_dropped, Some(1742));
+        assert_eq!(network.tx_dropped, Some(0));
+    }
+
+    #[test]
+    fn sysfs_link_down_leaves_speed_unset() {
+        let output = "speed=\nduplex=unknown\nstatistics/rx_errors=0\n";
+        let network = NetworkData::default().with_sysfs_link_stats(output);
+        assert_eq!(network.link_speed, None);
+        assert_eq!(network.full_duplex, None);
+        assert_eq!(network.rx_errors, Some(0));
+    }
+}
@@ -0,0 +1,57 @@
+use measurements::{AngularVelocity, Current, Power, Temperature, Voltage};
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::serialize::{
+    serialize_angular_velocity, serialize_current, serialize_power, serialize_temperature,
+    serialize_voltage,
+};
+
+#[cfg_attr(feature = "python", pyclass(from_py_object, module = "asic_rs"))]
+#[cfg_attr(feature = "python", asic_rs_pydantic::py_pydantic_model(getters))]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Default)]
+/// Power supply telemetry.
+pub struct PsuData {
+    /// The position of the power supply in the miner, indexed from 0
+    pub position: u8,
+    /// The model of the power supply
+    pub model: Option<String>,
+    /// The serial number of the power supply
+    pub serial_number: Option<String>,
+    /// The firmware version running on the power supply
+    pub firmware_version: Option<String>,
+    /// The AC input voltage
+    #[serde(serialize_with = "serialize_voltage")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_voltage: Option<Voltage>,
+    /// The AC input current
+    #[serde(serialize_with = "serialize_current")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub input_current: Option<Current>,
+    /// The DC output voltage supplied to the hashboards
+    #[serde(serialize_with = "serialize_voltage")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_voltage: Option<Voltage>,
+    /// The DC output power supplied to the hashboards
+    #[serde(serialize_with = "serialize_power")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub output_power: Option<Power>,
+    /// The internal temperature of the power supply
+    #[serde(serialize_with = "serialize_temperature")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub temperature: Option<Temperature>,
+    /// The RPM of the power supply fan, averaged if it has more than one
+    #[serde(serialize_with = "serialize_angular_velocity")]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fan_rpm: Option<AngularVelocity>,
+}
+
+impl PsuData {
+    pub fn new(position: u8) -> Self {
+        Self {
+            position,
+            ..Default::default()
+        }
+    }
+}
@@ -2,6 +2,7 @@ pub mod config;
 pub mod data;
 pub mod discovery;
 pub mod errors;
+pub mod ssh;
 pub mod test;
 pub mod traits;
 pub mod util;
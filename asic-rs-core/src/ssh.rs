@@ -0,0 +1,393 @@
+use std::{net::IpAddr, sync::Arc, time::Duration};
+
+use anyhow::{Context, anyhow, bail};
+use async_trait::async_trait;
+use russh::{
+    Channel, ChannelMsg, Disconnect, client,
+    keys::{PrivateKeyWithHashAlg, decode_secret_key, ssh_key::PublicKey},
+};
+use secrecy::ExposeSecret;
+use serde_json::Value;
+use tokio::{sync::Mutex, time::timeout};
+
+use crate::{
+    data::command::MinerCommand,
+    traits::{auth::MinerAuth, miner::APIClient},
+};
+
+/// Output of a command run over SSH.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SSHOutput {
+    pub stdout: String,
+    pub stderr: String,
+    /// Exit status, when the server reported one.
+    pub exit_status: Option<u32>,
+}
+
+impl SSHOutput {
+    pub fn success(&self) -> bool {
+        self.exit_status == Some(0)
+    }
+}
+
+/// Accepts any host key. Miners generate their host keys on first boot and
+/// after every reflash, so there is nothing stable to pin against.
+struct AcceptAnyHostKey;
+
+impl client::Handler for AcceptAnyHostKey {
+    type Error = russh::Error;
+
+    async fn check_server_key(&mut self, _key: &PublicKey) -> Result<bool, Self::Error> {
+        Ok(true)
+    }
+}
+
+/// SSH client for firmwares that expose data and recovery actions over SSH.
+///
+/// Authenticates with a password or private key from [`MinerAuth`] and keeps
+/// the session open between commands, reconnecting if it was dropped.
+pub struct SSHClient {
+    ip: IpAddr,
+    port: u16,
+    auth: MinerAuth,
+    connect_timeout: Duration,
+    command_timeout: Duration,
+    session: Mutex<Option<client::Handle<AcceptAnyHostKey>>>,
+}
+
+impl std::fmt::Debug for SSHClient {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SSHClient")
+            .field("ip", &self.ip)
+            .field("port", &self.port)
+            .field("auth", &self.auth)
+            .finish_non_exhaustive()
+    }
+}
+
+impl SSHClient {
+    pub fn new(ip: IpAddr, auth: MinerAuth) -> Self {
+        Self {
+            ip,
+            port: 22,
+            auth,
+            connect_timeout: Duration::from_secs(5),
+            command_timeout: Duration::from_secs(10),
+            session: Mutex::new(None),
+        }
+    }
+
+    pub fn with_port(mut self, port: u16) -> Self {
+        self.port = port;
+        self
+    }
+
+    pub fn with_connect_timeout(mut self, connect_timeout: Duration) -> Self {
+        self.connect_timeout = connect_timeout;
+        self
+    }
+
+    pub fn with_command_timeout(mut self, command_timeout: Duration) -> Self {
+        self.command_timeout = command_timeout;
+        self
+    }
+
+    /// Replace the credentials. The open session, if any, is dropped so the
+    /// next command logs in again.
+    pub fn set_auth(&mut self, auth: MinerAuth) {
+        self.auth = auth;
+        *self.session.get_mut() = None;
+    }
+
+    async fn connect(&self) -> anyhow::Result<client::Handle<AcceptAnyHostKey>> {
+        let config = Arc::new(client::Config {
+            inactivity_timeout: Some(Duration::from_secs(60)),
+            ..Default::default()
+        });
+        let mut session = timeout(
+            self.connect_timeout,
+            client::connect(config, (self.ip, self.port), AcceptAnyHostKey),
+        )
+        .await
+        .map_err(|_| anyhow!("SSH connection to {} timed out", self.ip))?
+        .with_context(|| format!("SSH connection to {} failed", self.ip))?;
+
+        let authenticated = timeout(self.connect_timeout, async {
+            match &self.auth {
+                MinerAuth::UserAndPass(creds) => session
+                    .authenticate_password(&creds.username, creds.password.expose_secret())
+                    .await
+                    .map(|result| result.success()),
+                MinerAuth::KeyAuth(creds) => {
+                    let key = decode_secret_key(
+                        creds.private_key.expose_secret(),
+                        creds.passphrase.as_ref().map(|p| p.expose_secret()),
+                    )?;
+                    let hash = session.best_supported_rsa_hash().await?.flatten();
+                    session
+                        .authenticate_publickey(
+                            &creds.username,
+                            PrivateKeyWithHashAlg::new(Arc::new(key), hash),
+                        )
+                        .await
+                        .map(|result| result.success())
+                }
+                MinerAuth::TokenAuth(_) => Ok(false),
+            }
+        })
+        .await
+        .map_err(|_| anyhow!("SSH authentication to {} timed out", self.ip))??;
+
+        if !authenticated {
+            bail!(
+                "SSH authentication to {} failed for user {:?}",
+                self.ip,
+                self.auth.username()
+            );
+        }
+        Ok(session)
+    }
+
+    /// Open a channel on the cached session, reconnecting when the miner has
+    /// closed it (reboot, idle timeout). Nothing has been sent to the miner
+    /// yet at this point, so reconnecting cannot run a command twice.
+    async fn open_channel(
+        &self,
+        cached: Option<client::Handle<AcceptAnyHostKey>>,
+    ) -> anyhow::Result<(client::Handle<AcceptAnyHostKey>, Channel<client::Msg>)> {
+        if let Some(handle) = cached.filter(|handle| !handle.is_closed()) {
+            match timeout(self.connect_timeout, handle.channel_open_session()).await {
+                Ok(Ok(channel)) => return Ok((handle, channel)),
+                Ok(Err(e)) => {
+                    tracing::debug!(miner_ip = %self.ip, error = %e, "SSH session lost, reconnecting");
+                }
+                Err(_) => {
+                    tracing::debug!(miner_ip = %self.ip, "SSH session unresponsive, reconnecting");
+                }
+            }
+        }
+        let handle = self.connect().await?;
+        let channel = timeout(self.connect_timeout, handle.channel_open_session())
+            .await
+            .map_err(|_| anyhow!("Opening an SSH channel on {} timed out", self.ip))??;
+        Ok((handle, channel))
+    }
+
+    async fn run(mut channel: Channel<client::Msg>, command: &str) -> anyhow::Result<SSHOutput> {
+        channel.exec(true, command).await?;
+
+        let mut stdout = Vec::new();
+        let mut stderr = Vec::new();
+        let mut exit_status = None;
+        while let Some(msg) = channel.wait().await {
+            match msg {
+                ChannelMsg::Data { data } => stdout.extend_from_slice(&data),
+                ChannelMsg::ExtendedData { data, ext: 1 } => stderr.extend_from_slice(&data),
+                ChannelMsg::ExitStatus {
+                    exit_status: status,
+                } => exit_status = Some(status),
+                _ => {}
+            }
+        }
+
+        Ok(SSHOutput {
+            stdout: String::from_utf8_lossy(&stdout).into_owned(),
+            stderr: String::from_utf8_lossy(&stderr).into_owned(),
+            exit_status,
+        })
+    }
+
+    /// Run `command` in a shell on the miner.
+    ///
+    /// A non-zero exit status is not an error here; check
+    /// [`SSHOutput::success`]. Commands that reboot the miner may drop the
+    /// connection before an exit status is sent. A command is never retried
+    /// once it has been sent, since it may not be safe to run twice.
+    pub async fn execute(&self, command: &str) -> anyhow::Result<SSHOutput> {
+        let mut session = self.session.lock().await;
+        let (handle, channel) = self.open_channel(session.take()).await?;
+        let output = timeout(self.command_timeout, Self::run(channel, command))
+            .await
+            .map_err(|_| anyhow!("SSH command {command:?} on {} timed out", self.ip))??;
+        *session = Some(handle);
+        Ok(output)
+    }
+
+    /// Close the open session, if any.
+    pub async fn disconnect(&self) {
+        if let Some(handle) = self.session.lock().await.take() {
+            let _ = handle
+                .disconnect(Disconnect::ByApplication, "", "English")
+                .await;
+        }
+    }
+}
+
+#[async_trait]
+impl APIClient for SSHClient {
+    /// Runs an SSH command and returns its standard output as a string, or an
+    /// error if it exits with a non-zero status.
+    async fn get_api_result(&self, command: &MinerCommand) -> anyhow::Result<Value> {
+        match command {
+            MinerCommand::SSH { command } => {
+                let output = self.execute(command).await?;
+                if !output.success() {
+                    bail!(
+                        "SSH command {command:?} exited with {:?}: {}",
+                        output.exit_status,
+                        output.stderr.trim()
+                    );
+                }
+                Ok(Value::String(output.stdout))
+            }
+            _ => Err(anyhow!("Unsupported command type for SSH client")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        net::Ipv4Addr,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    use russh::{
+        Channel, ChannelId, CryptoVec,
+        keys::{
+            PrivateKey,
+            ssh_key::{Algorithm, rand_core::OsRng},
+        },
+        server::{self, Auth, Msg, Session},
+    };
+    use tokio::net::TcpListener;
+
+    use super::*;
+
+    /// Local SSH server that accepts `root`/`root` and answers every exec
+    /// request with the command it was given. `fail` exits with status 1 and
+    /// `drop` closes the connection without answering, after counting the
+    /// exec request in `execs`.
+    #[derive(Clone, Default)]
+    struct EchoServer {
+        execs: Arc<AtomicUsize>,
+    }
+
+    impl server::Handler for EchoServer {
+        type Error = russh::Error;
+
+        async fn auth_password(&mut self, user: &str, password: &str) -> Result<Auth, Self::Error> {
+            Ok(if user == "root" && password == "root" {
+                Auth::Accept
+            } else {
+                Auth::reject()
+            })
+        }
+
+        async fn channel_open_session(
+            &mut self,
+            _channel: Channel<Msg>,
+            _session: &mut Session,
+        ) -> Result<bool, Self::Error> {
+            Ok(true)
+        }
+
+        async fn exec_request(
+            &mut self,
+            channel: ChannelId,
+            data: &[u8],
+            session: &mut Session,
+        ) -> Result<(), Self::Error> {
+            let command = String::from_utf8_lossy(data).into_owned();
+            self.execs.fetch_add(1, Ordering::SeqCst);
+            if command == "drop" {
+                return Err(russh::Error::Disconnect);
+            }
+            session.channel_success(channel)?;
+            session.data(channel, CryptoVec::from(format!("ran {command}")))?;
+            session.exit_status_request(channel, u32::from(command == "fail"))?;
+            session.eof(channel)?;
+            session.close(channel)?;
+            Ok(())
+        }
+    }
+
+    async fn start_server_with(server: EchoServer) -> u16 {
+        let config = Arc::new(server::Config {
+            keys: vec![PrivateKey::random(&mut OsRng, Algorithm::Ed25519).unwrap()],
+            ..Default::default()
+        });
+        let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, 0)).await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        tokio::spawn(async move {
+            while let Ok((socket, _)) = listener.accept().await {
+                let session = server::run_stream(config.clone(), socket, server.clone())
+                    .await
+                    .unwrap();
+                tokio::spawn(session);
+            }
+        });
+        port
+    }
+
+    async fn start_server() -> u16 {
+        start_server_with(EchoServer::default()).await
+    }
+
+    fn client(port: u16, password: &str) -> SSHClient {
+        SSHClient::new(
+            IpAddr::V4(Ipv4Addr::LOCALHOST),
+            MinerAuth::new("root", password),
+        )
+        .with_port(port)
+    }
+
+    #[tokio::test]
+    async fn runs_commands_over_one_session() {
+        let port = start_server().await;
+        let ssh = client(port, "root");
+
+        let uptime = ssh
+            .get_api_result(&MinerCommand::SSH {
+                command: "cat /proc/uptime",
+            })
+            .await
+            .unwrap();
+        assert_eq!(uptime, Value::String("ran cat /proc/uptime".into()));
+
+        let output = ssh.execute("fail").await.unwrap();
+        assert!(!output.success());
+        assert!(
+            ssh.get_api_result(&MinerCommand::SSH { command: "fail" })
+                .await
+                .is_err()
+        );
+        ssh.disconnect().await;
+
+        // Reconnects after the session was closed.
+        assert!(ssh.execute("dmesg").await.unwrap().success());
+    }
+
+    #[tokio::test]
+    async fn rejects_bad_credentials() {
+        let port = start_server().await;
+        assert!(client(port, "wrong").execute("true").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn does_not_rerun_a_sent_command() {
+        let server = EchoServer::default();
+        let execs = server.execs.clone();
+        let port = start_server_with(server).await;
+        let ssh = client(port, "root");
+
+        // Like a reboot: the connection drops before an exit status is sent.
+        if let Ok(output) = ssh.execute("drop").await {
+            assert_eq!(output.exit_status, None);
+        }
+        assert_eq!(execs.load(Ordering::SeqCst), 1);
+
+        // The next command reconnects as usual.
+        assert!(ssh.execute("true").await.unwrap().success());
+    }
+}
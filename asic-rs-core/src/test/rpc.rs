@@ -0,0 +1,64 @@
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use serde_json::Value;
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpListener,
+    task::JoinHandle,
+};
+
+/// Requests received by [`mock_rpc`], in arrival order.
+pub type RpcRequests = Arc<Mutex<Vec<Value>>>;
+
+/// Serve `count` RPC connections on an ephemeral port.
+///
+/// Each request is recorded and answered with `respond(command)`; `None`
+/// closes the connection without a reply. The server task fails if a
+/// connection does not arrive within 2 s.
+pub async fn mock_rpc(
+    count: usize,
+    respond: impl Fn(&str) -> Option<Value> + Send + 'static,
+) -> anyhow::Result<(u16, RpcRequests, JoinHandle<anyhow::Result<()>>)> {
+    let requests = RpcRequests::default();
+    let server_requests = Arc::clone(&requests);
+    let listener = TcpListener::bind("127.0.0.1:0").await?;
+    let port = listener.local_addr()?.port();
+
+    let server = tokio::spawn(async move {
+        for _ in 0..count {
+            let (mut socket, _) =
+                tokio::time::timeout(Duration::from_secs(2), listener.accept()).await??;
+            let request = read_request(&mut socket).await?;
+            let command = request["command"].as_str().unwrap_or_default().to_string();
+            server_requests
+                .lock()
+                .map_err(|_| anyhow::anyhow!("mock RPC request log poisoned"))?
+                .push(request);
+
+            if let Some(response) = respond(&command) {
+                socket.write_all(format!("{response}\n").as_bytes()).await?;
+            }
+        }
+        anyhow::Ok(())
+    });
+
+    Ok((port, requests, server))
+}
+
+/// Read until the bytes received so far parse as one JSON request.
+async fn read_request(socket: &mut tokio::net::TcpStream) -> anyhow::Result<Value> {
+    let mut buf = Vec::new();
+    let mut chunk = [0u8; 4096];
+    loop {
+        let n = socket.read(&mut chunk).await?;
+        buf.extend_from_slice(&chunk[..n]);
+        match serde_json::from_slice(buf.trim_ascii()) {
+            Ok(request) => return Ok(request),
+            Err(e) if n == 0 => return Err(e.into()),
+            Err(_) => {}
+        }
+    }
+}
@@ -7,11 +7,21 @@ pub struct UserAndPassAuth {
     pub password: SecretString,
 }
 
+/// Username and private key credentials, for SSH.
+#[derive(Clone, Debug)]
+pub struct KeyAuth {
+    pub username: String,
+    /// OpenSSH or PEM encoded private key.
+    pub private_key: SecretString,
+    pub passphrase: Option<SecretString>,
+}
+
 /// Credentials for authenticating with a miner.
 ///
 /// Most firmwares authenticate with a username/password ([`MinerAuth::UserAndPass`]).
 /// Some (e.g. VNish, BraiinsOS HTTP) accept a pre-issued bearer token instead
-/// ([`MinerAuth::TokenAuth`]). The two modes are mutually exclusive — backends
+/// ([`MinerAuth::TokenAuth`]), and SSH also accepts a private key
+/// ([`MinerAuth::KeyAuth`]). The modes are mutually exclusive — backends
 /// `match` on the variant to pick the right path.
 #[derive(Clone, Debug)]
 pub enum MinerAuth {
@@ -19,6 +29,8 @@ pub enum MinerAuth {
     UserAndPass(UserAndPassAuth),
     /// Pre-issued bearer token (used directly, no password login).
     TokenAuth(SecretString),
+    /// Username + private key, only accepted by SSH.
+    KeyAuth(KeyAuth),
 }
 
 impl MinerAuth {
@@ -35,19 +47,34 @@ impl MinerAuth {
         MinerAuth::TokenAuth(SecretString::from(token.into()))
     }
 
-    /// Username for user/pass auth; empty string for token auth.
+    /// Build private key credentials for SSH, to pass to
+    /// [`HasAuth::set_ssh_auth`].
+    pub fn from_key(
+        username: impl Into<String>,
+        private_key: impl Into<String>,
+        passphrase: Option<String>,
+    ) -> Self {
+        MinerAuth::KeyAuth(KeyAuth {
+            username: username.into(),
+            private_key: SecretString::from(private_key.into()),
+            passphrase: passphrase.map(SecretString::from),
+        })
+    }
+
+    /// Username for user/pass and key auth; empty string for token auth.
     pub fn username(&self) -> &str {
         match self {
             MinerAuth::UserAndPass(c) => &c.username,
+            MinerAuth::KeyAuth(c) => &c.username,
             MinerAuth::TokenAuth(_) => "",
         }
     }
 
-    /// Password for user/pass auth; empty string for token auth.
+    /// Password for user/pass auth; empty string for token and key auth.
     pub fn password(&self) -> &str {
         match self {
             MinerAuth::UserAndPass(c) => c.password.expose_secret(),
-            MinerAuth::TokenAuth(_) => "",
+            MinerAuth::TokenAuth(_) | MinerAuth::KeyAuth(_) => "",
         }
     }
 
@@ -55,18 +82,22 @@ impl MinerAuth {
     pub fn token(&self) -> Option<&SecretString> {
         match self {
             MinerAuth::TokenAuth(t) => Some(t),
-            MinerAuth::UserAndPass(_) => None,
+            MinerAuth::UserAndPass(_) | MinerAuth::KeyAuth(_) => None,
         }
     }
 }
 
 /// Trait for applying authentication credentials to a miner at runtime.
 ///
-/// `set_auth` has a default no-op for backends that don't support
+/// Both methods have a default no-op for backends that don't support
 /// credential override at runtime.
 pub trait HasAuth: Send + Sync {
     /// Apply authentication credentials to this miner.
     fn set_auth(&mut self, _auth: MinerAuth) {}
+
+    /// Apply credentials used only for SSH, e.g. a private key. Backends that
+    /// don't reach the miner over SSH ignore them.
+    fn set_ssh_auth(&mut self, _auth: MinerAuth) {}
 }
 
 /// Trait for declaring the default credentials for a backend.
@@ -98,4 +129,16 @@ mod tests {
         assert!(debug.contains("admin"));
         assert!(!debug.contains("secret123"));
     }
+
+    #[test]
+    fn debug_redacts_private_key() {
+        let auth = MinerAuth::from_key("root", "-----BEGIN KEY-----", Some("hunter2".into()));
+
+        let debug = format!("{:?}", auth);
+
+        assert!(debug.contains("root"));
+        assert!(!debug.contains("BEGIN KEY"));
+        assert!(!debug.contains("hunter2"));
+        assert_eq!(auth.password(), "");
+    }
 }
@@ -11,10 +11,13 @@ use tracing;
 
 use crate::{
     config::{
+        appliance::ApplianceConfig,
         collector::{ConfigCollector, ConfigField, ConfigLocation},
         fan::FanConfig,
+        network::NetworkConfig,
         pools::PoolGroupConfig,
         scaling::ScalingConfig,
+        syslog::SyslogTarget,
         temperature::TemperatureConfig,
         tuning::TuningConfig,
     },
@@ -23,13 +26,16 @@ use crate::{
         capabilities::TuningCapabilities,
         collector::{DataCollector, DataField, DataLocation},
         command::MinerCommand,
+        control_board::ControlBoardData,
         device::DeviceInfo,
         fan::FanData,
         firmware::FirmwareImage,
         hashrate::{HashRate, HashRateUnit},
         message::MinerMessage,
         miner::{MinerData, TuningTarget},
+        network::NetworkData,
         pool::PoolGroupData,
+        psu::PsuData,
     },
     traits::model::MinerModel,
     util::unix_timestamp_secs,
@@ -57,12 +63,14 @@ pub trait HasMinerControl:
     SetFaultLight
     + SetPowerLimit
     + SetTuningPercent
+    + SetApplianceConfig
     + Restart
     + Resume
     + Pause
     + ChangePassword
     + FactoryReset
     + ReadLogs
+    + SetSyslogTarget
 {
 }
 
@@ -70,12 +78,14 @@ impl<
     T: SetFaultLight
         + SetPowerLimit
         + SetTuningPercent
+        + SetApplianceConfig
         + Restart
         + Resume
         + Pause
         + ChangePassword
         + FactoryReset
-        + ReadLogs,
+        + ReadLogs
+        + SetSyslogTarget,
 > HasMinerControl for T
 {
 }
@@ -87,6 +97,7 @@ pub trait SupportsConfigs:
     + SupportsTemperatureConfig
     + SupportsTuningConfig
     + SupportsFanConfig
+    + SupportsNetworkConfig
 {
 }
 
@@ -96,7 +107,8 @@ impl<
         + SupportsScalingConfig
         + SupportsTemperatureConfig
         + SupportsTuningConfig
-        + SupportsFanConfig,
+        + SupportsFanConfig
+        + SupportsNetworkConfig,
 > SupportsConfigs for T
 {
 }
@@ -131,14 +143,17 @@ pub trait GetMinerData:
     + GetMAC
     + GetSerialNumber
     + GetHostname
+    + GetNetwork
     + GetApiVersion
     + GetFirmwareVersion
     + GetControlBoardVersion
+    + GetControlBoard
     + GetHashboards
     + GetHashrate
     + GetExpectedHashrate
     + GetFans
     + GetPsuFans
+    + GetPsus
     + GetFluidTemperature
     + GetWattage
     + GetTuningPercent
@@ -146,6 +161,7 @@ pub trait GetMinerData:
     + GetScaledTuningTarget
     + GetTuningCapabilities
     + GetLightFlashing
+    + GetApplianceConfig
     + GetMessages
     + GetUptime
     + GetIsMining
@@ -191,14 +207,17 @@ impl<
         + GetMAC
         + GetSerialNumber
         + GetHostname
+        + GetNetwork
         + GetApiVersion
         + GetFirmwareVersion
         + GetControlBoardVersion
+        + GetControlBoard
         + GetHashboards
         + GetHashrate
         + GetExpectedHashrate
         + GetFans
         + GetPsuFans
+        + GetPsus
         + GetFluidTemperature
         + GetWattage
         + GetTuningPercent
@@ -206,6 +225,7 @@ impl<
         + GetScaledTuningTarget
         + GetTuningCapabilities
         + GetLightFlashing
+        + GetApplianceConfig
         + GetMessages
         + GetUptime
         + GetIsMining
@@ -232,9 +252,11 @@ impl<
         let mac = self.parse_mac(&data);
         let serial_number = self.parse_serial_number(&data);
         let hostname = self.parse_hostname(&data);
+        let network = self.parse_network(&data);
         let api_version = self.parse_api_version(&data);
         let firmware_version = self.parse_firmware_version(&data);
         let control_board_version = self.parse_control_board_version(&data);
+        let control_board = self.parse_control_board(&data);
         let uptime = self.parse_uptime(&data);
         let hashrate = self.parse_hashrate(&data);
         let expected_hashrate = self.parse_expected_hashrate(&data);
@@ -247,8 +269,10 @@ impl<
         let outlet_fluid_temperature = self.parse_outlet_fluid_temperature(&data);
         let fans = self.parse_fans(&data);
         let psu_fans = self.parse_psu_fans(&data);
+        let psus = self.parse_psus(&data);
         let hashboards = self.parse_hashboards(&data);
         let light_flashing = self.parse_light_flashing(&data);
+        let appliance = self.parse_appliance_config(&data);
         let is_mining = self.parse_is_mining(&data);
         let messages = self.parse_messages(&data);
         let pools = self.parse_pools(&data);
@@ -305,11 +329,13 @@ impl<
             device_info: device_info.clone(),
             serial_number,
             hostname,
+            network,
 
             // Version information
             api_version,
             firmware_version,
             control_board_version,
+            control_board,
 
             // Hashboard information
             expected_hashboards: device_info.hardware.board_count(),
@@ -325,6 +351,7 @@ impl<
             expected_fans: device_info.hardware.fans,
             fans,
             psu_fans,
+            psus,
             average_temperature,
             fluid_temperature,
             outlet_fluid_temperature,
@@ -339,6 +366,7 @@ impl<
 
             // Status information
             light_flashing,
+            appliance,
             messages,
             uptime,
             is_mining,
@@ -384,6 +412,18 @@ pub trait GraphQLClient: APIClient {
     ) -> anyhow::Result<Value>;
 }
 
+#[async_trait]
+pub trait GRPCClient: APIClient {
+    /// Call the unary method `command`, given as `package.Service/Method`, with
+    /// `parameters` as the JSON form of its request message.
+    async fn send_command(
+        &self,
+        command: &str,
+        _privileged: bool,
+        parameters: Option<Value>,
+    ) -> anyhow::Result<Value>;
+}
+
 // Data traits
 pub trait GetIP: Send + Sync {
     /// Returns the IP address of the miner.
@@ -464,6 +504,21 @@ pub trait GetHostname: CollectData {
     }
 }
 
+// Network
+#[async_trait]
+pub trait GetNetwork: CollectData {
+    #[tracing::instrument(level = "debug")]
+    async fn get_network(&self) -> Option<NetworkData> {
+        let mut collector = self.get_collector();
+        let data = collector.collect(&[DataField::Network]).await;
+        self.parse_network(&data)
+    }
+    #[allow(unused_variables)]
+    fn parse_network(&self, data: &HashMap<DataField, Value>) -> Option<NetworkData> {
+        None
+    }
+}
+
 // API Version
 #[async_trait]
 pub trait GetApiVersion: CollectData {
@@ -511,6 +566,21 @@ pub trait GetControlBoardVersion: CollectData {
         None
     }
 }
+
+// Control Board
+#[async_trait]
+pub trait GetControlBoard: CollectData {
+    #[tracing::instrument(level = "debug")]
+    async fn get_control_board(&self) -> Option<ControlBoardData> {
+        let mut collector = self.get_collector();
+        let data = collector.collect(&[DataField::ControlBoard]).await;
+        self.parse_control_board(&data)
+    }
+    #[allow(unused_variables)]
+    fn parse_control_board(&self, data: &HashMap<DataField, Value>) -> Option<ControlBoardData> {
+        None
+    }
+}
 // Hashboards
 #[async_trait]
 pub trait GetHashboards: CollectData {
@@ -596,6 +666,21 @@ pub trait GetPsuFans: CollectData {
     }
 }
 
+// PSUs
+#[async_trait]
+pub trait GetPsus: CollectData {
+    #[tracing::instrument(level = "debug")]
+    async fn get_psus(&self) -> Vec<PsuData> {
+        let mut collector = self.get_collector();
+        let data = collector.collect(&[DataField::Psus]).await;
+        self.parse_psus(&data)
+    }
+    #[allow(unused_variables)]
+    fn parse_psus(&self, data: &HashMap<DataField, Value>) -> Vec<PsuData> {
+        vec![]
+    }
+}
+
 // Fluid Temperature
 #[async_trait]
 pub trait GetFluidTemperature: CollectData {
@@ -721,6 +806,21 @@ pub trait GetLightFlashing: CollectData {
     }
 }
 
+// Appliance Config
+#[async_trait]
+pub trait GetApplianceConfig: CollectData {
+    #[tracing::instrument(level = "debug")]
+    async fn get_appliance_config(&self) -> Option<ApplianceConfig> {
+        let mut collector = self.get_collector();
+        let data = collector.collect(&[DataField::Appliance]).await;
+        self.parse_appliance_config(&data)
+    }
+    #[allow(unused_variables)]
+    fn parse_appliance_config(&self, data: &HashMap<DataField, Value>) -> Option<ApplianceConfig> {
+        None
+    }
+}
+
 // Messages
 #[async_trait]
 pub trait GetMessages: CollectData {
@@ -813,6 +913,19 @@ pub trait SetTuningPercent {
     }
 }
 
+#[async_trait]
+pub trait SetApplianceConfig {
+    /// Apply home-miner appliance settings; `None` fields are left unchanged.
+    #[allow(unused_variables)]
+    async fn set_appliance_config(&self, config: ApplianceConfig) -> anyhow::Result<bool> {
+        anyhow::bail!("Setting appliance config is not supported on this platform");
+    }
+    /// Defaults to `false`; home-miner backends override this.
+    fn supports_set_appliance_config(&self) -> bool {
+        false
+    }
+}
+
 #[async_trait]
 pub trait Restart {
     async fn restart(&self) -> anyhow::Result<bool> {
@@ -866,6 +979,20 @@ pub trait ReadLogs {
     fn supports_read_logs(&self) -> bool;
 }
 
+#[async_trait]
+pub trait SetSyslogTarget {
+    /// Forward the miner's system log to a remote syslog receiver, or stop
+    /// forwarding with `None`.
+    #[allow(unused_variables)]
+    async fn set_syslog_target(&self, target: Option<SyslogTarget>) -> anyhow::Result<bool> {
+        anyhow::bail!("Setting a syslog target is not supported on this platform");
+    }
+    /// Defaults to `false`; backends that can forward logs override this.
+    fn supports_set_syslog_target(&self) -> bool {
+        false
+    }
+}
+
 #[async_trait]
 pub trait UpgradeFirmware {
     #[allow(unused_variables)]
@@ -999,4 +1126,49 @@ pub trait SupportsFanConfig: CollectConfigs {
     fn supports_fan_config(&self) -> bool {
         false
     }
+    /// Whether `get_fan_config` works. Defaults to `supports_fan_config`;
+    /// firmwares that can set the fan mode but not read it back override this.
+    fn supports_get_fan_config(&self) -> bool {
+        self.supports_fan_config()
+    }
+}
+
+#[async_trait]
+pub trait SupportsNetworkConfig: CollectConfigs + GetIP {
+    /// Apply a network configuration that keeps the miner at its current
+    /// address. A static config with a different IP, or a switch to DHCP, is
+    /// rejected.
+    async fn set_network_config(&self, config: NetworkConfig) -> anyhow::Result<bool> {
+        config.validate(self.get_ip(), false)?;
+        self.apply_network_config(config).await
+    }
+    /// Apply a network configuration that may move the miner to a new
+    /// address, after which it no longer answers at the current one.
+    async fn set_network_config_readdress(&self, config: NetworkConfig) -> anyhow::Result<bool> {
+        config.validate(self.get_ip(), true)?;
+        self.apply_network_config(config).await
+    }
+    /// Send a network configuration to the miner. Only called with a config
+    /// that [`NetworkConfig::validate`] has accepted.
+    #[allow(unused_variables)]
+    async fn apply_network_config(&self, config: NetworkConfig) -> anyhow::Result<bool> {
+        anyhow::bail!("Setting network config is not supported on this platform");
+    }
+    #[tracing::instrument(level = "debug")]
+    async fn get_network_config(&self) -> anyhow::Result<NetworkConfig> {
+        let mut collector = self.get_config_collector();
+        let data = collector.collect(&[ConfigField::Network]).await;
+        self.parse_network_config(&data)
+    }
+    #[allow(unused_variables)]
+    fn parse_network_config(
+        &self,
+        data: &HashMap<ConfigField, Value>,
+    ) -> anyhow::Result<NetworkConfig> {
+        anyhow::bail!("Getting network config is not supported on this platform");
+    }
+
+    fn supports_network_config(&self) -> bool {
+        false
+    }
 }
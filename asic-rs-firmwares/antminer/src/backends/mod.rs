@@ -1,5 +1,8 @@
+pub(crate) mod network;
+pub(crate) mod system_info;
 pub mod v2020;
 pub mod v2023_07;
+pub(crate) mod work_mode;
 
 use std::net::IpAddr;
 
@@ -0,0 +1,138 @@
+use std::net::IpAddr;
+
+use asic_rs_core::{config::network::NetworkConfig, data::network::NetworkData};
+use serde_json::Value;
+
+/// `ipPro` value of `set_network_conf` for DHCP addressing.
+pub(crate) const PROTOCOL_DHCP: u8 = 1;
+/// `ipPro` value of `set_network_conf` for static addressing.
+pub(crate) const PROTOCOL_STATIC: u8 = 2;
+/// Tag of the sysfs link stats merged into the network info.
+pub(crate) const LINK_STATS_TAG: &str = "link_stats";
+
+fn addr(info: &Value, key: &str) -> Option<IpAddr> {
+    info.get(key)?.as_str()?.trim().parse().ok()
+}
+
+/// Parse the response of `get_network_info` (or `get_system_info`, which
+/// carries the same fields).
+pub(crate) fn parse_network_info(info: &Value) -> anyhow::Result<NetworkConfig> {
+    let nettype = info
+        .get("nettype")
+        .or_else(|| info.get("conf_nettype"))
+        .and_then(Value::as_str)
+        .ok_or_else(|| anyhow::anyhow!("No nettype in Antminer network info"))?;
+
+    let dns = info
+        .get("dnsservers")
+        .or_else(|| info.get("conf_dnsservers"))
+        .and_then(Value::as_str)
+        .map(|servers| {
+            servers
+                .split([' ', ','])
+                .filter_map(|server| server.trim().parse().ok())
+                .collect::<Vec<IpAddr>>()
+        })
+        .filter(|dns| !dns.is_empty());
+
+    Ok(NetworkConfig {
+        dhcp: nettype.eq_ignore_ascii_case("dhcp"),
+        ip: addr(info, "ipaddress"),
+        netmask: addr(info, "netmask"),
+        gateway: addr(info, "gateway").or_else(|| addr(info, "conf_gateway")),
+        dns,
+        hostname: info
+            .get("hostname")
+            .and_then(Value::as_str)
+            .filter(|hostname| !hostname.is_empty())
+            .map(String::from),
+    })
+}
+
+/// Parse the interface state from `get_network_info`. The firmware does not
+/// report link speed or error counters.
+pub(crate) fn parse_network_data(info: &Value) -> Option<NetworkData> {
+    let network = parse_network_info(info).ok().map(|config| {
+        let mut network = NetworkData::from(config);
+        network.interface = info
+            .get("netdevice")
+            .and_then(Value::as_str)
+            .filter(|device| !device.is_empty())
+            .map(String::from);
+        network
+    });
+    // Link stats are read over SSH, and only when SSH credentials were set.
+    match info.get(LINK_STATS_TAG).and_then(Value::as_str) {
+        Some(link_stats) => Some(
+            network
+                .unwrap_or_default()
+                .with_sysfs_link_stats(link_stats),
+        ),
+        None => network,
+    }
+}
+
+/// Render an optional address for `set_network_conf`, which expects empty
+/// strings for unused fields.
+pub(crate) fn addr_string(addr: Option<IpAddr>) -> String {
+    addr.map(|addr| addr.to_string()).unwrap_or_default()
+}
+
+/// Render the DNS servers for `set_network_conf`.
+pub(crate) fn dns_string(dns: Option<&[IpAddr]>) -> String {
+    dns.unwrap_or_default()
+        .iter()
+        .map(IpAddr::to_string)
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test::json::v2020::{AM_SSH_LINK_STATS, AM_SYSTEM_INFO};
+
+    #[test]
+    fn parses_dhcp_network_info() -> anyhow::Result<()> {
+        let info: Value = serde_json::from_str(AM_SYSTEM_INFO)?;
+        let config = parse_network_info(&info)?;
+
+        assert!(config.dhcp);
+        assert_eq!(config.ip, Some("192.168.1.100".parse()?));
+        assert_eq!(config.netmask, Some("255.255.255.0".parse()?));
+        assert_eq!(config.gateway, None);
+        assert_eq!(config.dns, None);
+        assert_eq!(config.hostname.as_deref(), Some("Antminer"));
+        Ok(())
+    }
+
+    #[test]
+    fn parses_network_data() -> anyhow::Result<()> {
+        let info: Value = serde_json::from_str(AM_SYSTEM_INFO)?;
+        let network = parse_network_data(&info).ok_or_else(|| anyhow::anyhow!("no network"))?;
+
+        assert_eq!(network.interface.as_deref(), Some("eth0"));
+        assert_eq!(network.dhcp, Some(true));
+        assert_eq!(network.netmask, Some("255.255.255.0".parse()?));
+        assert_eq!(network.gateway, None);
+        assert!(network.dns.is_empty());
+        assert_eq!(network.link_speed, None);
+        Ok(())
+    }
+
+    #[test]
+    fn merges_ssh_link_stats() -> anyhow::Result<()> {
+        let mut info: Value = serde_json::from_str(AM_SYSTEM_INFO)?;
+        info[LINK_STATS_TAG] = Value::String(AM_SSH_LINK_STATS.to_string());
+        let network = parse_network_data(&info).ok_or_else(|| anyhow::anyhow!("no network"))?;
+
+        assert_eq!(network.interface.as_deref(), Some("eth0"));
+        assert_eq!(network.dhcp, Some(true));
+        assert_eq!(network.link_speed, Some(1000));
+        assert_eq!(network.full_duplex, Some(true));
+        assert_eq!(network.rx_errors, Some(2));
+        assert_eq!(network.rx_dropped, Some(318));
+        assert_eq!(network.tx_errors, Some(0));
+        Ok(())
+    }
+}
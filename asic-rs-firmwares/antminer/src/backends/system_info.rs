@@ -0,0 +1,52 @@
+use asic_rs_core::data::control_board::ControlBoardData;
+use serde_json::Value;
+
+/// Parse control board details from `get_system_info`.
+///
+/// The stock firmware reports only its OS and kernel, e.g.
+/// `"Linux 4.9.113 #1 SMP PREEMPT ..."`, which is trimmed to the release.
+pub(crate) fn parse_control_board(info: &Value) -> Option<ControlBoardData> {
+    let text = |key: &str| {
+        info.get(key)
+            .and_then(Value::as_str)
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+    };
+    let kernel_version = text("system_kernel_version").map(|kernel| {
+        let kernel = kernel.strip_prefix("Linux ").unwrap_or(kernel);
+        kernel
+            .split_whitespace()
+            .next()
+            .unwrap_or(kernel)
+            .to_string()
+    });
+
+    let control_board = ControlBoardData {
+        os_version: text("system_mode").map(String::from),
+        kernel_version,
+        ..Default::default()
+    };
+    (!control_board.is_empty()).then_some(control_board)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::test::json::v2020::AM_SYSTEM_INFO;
+
+    #[test]
+    fn parse_control_board_test() -> anyhow::Result<()> {
+        let info: Value = serde_json::from_str(AM_SYSTEM_INFO)?;
+        let control_board = parse_control_board(&info).unwrap();
+        assert_eq!(control_board.os_version.as_deref(), Some("GNU/Linux"));
+        assert_eq!(control_board.kernel_version.as_deref(), Some("4.9.113"));
+
+        assert_eq!(
+            parse_control_board(&json!({ "hostname": "Antminer" })),
+            None
+        );
+        Ok(())
+    }
+}
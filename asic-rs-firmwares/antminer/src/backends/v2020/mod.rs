@@ -1,27 +1,32 @@
-use std::{collections::HashMap, fmt::Display, net::IpAddr, str::FromStr, time::Duration};
+use std::{collections::HashMap, net::IpAddr, str::FromStr, time::Duration};
 
 use anyhow;
 use asic_rs_core::{
     config::{
         collector::{ConfigCollector, ConfigExtractor, ConfigField, ConfigLocation},
         fan::FanConfig,
+        network::NetworkConfig,
         pools::{PoolConfig, PoolGroupConfig},
         tuning::TuningConfig,
     },
     data::{
         board::{BoardData, MinerControlBoard},
+        capabilities::TuningCapabilities,
         collector::{
             DataCollector, DataExtensions, DataExtractor, DataField, DataLocation, get_by_pointer,
         },
         command::MinerCommand,
+        control_board::ControlBoardData,
         device::{DeviceInfo, HashAlgorithm},
         fan::FanData,
         firmware::FirmwareImage,
         hashrate::{HashRate, HashRateUnit},
         message::{MessageSeverity, MinerMessage},
         miner::{MiningMode, TuningTarget},
+        network::{NetworkData, SYSFS_LINK_STATS_COMMAND},
         pool::{PoolData, PoolGroupData, PoolURL},
     },
+    ssh::SSHClient,
     traits::{miner::*, model::MinerModel},
 };
 use asic_rs_makes_antminer::hardware::AntMinerControlBoard;
@@ -33,7 +38,20 @@ use serde_json::{Value, json};
 use web::AntMinerWebAPI;
 
 use self::firmware::resolve_firmware_image;
-use crate::firmware::AntMinerStockFirmware;
+use crate::{
+    backends::{
+        network::{
+            LINK_STATS_TAG, PROTOCOL_DHCP, PROTOCOL_STATIC, addr_string, dns_string,
+            parse_network_data, parse_network_info,
+        },
+        system_info,
+        work_mode::{
+            MinerMode, POWER_TARGET_KEY, power_target_value, work_mode_capabilities,
+            work_mode_for_power_limit,
+        },
+    },
+    firmware::AntMinerStockFirmware,
+};
 
 mod firmware;
 mod rpc;
@@ -44,27 +62,9 @@ pub struct AntMinerV2020 {
     pub ip: IpAddr,
     pub rpc: AntMinerRPCAPI,
     pub web: AntMinerWebAPI,
+    pub ssh: SSHClient,
     pub device_info: DeviceInfo,
-}
-
-#[allow(dead_code)]
-enum MinerMode {
-    Sleep,
-    Low,
-    Normal,
-    High,
-}
-
-impl Display for MinerMode {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let s = match self {
-            MinerMode::Sleep => "1",
-            MinerMode::Low => "3",
-            MinerMode::High => "2",
-            _ => "0",
-        };
-        f.write_str(s)
-    }
+    ssh_auth_set: bool,
 }
 
 impl MinerMode {
@@ -201,7 +201,7 @@ fn miner_conf_with_fan_config(miner_conf: &Value, config: FanConfig) -> Value {
         FanConfig::Auto { .. } => {
             payload.insert("bitmain-fan-ctrl".to_string(), Value::Bool(false));
         }
-        FanConfig::Manual { fan_speed } => {
+        FanConfig::Manual { fan_speed, .. } => {
             payload.insert("bitmain-fan-ctrl".to_string(), Value::Bool(true));
             payload.insert(
                 "bitmain-fan-pwm".to_string(),
@@ -219,11 +219,14 @@ impl AntMinerV2020 {
             ip,
             rpc: AntMinerRPCAPI::new(ip),
             web: AntMinerWebAPI::new(ip, auth),
+            // SSH logs in as the system root user, not the web UI account.
+            ssh: SSHClient::new(ip, MinerAuth::new("root", "admin")),
             device_info: DeviceInfo::new(
                 model,
                 AntMinerStockFirmware::default(),
                 HashAlgorithm::SHA256,
             ),
+            ssh_auth_set: false,
         }
     }
 
@@ -321,6 +324,7 @@ impl APIClient for AntMinerV2020 {
         match command {
             MinerCommand::RPC { .. } => self.rpc.get_api_result(command).await,
             MinerCommand::WebAPI { .. } => self.web.get_api_result(command).await,
+            MinerCommand::SSH { .. } => self.ssh.get_api_result(command).await,
             _ => Err(anyhow::anyhow!("Unsupported command type for Antminer API")),
         }
     }
@@ -349,6 +353,17 @@ impl GetConfigsLocations for AntMinerV2020 {
                     tag: None,
                 },
             )],
+            ConfigField::Network => vec![(
+                MinerCommand::WebAPI {
+                    command: "get_network_info",
+                    parameters: None,
+                },
+                ConfigExtractor {
+                    func: get_by_pointer,
+                    key: Some(""),
+                    tag: None,
+                },
+            )],
             _ => vec![],
         }
     }
@@ -386,6 +401,13 @@ impl GetDataLocations for AntMinerV2020 {
             command: "get_system_info",
             parameters: None,
         };
+        const WEB_NETWORK_INFO: MinerCommand = MinerCommand::WebAPI {
+            command: "get_network_info",
+            parameters: None,
+        };
+        const SSH_LINK_STATS: MinerCommand = MinerCommand::SSH {
+            command: SYSFS_LINK_STATS_COMMAND,
+        };
 
         const WEB_BLINK_STATUS: MinerCommand = MinerCommand::WebAPI {
             command: "get_blink_status",
@@ -432,6 +454,14 @@ impl GetDataLocations for AntMinerV2020 {
                     tag: None,
                 },
             )],
+            DataField::ControlBoard => vec![(
+                WEB_SYSTEM_INFO,
+                DataExtractor {
+                    func: get_by_pointer,
+                    key: Some(""),
+                    tag: None,
+                },
+            )],
             DataField::Hostname => vec![(
                 WEB_SYSTEM_INFO,
                 DataExtractor {
@@ -440,6 +470,29 @@ impl GetDataLocations for AntMinerV2020 {
                     tag: None,
                 },
             )],
+            DataField::Network => {
+                let mut locations = vec![(
+                    WEB_NETWORK_INFO,
+                    DataExtractor {
+                        func: get_by_pointer,
+                        key: Some(""),
+                        tag: None,
+                    },
+                )];
+                // SSH is off by default on stock firmware, so a filtered port
+                // 22 would otherwise stall every poll.
+                if self.ssh_auth_set {
+                    locations.push((
+                        SSH_LINK_STATS,
+                        DataExtractor {
+                            func: get_by_pointer,
+                            key: Some(""),
+                            tag: Some(LINK_STATS_TAG),
+                        },
+                    ));
+                }
+                locations
+            }
             DataField::ControlBoardVersion => vec![(
                 WEB_MINER_TYPE,
                 DataExtractor {
@@ -546,7 +599,7 @@ impl GetDataLocations for AntMinerV2020 {
                     tag: None,
                 },
             )],
-            DataField::TuningTarget => vec![(
+            DataField::TuningTarget | DataField::TuningCapabilities => vec![(
                 WEB_MINER_CONF,
                 DataExtractor {
                     func: get_by_pointer,
@@ -590,6 +643,12 @@ impl GetHostname for AntMinerV2020 {
     }
 }
 
+impl GetNetwork for AntMinerV2020 {
+    fn parse_network(&self, data: &HashMap<DataField, Value>) -> Option<NetworkData> {
+        data.get(&DataField::Network).and_then(parse_network_data)
+    }
+}
+
 impl GetApiVersion for AntMinerV2020 {
     fn parse_api_version(&self, data: &HashMap<DataField, Value>) -> Option<String> {
         data.extract::<String>(DataField::ApiVersion)
@@ -814,6 +873,13 @@ impl GetControlBoardVersion for AntMinerV2020 {
     }
 }
 
+impl GetControlBoard for AntMinerV2020 {
+    fn parse_control_board(&self, data: &HashMap<DataField, Value>) -> Option<ControlBoardData> {
+        data.get(&DataField::ControlBoard)
+            .and_then(system_info::parse_control_board)
+    }
+}
+
 impl GetWattage for AntMinerV2020 {
     fn parse_wattage(&self, data: &HashMap<DataField, Value>) -> Option<Power> {
         if let Some(stats_data) = data.get(&DataField::Wattage) {
@@ -889,7 +955,18 @@ impl GetFluidTemperature for AntMinerV2020 {
 }
 
 impl GetPsuFans for AntMinerV2020 {}
-impl GetTuningCapabilities for AntMinerV2020 {}
+
+impl GetPsus for AntMinerV2020 {}
+
+impl GetTuningCapabilities for AntMinerV2020 {
+    fn parse_tuning_capabilities(
+        &self,
+        data: &HashMap<DataField, Value>,
+    ) -> Option<TuningCapabilities> {
+        data.get(&DataField::TuningCapabilities)
+            .and_then(work_mode_capabilities)
+    }
+}
 
 impl GetMessages for AntMinerV2020 {
     fn parse_messages(&self, data: &HashMap<DataField, Value>) -> Vec<MinerMessage> {
@@ -939,7 +1016,32 @@ impl SetFaultLight for AntMinerV2020 {
 #[async_trait]
 impl SetPowerLimit for AntMinerV2020 {
     fn supports_set_power_limit(&self) -> bool {
-        false
+        true
+    }
+
+    async fn set_power_limit(&self, limit: Power) -> anyhow::Result<bool> {
+        let pre = self.web.get_miner_conf().await?;
+        if let Some(target) = power_target_value(&pre, limit) {
+            let mut payload = browser_miner_conf_payload(&pre);
+            payload.insert(POWER_TARGET_KEY.to_string(), target);
+            self.web.set_miner_conf(Value::Object(payload)).await?;
+            return Ok(true);
+        }
+
+        let current = miner_conf_mining_mode(&pre)
+            .ok_or_else(|| anyhow::anyhow!("No Antminer mining mode found in miner config"))?;
+        let wattage = self
+            .get_wattage()
+            .await
+            .ok_or_else(|| anyhow::anyhow!("Antminer did not report its power draw"))?;
+        let mode = MinerMode::from(work_mode_for_power_limit(limit, wattage, current)?);
+
+        let Some(miner_conf) = miner_conf_with_miner_mode(&pre, mode) else {
+            anyhow::bail!("No Antminer mining mode field found in miner config")
+        };
+
+        self.web.set_miner_conf(miner_conf).await?;
+        Ok(true)
     }
 }
 
@@ -1114,6 +1216,8 @@ impl ReadLogs for AntMinerV2020 {
     }
 }
 
+impl SetSyslogTarget for AntMinerV2020 {}
+
 #[async_trait]
 impl FactoryReset for AntMinerV2020 {
     async fn factory_reset(&self) -> anyhow::Result<bool> {
@@ -1156,6 +1260,13 @@ impl HasAuth for AntMinerV2020 {
     fn set_auth(&mut self, auth: MinerAuth) {
         self.web.set_auth(auth);
     }
+
+    /// Also enables reading the link stats in [`DataField::Network`], which
+    /// are only available over SSH.
+    fn set_ssh_auth(&mut self, auth: MinerAuth) {
+        self.ssh.set_auth(auth);
+        self.ssh_auth_set = true;
+    }
 }
 
 #[async_trait]
@@ -1165,6 +1276,7 @@ impl SupportsTuningConfig for AntMinerV2020 {
         config: TuningConfig,
         _scaling_config: Option<asic_rs_core::config::scaling::ScalingConfig>,
     ) -> anyhow::Result<bool> {
+        config.ensure_target_only()?;
         let mode = match config.target {
             TuningTarget::MiningMode(MiningMode::Low) => MinerMode::Low,
             TuningTarget::MiningMode(MiningMode::Normal) => MinerMode::Normal,
@@ -1204,6 +1316,7 @@ impl SupportsTuningConfig for AntMinerV2020 {
 #[async_trait]
 impl SupportsFanConfig for AntMinerV2020 {
     async fn set_fan_config(&self, config: FanConfig) -> anyhow::Result<bool> {
+        config.ensure_no_min_fans()?;
         let pre = self.web.get_miner_conf().await?;
         self.web
             .set_miner_conf(miner_conf_with_fan_config(&pre, config))
@@ -1240,6 +1353,59 @@ impl SupportsFanConfig for AntMinerV2020 {
 impl SupportsTemperatureConfig for AntMinerV2020 {}
 impl GetTuningPercent for AntMinerV2020 {}
 impl SetTuningPercent for AntMinerV2020 {}
+impl GetApplianceConfig for AntMinerV2020 {}
+impl SetApplianceConfig for AntMinerV2020 {}
+
+#[async_trait]
+impl SupportsNetworkConfig for AntMinerV2020 {
+    async fn apply_network_config(&self, config: NetworkConfig) -> anyhow::Result<bool> {
+        // The hostname is always written, so keep the current one unless a new one is given.
+        let hostname = match config.hostname {
+            Some(hostname) => hostname,
+            None => parse_network_info(&self.web.get_network_info().await?)?
+                .hostname
+                .unwrap_or_default(),
+        };
+
+        if config.dhcp {
+            self.web
+                .set_network_conf(
+                    String::new(),
+                    String::new(),
+                    String::new(),
+                    String::new(),
+                    hostname,
+                    PROTOCOL_DHCP,
+                )
+                .await?;
+        } else {
+            self.web
+                .set_network_conf(
+                    addr_string(config.ip),
+                    dns_string(config.dns.as_deref()),
+                    addr_string(config.gateway),
+                    addr_string(config.netmask),
+                    hostname,
+                    PROTOCOL_STATIC,
+                )
+                .await?;
+        }
+        Ok(true)
+    }
+
+    fn parse_network_config(
+        &self,
+        data: &HashMap<ConfigField, Value>,
+    ) -> anyhow::Result<NetworkConfig> {
+        data.get(&ConfigField::Network)
+            .ok_or_else(|| anyhow::anyhow!("No network config data"))
+            .and_then(parse_network_info)
+    }
+
+    fn supports_network_config(&self) -> bool {
+        true
+    }
+}
 
 #[cfg(test)]
 mod tests {
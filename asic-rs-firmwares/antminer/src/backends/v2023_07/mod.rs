@@ -1,29 +1,47 @@
-use std::{collections::HashMap, fmt::Display, net::IpAddr, str::FromStr, time::Duration};
+use std::{collections::HashMap, net::IpAddr, str::FromStr, time::Duration};
 
 use self::firmware::resolve_firmware_image;
-use crate::firmware::AntMinerStockFirmware;
+use crate::{
+    backends::{
+        network::{
+            LINK_STATS_TAG, PROTOCOL_DHCP, PROTOCOL_STATIC, addr_string, dns_string,
+            parse_network_data, parse_network_info,
+        },
+        system_info,
+        work_mode::{
+            MinerMode, POWER_TARGET_KEY, power_target_value, work_mode_capabilities,
+            work_mode_for_power_limit,
+        },
+    },
+    firmware::AntMinerStockFirmware,
+};
 use anyhow;
 use asic_rs_core::{
     config::{
         collector::{ConfigCollector, ConfigExtractor, ConfigField, ConfigLocation},
         fan::FanConfig,
+        network::NetworkConfig,
         pools::{PoolConfig, PoolGroupConfig},
         tuning::TuningConfig,
     },
     data::{
         board::{BoardData, MinerControlBoard},
+        capabilities::TuningCapabilities,
         collector::{
             DataCollector, DataExtensions, DataExtractor, DataField, DataLocation, get_by_pointer,
         },
         command::MinerCommand,
+        control_board::ControlBoardData,
         device::{DeviceInfo, HashAlgorithm},
         fan::FanData,
         firmware::FirmwareImage,
         hashrate::{HashRate, HashRateUnit},
         message::{MessageSeverity, MinerMessage},
         miner::{MiningMode, TuningTarget},
+        network::{NetworkData, SYSFS_LINK_STATS_COMMAND},
         pool::{PoolData, PoolGroupData, PoolURL},
     },
+    ssh::SSHClient,
     traits::{miner::*, model::MinerModel},
 };
 use asic_rs_makes_antminer::hardware::AntMinerControlBoard;
@@ -43,27 +61,9 @@ pub struct AntMinerV202307 {
     pub ip: IpAddr,
     pub rpc: AntMinerRPCAPI,
     pub web: AntMinerWebAPI,
+    pub ssh: SSHClient,
     pub device_info: DeviceInfo,
-}
-
-#[allow(dead_code)]
-enum MinerMode {
-    Sleep,
-    Low,
-    Normal,
-    High,
-}
-
-impl Display for MinerMode {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let s = match self {
-            MinerMode::Sleep => "1",
-            MinerMode::Low => "3",
-            MinerMode::High => "2",
-            _ => "0",
-        };
-        f.write_str(s)
-    }
+    ssh_auth_set: bool,
 }
 
 fn miner_mode_config_key(miner_conf: &Value) -> Option<&'static str> {
@@ -122,11 +122,14 @@ impl AntMinerV202307 {
             ip,
             rpc: AntMinerRPCAPI::new(ip),
             web: AntMinerWebAPI::new(ip, auth),
+            // SSH logs in as the system root user, not the web UI account.
+            ssh: SSHClient::new(ip, MinerAuth::new("root", "admin")),
             device_info: DeviceInfo::new(
                 model,
                 AntMinerStockFirmware::default(),
                 HashAlgorithm::SHA256,
             ),
+            ssh_auth_set: false,
         }
     }
 
@@ -224,6 +227,7 @@ impl APIClient for AntMinerV202307 {
         match command {
             MinerCommand::RPC { .. } => self.rpc.get_api_result(command).await,
             MinerCommand::WebAPI { .. } => self.web.get_api_result(command).await,
+            MinerCommand::SSH { .. } => self.ssh.get_api_result(command).await,
             _ => Err(anyhow::anyhow!("Unsupported command type for Antminer API")),
         }
     }
@@ -252,6 +256,17 @@ impl GetConfigsLocations for AntMinerV202307 {
                     tag: None,
                 },
             )],
+            ConfigField::Network => vec![(
+                MinerCommand::WebAPI {
+                    command: "get_network_info",
+                    parameters: None,
+                },
+                ConfigExtractor {
+                    func: get_by_pointer,
+                    key: Some(""),
+                    tag: None,
+                },
+            )],
             _ => vec![],
         }
     }
@@ -290,6 +305,14 @@ impl GetDataLocations for AntMinerV202307 {
             parameters: None,
         };
 
+        const WEB_NETWORK_INFO: MinerCommand = MinerCommand::WebAPI {
+            command: "get_network_info",
+            parameters: None,
+        };
+        const SSH_LINK_STATS: MinerCommand = MinerCommand::SSH {
+            command: SYSFS_LINK_STATS_COMMAND,
+        };
+
         const WEB_BLINK_STATUS: MinerCommand = MinerCommand::WebAPI {
             command: "get_blink_status",
             parameters: None,
@@ -335,6 +358,14 @@ impl GetDataLocations for AntMinerV202307 {
                     tag: None,
                 },
             )],
+            DataField::ControlBoard => vec![(
+                WEB_SYSTEM_INFO,
+                DataExtractor {
+                    func: get_by_pointer,
+                    key: Some(""),
+                    tag: None,
+                },
+            )],
             DataField::Hostname => vec![(
                 WEB_SYSTEM_INFO,
                 DataExtractor {
@@ -343,6 +374,29 @@ impl GetDataLocations for AntMinerV202307 {
                     tag: None,
                 },
             )],
+            DataField::Network => {
+                let mut locations = vec![(
+                    WEB_NETWORK_INFO,
+                    DataExtractor {
+                        func: get_by_pointer,
+                        key: Some(""),
+                        tag: None,
+                    },
+                )];
+                // SSH is off by default on stock firmware, so a filtered port
+                // 22 would otherwise stall every poll.
+                if self.ssh_auth_set {
+                    locations.push((
+                        SSH_LINK_STATS,
+                        DataExtractor {
+                            func: get_by_pointer,
+                            key: Some(""),
+                            tag: Some(LINK_STATS_TAG),
+                        },
+                    ));
+                }
+                locations
+            }
             DataField::ControlBoardVersion => vec![(
                 WEB_MINER_TYPE,
                 DataExtractor {
@@ -449,7 +503,7 @@ impl GetDataLocations for AntMinerV202307 {
                     tag: None,
                 },
             )],
-            DataField::TuningTarget => vec![(
+            DataField::TuningTarget | DataField::TuningCapabilities => vec![(
                 WEB_MINER_CONF,
                 DataExtractor {
                     func: get_by_pointer,
@@ -493,6 +547,12 @@ impl GetHostname for AntMinerV202307 {
     }
 }
 
+impl GetNetwork for AntMinerV202307 {
+    fn parse_network(&self, data: &HashMap<DataField, Value>) -> Option<NetworkData> {
+        data.get(&DataField::Network).and_then(parse_network_data)
+    }
+}
+
 impl GetApiVersion for AntMinerV202307 {
     fn parse_api_version(&self, data: &HashMap<DataField, Value>) -> Option<String> {
         data.extract::<String>(DataField::ApiVersion)
@@ -717,6 +777,13 @@ impl GetControlBoardVersion for AntMinerV202307 {
     }
 }
 
+impl GetControlBoard for AntMinerV202307 {
+    fn parse_control_board(&self, data: &HashMap<DataField, Value>) -> Option<ControlBoardData> {
+        data.get(&DataField::ControlBoard)
+            .and_then(system_info::parse_control_board)
+    }
+}
+
 impl GetWattage for AntMinerV202307 {
     fn parse_wattage(&self, data: &HashMap<DataField, Value>) -> Option<Power> {
         if let Some(stats_data) = data.get(&DataField::Wattage) {
@@ -792,7 +859,18 @@ impl GetFluidTemperature for AntMinerV202307 {
 }
 
 impl GetPsuFans for AntMinerV202307 {}
-impl GetTuningCapabilities for AntMinerV202307 {}
+
+impl GetPsus for AntMinerV202307 {}
+
+impl GetTuningCapabilities for AntMinerV202307 {
+    fn parse_tuning_capabilities(
+        &self,
+        data: &HashMap<DataField, Value>,
+    ) -> Option<TuningCapabilities> {
+        data.get(&DataField::TuningCapabilities)
+            .and_then(work_mode_capabilities)
+    }
+}
 
 impl GetMessages for AntMinerV202307 {
     fn parse_messages(&self, data: &HashMap<DataField, Value>) -> Vec<MinerMessage> {
@@ -842,7 +920,34 @@ impl SetFaultLight for AntMinerV202307 {
 #[async_trait]
 impl SetPowerLimit for AntMinerV202307 {
     fn supports_set_power_limit(&self) -> bool {
-        false
+        true
+    }
+
+    async fn set_power_limit(&self, limit: Power) -> anyhow::Result<bool> {
+        let pre = self.web.get_miner_conf().await?;
+        if let Some(target) = power_target_value(&pre, limit) {
+            self.web
+                .set_miner_conf(json!({ POWER_TARGET_KEY: target }))
+                .await?;
+            return Ok(true);
+        }
+
+        let current = miner_conf_mining_mode(&pre)
+            .ok_or_else(|| anyhow::anyhow!("No Antminer mining mode found in miner config"))?;
+        let wattage = self
+            .get_wattage()
+            .await
+            .ok_or_else(|| anyhow::anyhow!("Antminer did not report its power draw"))?;
+        let mode = MinerMode::from(work_mode_for_power_limit(limit, wattage, current)?);
+
+        let Some(mode_key) = miner_mode_config_key(&pre) else {
+            anyhow::bail!("No Antminer mining mode field found in miner config")
+        };
+
+        self.web
+            .set_miner_conf(json!({ mode_key: mode.to_string() }))
+            .await?;
+        Ok(true)
     }
 }
 
@@ -1041,6 +1146,8 @@ impl ReadLogs for AntMinerV202307 {
     }
 }
 
+impl SetSyslogTarget for AntMinerV202307 {}
+
 #[async_trait]
 impl FactoryReset for AntMinerV202307 {
     async fn factory_reset(&self) -> anyhow::Result<bool> {
@@ -1083,6 +1190,13 @@ impl HasAuth for AntMinerV202307 {
     fn set_auth(&mut self, auth: MinerAuth) {
         self.web.set_auth(auth);
     }
+
+    /// Also enables reading the link stats in [`DataField::Network`], which
+    /// are only available over SSH.
+    fn set_ssh_auth(&mut self, auth: MinerAuth) {
+        self.ssh.set_auth(auth);
+        self.ssh_auth_set = true;
+    }
 }
 
 #[async_trait]
@@ -1092,6 +1206,7 @@ impl SupportsTuningConfig for AntMinerV202307 {
         config: TuningConfig,
         _scaling_config: Option<asic_rs_core::config::scaling::ScalingConfig>,
     ) -> anyhow::Result<bool> {
+        config.ensure_target_only()?;
         let mode = match config.target {
             TuningTarget::MiningMode(MiningMode::Low) => MinerMode::Low,
             TuningTarget::MiningMode(MiningMode::Normal) => MinerMode::Normal,
@@ -1133,9 +1248,10 @@ impl SupportsTuningConfig for AntMinerV202307 {
 #[async_trait]
 impl SupportsFanConfig for AntMinerV202307 {
     async fn set_fan_config(&self, config: FanConfig) -> anyhow::Result<bool> {
+        config.ensure_no_min_fans()?;
         let payload = match config {
             FanConfig::Auto { .. } => json!({"bitmain-fan-ctrl": false}),
-            FanConfig::Manual { fan_speed } => json!({
+            FanConfig::Manual { fan_speed, .. } => json!({
                 "bitmain-fan-ctrl": true,
                 "bitmain-fan-pwm": fan_speed.min(100).to_string(),
             }),
@@ -1174,6 +1290,59 @@ impl SupportsFanConfig for AntMinerV202307 {
 impl SupportsTemperatureConfig for AntMinerV202307 {}
 impl GetTuningPercent for AntMinerV202307 {}
 impl SetTuningPercent for AntMinerV202307 {}
+impl GetApplianceConfig for AntMinerV202307 {}
+impl SetApplianceConfig for AntMinerV202307 {}
+
+#[async_trait]
+impl SupportsNetworkConfig for AntMinerV202307 {
+    async fn apply_network_config(&self, config: NetworkConfig) -> anyhow::Result<bool> {
+        // The hostname is always written, so keep the current one unless a new one is given.
+        let hostname = match config.hostname {
+            Some(hostname) => hostname,
+            None => parse_network_info(&self.web.get_network_info().await?)?
+                .hostname
+                .unwrap_or_default(),
+        };
+
+        if config.dhcp {
+            self.web
+                .set_network_conf(
+                    String::new(),
+                    String::new(),
+                    String::new(),
+                    String::new(),
+                    hostname,
+                    PROTOCOL_DHCP,
+                )
+                .await?;
+        } else {
+            self.web
+                .set_network_conf(
+                    addr_string(config.ip),
+                    dns_string(config.dns.as_deref()),
+                    addr_string(config.gateway),
+                    addr_string(config.netmask),
+                    hostname,
+                    PROTOCOL_STATIC,
+                )
+                .await?;
+        }
+        Ok(true)
+    }
+
+    fn parse_network_config(
+        &self,
+        data: &HashMap<ConfigField, Value>,
+    ) -> anyhow::Result<NetworkConfig> {
+        data.get(&ConfigField::Network)
+            .ok_or_else(|| anyhow::anyhow!("No network config data"))
+            .and_then(parse_network_info)
+    }
+
+    fn supports_network_config(&self) -> bool {
+        true
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,198 @@
+use asic_rs_core::data::{
+    capabilities::{PowerTuningCapabilities, PresetTuningCapabilities, TuningCapabilities},
+    miner::{MiningMode, TuningTarget},
+};
+use std::fmt::Display;
+
+use measurements::Power;
+use serde_json::Value;
+
+/// Miner config key holding the power target, in watts, on stock firmware
+/// that exposes one.
+pub(crate) const POWER_TARGET_KEY: &str = "power-target";
+
+/// Work mode values written to `miner-mode` / `bitmain-work-mode`.
+#[allow(dead_code)]
+pub(crate) enum MinerMode {
+    Sleep,
+    Low,
+    Normal,
+    High,
+}
+
+impl From<MiningMode> for MinerMode {
+    fn from(mode: MiningMode) -> Self {
+        match mode {
+            MiningMode::Low => MinerMode::Low,
+            MiningMode::Normal => MinerMode::Normal,
+            MiningMode::High => MinerMode::High,
+        }
+    }
+}
+
+impl Display for MinerMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            MinerMode::Sleep => "1",
+            MinerMode::Low => "3",
+            MinerMode::High => "2",
+            _ => "0",
+        };
+        f.write_str(s)
+    }
+}
+
+/// Approximate power draw of each work mode relative to normal mode, lowest first.
+///
+/// Stock firmware does not report per-mode wattage, so these ratios are used to
+/// estimate what each mode will draw from the miner's current consumption.
+const MODE_POWER_RATIOS: [(MiningMode, f64); 3] = [
+    (MiningMode::Low, 0.75),
+    (MiningMode::Normal, 1.0),
+    (MiningMode::High, 1.15),
+];
+
+fn power_ratio(mode: MiningMode) -> f64 {
+    MODE_POWER_RATIOS
+        .iter()
+        .find(|(m, _)| *m == mode)
+        .map(|(_, ratio)| *ratio)
+        .unwrap_or(1.0)
+}
+
+/// Work modes selectable through `miner-mode` / `bitmain-work-mode`, and the
+/// power target when the miner config exposes one.
+pub(crate) fn work_mode_capabilities(miner_conf: &Value) -> Option<TuningCapabilities> {
+    let presets = ["miner-mode", "bitmain-work-mode"]
+        .iter()
+        .any(|key| miner_conf.get(key).is_some())
+        .then(|| PresetTuningCapabilities {
+            default: Some(TuningTarget::MiningMode(MiningMode::Normal)),
+            presets: MODE_POWER_RATIOS
+                .iter()
+                .map(|(mode, _)| TuningTarget::MiningMode(*mode))
+                .collect(),
+        });
+    let power = miner_conf
+        .get(POWER_TARGET_KEY)
+        .map(|_| PowerTuningCapabilities::default());
+
+    (presets.is_some() || power.is_some()).then_some(TuningCapabilities {
+        power,
+        presets,
+        ..Default::default()
+    })
+}
+
+/// The `power-target` value to write for `limit`, matching the type the
+/// firmware reported, or `None` when the firmware has no power target.
+pub(crate) fn power_target_value(miner_conf: &Value, limit: Power) -> Option<Value> {
+    let watts = limit.as_watts().round() as u64;
+    match miner_conf.get(POWER_TARGET_KEY)? {
+        Value::String(_) => Some(Value::String(watts.to_string())),
+        _ => Some(Value::from(watts)),
+    }
+}
+
+/// Pick the highest work mode expected to stay within `limit`, given that the
+/// miner currently draws `wattage` in `current` mode.
+///
+/// This is an estimate for firmware without a power target. It errors when
+/// even low power mode would exceed the limit, rather than putting the miner
+/// to sleep.
+pub(crate) fn work_mode_for_power_limit(
+    limit: Power,
+    wattage: Power,
+    current: MiningMode,
+) -> anyhow::Result<MiningMode> {
+    anyhow::ensure!(
+        wattage.as_watts() > 0.0,
+        "Cannot pick a work mode without the miner's current power draw"
+    );
+    let normal = wattage.as_watts() / power_ratio(current);
+
+    MODE_POWER_RATIOS
+        .iter()
+        .rev()
+        .find(|(_, ratio)| normal * ratio <= limit.as_watts())
+        .map(|(mode, _)| *mode)
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "No work mode fits a {} W limit; low power mode is expected to draw about {:.0} W",
+                limit.as_watts(),
+                normal * power_ratio(MiningMode::Low)
+            )
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn power_limit_picks_highest_mode_within_limit() -> anyhow::Result<()> {
+        let wattage = Power::from_watts(3000.0);
+
+        assert_eq!(
+            work_mode_for_power_limit(Power::from_watts(3500.0), wattage, MiningMode::Normal)?,
+            MiningMode::High
+        );
+        assert_eq!(
+            work_mode_for_power_limit(Power::from_watts(2900.0), wattage, MiningMode::Normal)?,
+            MiningMode::Low
+        );
+        assert_eq!(
+            work_mode_for_power_limit(Power::from_watts(3000.0), wattage, MiningMode::Low)?,
+            MiningMode::Low
+        );
+        assert!(
+            work_mode_for_power_limit(Power::from_watts(1000.0), wattage, MiningMode::Normal)
+                .is_err()
+        );
+        assert!(
+            work_mode_for_power_limit(
+                Power::from_watts(1000.0),
+                Power::from_watts(0.0),
+                MiningMode::Normal
+            )
+            .is_err()
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn power_target_keeps_the_firmware_value_type() {
+        let limit = Power::from_watts(2000.4);
+
+        assert_eq!(power_target_value(&json!({ "miner-mode": 0 }), limit), None);
+        assert_eq!(
+            power_target_value(&json!({ "power-target": "3000" }), limit),
+            Some(json!("2000"))
+        );
+        assert_eq!(
+            power_target_value(&json!({ "power-target": 3000 }), limit),
+            Some(json!(2000))
+        );
+    }
+
+    #[test]
+    fn capabilities_require_work_mode_key() {
+        assert!(work_mode_capabilities(&json!({ "bitmain-fan-ctrl": false })).is_none());
+
+        let capabilities = work_mode_capabilities(&json!({ "miner-mode": 0 }))
+            .and_then(|c| c.presets)
+            .expect("work mode presets");
+        assert_eq!(capabilities.presets.len(), 3);
+        assert_eq!(
+            capabilities.default,
+            Some(TuningTarget::MiningMode(MiningMode::Normal))
+        );
+
+        let capabilities =
+            work_mode_capabilities(&json!({ "power-target": 3000 })).expect("power capabilities");
+        assert!(capabilities.power.is_some());
+        assert!(capabilities.presets.is_none());
+    }
+}
@@ -7,3 +7,4 @@ pub(crate) const AM_POOLS: &str = include_str!("pools.json");
 pub(crate) const AM_SUMMARY: &str = include_str!("summary.json");
 pub(crate) const AM_SYSTEM_INFO: &str = include_str!("system_info.json");
 pub(crate) const AM_VERSION: &str = include_str!("version.json");
+pub(crate) const AM_SSH_LINK_STATS: &str = include_str!("ssh_link_stats.txt");
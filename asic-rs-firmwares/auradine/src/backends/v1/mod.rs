@@ -12,7 +12,10 @@ use asic_rs_core::{
             ConfigCollector, ConfigExtractor, ConfigField, ConfigLocation,
             get_by_pointer as get_config_by_pointer,
         },
+        fan::FanConfig,
         pools::{PoolConfig, PoolGroupConfig},
+        scaling::ScalingConfig,
+        tuning::TuningConfig,
     },
     data::{
         board::{BoardData, ChipData, MinerControlBoard},
@@ -22,19 +25,21 @@ use asic_rs_core::{
         command::MinerCommand,
         device::{DeviceInfo, HashAlgorithm},
         fan::FanData,
+        firmware::{self, FirmwareImage},
         hashrate::{HashRate, HashRateUnit},
         message::{MessageSeverity, MinerMessage},
         miner::{MiningMode, TuningTarget},
         pool::{PoolData, PoolGroupData, PoolURL},
+        psu::PsuData,
     },
     traits::{miner::*, model::MinerModel},
 };
 use asic_rs_makes_auradine::hardware::AuradineControlBoard;
 use async_trait::async_trait;
 use macaddr::MacAddr;
-use measurements::{AngularVelocity, Frequency, Power, Temperature, Voltage};
+use measurements::{AngularVelocity, Current, Frequency, Power, Temperature, Voltage};
 use reqwest::Method;
-use serde_json::{Value, json};
+use serde_json::{Map, Value, json};
 
 use crate::firmware::AuradineFirmware;
 
@@ -44,6 +49,10 @@ pub(crate) mod web;
 use rpc::AuradineRPCAPI;
 use web::AuradineWebAPI;
 
+/// How long the miner may take to flash an upgrade, reboot and report the new
+/// firmware version.
+const UPGRADE_TIMEOUT: Duration = Duration::from_secs(15 * 60);
+
 #[derive(Debug)]
 pub struct AuradineV1 {
     ip: IpAddr,
@@ -156,6 +165,88 @@ impl AuradineV1 {
 
         Ok(pools)
     }
+
+    fn ths_target(ths: f64) -> TuningTarget {
+        TuningTarget::HashRate(
+            HashRate {
+                value: ths,
+                unit: HashRateUnit::TeraHash,
+                algo: String::from("SHA256"),
+            }
+            .as_unit(HashRateUnit::default()),
+        )
+    }
+
+    fn tuning_target_from_mode(mode: &Map<String, Value>) -> Option<TuningTarget> {
+        if let Some(power) = mode.get("Power").and_then(Value::as_f64) {
+            return Some(TuningTarget::Power(Power::from_watts(power)));
+        }
+
+        if let Some(ths) = mode.get("Ths").and_then(Value::as_f64) {
+            return Some(Self::ths_target(ths));
+        }
+
+        match mode.get("Mode").and_then(Value::as_str) {
+            Some(s) if s.eq_ignore_ascii_case("eco") => {
+                Some(TuningTarget::MiningMode(MiningMode::Low))
+            }
+            Some(s) if s.eq_ignore_ascii_case("normal") => {
+                Some(TuningTarget::MiningMode(MiningMode::Normal))
+            }
+            Some(s) if s.eq_ignore_ascii_case("turbo") => {
+                Some(TuningTarget::MiningMode(MiningMode::High))
+            }
+            _ => None,
+        }
+    }
+
+    fn build_mode_payload(target: &TuningTarget) -> Value {
+        match target {
+            TuningTarget::MiningMode(mode) => {
+                let mode = match mode {
+                    MiningMode::Low => "eco",
+                    MiningMode::Normal => "normal",
+                    MiningMode::High => "turbo",
+                };
+                json!({ "mode": mode })
+            }
+            TuningTarget::Power(power) => json!({
+                "mode": "custom",
+                "tune": "power",
+                "power": power.as_watts().round() as u64,
+            }),
+            TuningTarget::HashRate(hashrate) => json!({
+                "mode": "custom",
+                "tune": "ths",
+                "ths": hashrate.clone().as_unit(HashRateUnit::TeraHash).value.round() as u64,
+            }),
+        }
+    }
+
+    /// The fixed speed percentage of a fan from the `fan` response, or `None`
+    /// when the fan is under firmware control. The API reports target and
+    /// maximum RPM per fan; fans under firmware control report a non-manual
+    /// mode or no target RPM.
+    fn fixed_fan_speed(fan: &Value) -> anyhow::Result<Option<u64>> {
+        let manual_mode = fan
+            .get("Mode")
+            .and_then(Value::as_str)
+            .is_none_or(|mode| mode.eq_ignore_ascii_case("manual"));
+        let target = fan
+            .get("Target")
+            .and_then(Value::as_f64)
+            .filter(|target| *target > 0.0);
+        let (true, Some(target)) = (manual_mode, target) else {
+            return Ok(None);
+        };
+        let max = fan
+            .get("Max")
+            .and_then(Value::as_f64)
+            .filter(|max| *max > 0.0)
+            .ok_or_else(|| anyhow::anyhow!("Missing fan maximum speed"))?;
+
+        Ok(Some((target / max * 100.0).round().clamp(0.0, 100.0) as u64))
+    }
 }
 
 #[async_trait]
@@ -175,6 +266,14 @@ impl GetConfigsLocations for AuradineV1 {
             command: "pools",
             parameters: None,
         };
+        const WEB_MODE: MinerCommand = MinerCommand::WebAPI {
+            command: "mode",
+            parameters: None,
+        };
+        const WEB_FAN: MinerCommand = MinerCommand::WebAPI {
+            command: "fan",
+            parameters: None,
+        };
 
         match data_field {
             ConfigField::Pools => vec![(
@@ -185,6 +284,22 @@ impl GetConfigsLocations for AuradineV1 {
                     tag: None,
                 },
             )],
+            ConfigField::Tuning => vec![(
+                WEB_MODE,
+                ConfigExtractor {
+                    func: get_config_by_pointer,
+                    key: Some("/Mode/0"),
+                    tag: None,
+                },
+            )],
+            ConfigField::Fan => vec![(
+                WEB_FAN,
+                ConfigExtractor {
+                    func: get_config_by_pointer,
+                    key: Some("/Fan"),
+                    tag: None,
+                },
+            )],
             _ => vec![],
         }
     }
@@ -442,6 +557,14 @@ impl GetDataLocations for AuradineV1 {
                     tag: None,
                 },
             )],
+            DataField::Psus => vec![(
+                WEB_PSU,
+                DataExtractor {
+                    func: get_by_pointer,
+                    key: Some("/PSU"),
+                    tag: None,
+                },
+            )],
             DataField::Wattage => vec![
                 (
                     RPC_SUMMARY,
@@ -570,6 +693,8 @@ impl GetHostname for AuradineV1 {
     }
 }
 
+impl GetNetwork for AuradineV1 {}
+
 impl GetApiVersion for AuradineV1 {
     fn parse_api_version(&self, data: &HashMap<DataField, Value>) -> Option<String> {
         data.extract::<String>(DataField::ApiVersion)
@@ -610,6 +735,8 @@ impl GetControlBoardVersion for AuradineV1 {
     }
 }
 
+impl GetControlBoard for AuradineV1 {}
+
 impl GetHashboards for AuradineV1 {
     fn parse_hashboards(&self, data: &HashMap<DataField, Value>) -> Vec<BoardData> {
         let api_data = data.get(&DataField::Hashboards);
@@ -1057,6 +1184,44 @@ impl GetPsuFans for AuradineV1 {
     }
 }
 
+impl GetPsus for AuradineV1 {
+    fn parse_psus(&self, data: &HashMap<DataField, Value>) -> Vec<PsuData> {
+        let Some(psus) = data.get(&DataField::Psus).and_then(Value::as_array) else {
+            return vec![];
+        };
+
+        psus.iter()
+            .enumerate()
+            .filter_map(|(idx, psu)| {
+                let psu = psu.as_object()?;
+                let reading = |key: &str| {
+                    psu.get(key)
+                        .and_then(Value::as_str)
+                        .and_then(Self::parse_number_from_units)
+                };
+                let fan_speeds: Vec<f64> = psu
+                    .iter()
+                    .filter(|(key, _)| key.starts_with("FanSpeed"))
+                    .filter_map(|(_, value)| value.as_str().and_then(Self::parse_number_from_units))
+                    .collect();
+
+                let mut psu_data = PsuData::new(u8::try_from(idx).ok()?);
+                psu_data.input_voltage = reading("Vin").map(Voltage::from_volts);
+                psu_data.input_current = reading("Iin").map(Current::from_amperes);
+                psu_data.output_voltage = reading("Vout").map(Voltage::from_volts);
+                psu_data.output_power = reading("PowerOut").map(Power::from_watts);
+                psu_data.temperature = reading("Temp").map(Temperature::from_celsius);
+                psu_data.fan_rpm = (!fan_speeds.is_empty()).then(|| {
+                    AngularVelocity::from_rpm(
+                        fan_speeds.iter().sum::<f64>() / fan_speeds.len() as f64,
+                    )
+                });
+                Some(psu_data)
+            })
+            .collect()
+    }
+}
+
 impl GetFluidTemperature for AuradineV1 {}
 
 impl GetWattage for AuradineV1 {
@@ -1075,45 +1240,11 @@ impl GetWattage for AuradineV1 {
 impl GetTuningTarget for AuradineV1 {
     fn parse_tuning_target(&self, data: &HashMap<DataField, Value>) -> Option<TuningTarget> {
         if let Some(ths) = data.extract::<f64>(DataField::TuningTarget) {
-            return Some(TuningTarget::HashRate(
-                HashRate {
-                    value: ths,
-                    unit: HashRateUnit::TeraHash,
-                    algo: String::from("SHA256"),
-                }
-                .as_unit(HashRateUnit::default()),
-            ));
+            return Some(Self::ths_target(ths));
         }
 
         let mode = data.get(&DataField::TuningTarget)?.as_object()?;
-
-        if let Some(power) = mode.get("Power").and_then(Value::as_f64) {
-            return Some(TuningTarget::Power(Power::from_watts(power)));
-        }
-
-        if let Some(ths) = mode.get("Ths").and_then(Value::as_f64) {
-            return Some(TuningTarget::HashRate(
-                HashRate {
-                    value: ths,
-                    unit: HashRateUnit::TeraHash,
-                    algo: String::from("SHA256"),
-                }
-                .as_unit(HashRateUnit::default()),
-            ));
-        }
-
-        match mode.get("Mode").and_then(Value::as_str) {
-            Some(s) if s.eq_ignore_ascii_case("eco") => {
-                Some(TuningTarget::MiningMode(MiningMode::Low))
-            }
-            Some(s) if s.eq_ignore_ascii_case("normal") => {
-                Some(TuningTarget::MiningMode(MiningMode::Normal))
-            }
-            Some(s) if s.eq_ignore_ascii_case("turbo") => {
-                Some(TuningTarget::MiningMode(MiningMode::High))
-            }
-            _ => None,
-        }
+        Self::tuning_target_from_mode(mode)
     }
 }
 
@@ -1255,17 +1386,9 @@ impl SetFaultLight for AuradineV1 {
 #[async_trait]
 impl SetPowerLimit for AuradineV1 {
     async fn set_power_limit(&self, limit: Power) -> anyhow::Result<bool> {
+        let payload = Self::build_mode_payload(&TuningTarget::Power(limit));
         self.web
-            .send_command(
-                "mode",
-                true,
-                Some(json!({
-                    "mode": "custom",
-                    "tune": "power",
-                    "power": limit.as_watts().round() as u64,
-                })),
-                Method::POST,
-            )
+            .send_command("mode", true, Some(payload), Method::POST)
             .await?;
         Ok(true)
     }
@@ -1374,18 +1497,40 @@ impl Resume for AuradineV1 {
     }
 }
 
+#[async_trait]
 impl ChangePassword for AuradineV1 {
+    async fn change_password(&mut self, password: &str) -> anyhow::Result<bool> {
+        let username = self.web.username().to_string();
+        self.web
+            .send_command(
+                "password",
+                true,
+                Some(json!({ "user": username, "password": password })),
+                Method::POST,
+            )
+            .await?;
+        self.set_auth(MinerAuth::new(username, password));
+        Ok(true)
+    }
+
     fn supports_change_password(&self) -> bool {
-        false
+        true
     }
 }
 
+#[async_trait]
 impl ReadLogs for AuradineV1 {
+    async fn read_logs(&self) -> anyhow::Result<String> {
+        self.web.read_logs().await
+    }
+
     fn supports_read_logs(&self) -> bool {
-        false
+        true
     }
 }
 
+impl SetSyslogTarget for AuradineV1 {}
+
 impl FactoryReset for AuradineV1 {
     fn supports_factory_reset(&self) -> bool {
         false
@@ -1401,8 +1546,15 @@ impl SupportsScalingConfig for AuradineV1 {
 
 #[async_trait]
 impl UpgradeFirmware for AuradineV1 {
+    async fn upgrade_firmware(&self, image: FirmwareImage) -> anyhow::Result<bool> {
+        let previous = firmware::version_before_upgrade(self).await?;
+        self.web.upload_firmware(image).await?;
+        firmware::wait_for_upgraded_version(self, &previous, None, UPGRADE_TIMEOUT).await?;
+        Ok(true)
+    }
+
     fn supports_upgrade_firmware(&self) -> bool {
-        false
+        true
     }
 }
 
@@ -1420,21 +1572,119 @@ impl HasAuth for AuradineV1 {
 
 #[async_trait]
 impl SupportsTuningConfig for AuradineV1 {
+    async fn set_tuning_config(
+        &self,
+        config: TuningConfig,
+        _scaling_config: Option<ScalingConfig>,
+    ) -> anyhow::Result<bool> {
+        config.ensure_target_only()?;
+        let payload = Self::build_mode_payload(&config.target);
+        self.web
+            .send_command("mode", true, Some(payload), Method::POST)
+            .await?;
+        Ok(true)
+    }
+
+    fn parse_tuning_config(
+        &self,
+        data: &HashMap<ConfigField, Value>,
+    ) -> anyhow::Result<TuningConfig> {
+        data.get(&ConfigField::Tuning)
+            .and_then(Value::as_object)
+            .and_then(Self::tuning_target_from_mode)
+            .map(TuningConfig::new)
+            .ok_or_else(|| anyhow::anyhow!("No operating mode in Auradine mode response"))
+    }
+
     fn supports_tuning_config(&self) -> bool {
-        false
+        true
     }
 }
 
 #[async_trait]
 impl SupportsFanConfig for AuradineV1 {
+    async fn set_fan_config(&self, config: FanConfig) -> anyhow::Result<bool> {
+        config.ensure_no_min_fans()?;
+        let fan_speed = match config {
+            FanConfig::Manual { fan_speed, .. } => {
+                anyhow::ensure!(fan_speed <= 100, "Fan speed must be between 0 and 100");
+                Some(fan_speed)
+            }
+            FanConfig::Auto {
+                target_temp: None,
+                idle_speed: None,
+                ..
+            } => None,
+            FanConfig::Auto { .. } => anyhow::bail!(
+                "Auradine automatic fan control has no target temperature or idle speed"
+            ),
+        };
+
+        let fans = self
+            .web
+            .send_command("fan", false, None, Method::GET)
+            .await?;
+        let fans = fans
+            .get("Fan")
+            .and_then(Value::as_array)
+            .cloned()
+            .unwrap_or_default();
+        anyhow::ensure!(!fans.is_empty(), "No fans reported by the miner");
+
+        let Some(fan_speed) = fan_speed else {
+            // The API can only set a fixed speed, so automatic control can be
+            // kept but not restored once a fixed speed was set.
+            for fan in &fans {
+                anyhow::ensure!(
+                    Self::fixed_fan_speed(fan)?.is_none(),
+                    "Auradine fans at a fixed speed cannot be handed back to firmware control"
+                );
+            }
+            return Ok(true);
+        };
+
+        let ids = fans
+            .iter()
+            .filter_map(|fan| fan.get("ID").and_then(Value::as_u64))
+            .collect::<Vec<_>>();
+        anyhow::ensure!(!ids.is_empty(), "No fan IDs reported by the miner");
+
+        for id in ids {
+            self.web
+                .send_command(
+                    "fan",
+                    true,
+                    Some(json!({ "index": id, "percentage": fan_speed })),
+                    Method::POST,
+                )
+                .await?;
+        }
+        Ok(true)
+    }
+
+    fn parse_fan_config(&self, data: &HashMap<ConfigField, Value>) -> anyhow::Result<FanConfig> {
+        let fan = data
+            .get(&ConfigField::Fan)
+            .and_then(Value::as_array)
+            .and_then(|fans| fans.first())
+            .ok_or_else(|| anyhow::anyhow!("No fans in Auradine fan response"))?;
+        Ok(match Self::fixed_fan_speed(fan)? {
+            Some(fan_speed) => FanConfig::manual(fan_speed),
+            None => FanConfig::auto(None, None),
+        })
+    }
+
     fn supports_fan_config(&self) -> bool {
-        false
+        true
     }
 }
 
 impl SupportsTemperatureConfig for AuradineV1 {}
 impl GetTuningPercent for AuradineV1 {}
 impl SetTuningPercent for AuradineV1 {}
+impl GetApplianceConfig for AuradineV1 {}
+impl SetApplianceConfig for AuradineV1 {}
+impl SupportsNetworkConfig for AuradineV1 {}
 
 #[cfg(test)]
 mod tests {
@@ -1608,6 +1858,23 @@ mod tests {
         assert_eq!(miner_data.wattage, Some(Power::from_watts(58.94)));
         assert_eq!(miner_data.fans.len(), 4);
         assert_eq!(miner_data.psu_fans.len(), 2);
+        assert_eq!(miner_data.psus.len(), 1);
+        assert_eq!(
+            miner_data.psus[0].input_voltage,
+            Some(Voltage::from_volts(245.0))
+        );
+        assert_eq!(
+            miner_data.psus[0].input_current,
+            Some(Current::from_amperes(0.28))
+        );
+        assert_eq!(
+            miner_data.psus[0].output_voltage,
+            Some(Voltage::from_volts(11.53))
+        );
+        assert_eq!(
+            miner_data.psus[0].fan_rpm,
+            Some(AngularVelocity::from_rpm(4098.0))
+        );
         assert_eq!(miner_data.hashboards.len(), 3);
         assert_eq!(
             miner_data
@@ -1715,6 +1982,61 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn parse_tuning_and_fan_config_test() -> anyhow::Result<()> {
+        let miner = AuradineV1::new(IpAddr::from([127, 0, 0, 1]), AuradineModel::AT1500);
+        let mut results = HashMap::new();
+        results.insert(
+            MinerCommand::WebAPI {
+                command: "mode",
+                parameters: None,
+            },
+            Value::from_str(MODE)?,
+        );
+        results.insert(
+            MinerCommand::WebAPI {
+                command: "fan",
+                parameters: None,
+            },
+            Value::from_str(FAN)?,
+        );
+
+        let mock_api = MockAPIClient::new(results);
+        let mut collector = ConfigCollector::new_with_client(&miner, &mock_api);
+        let data = collector
+            .collect(&[ConfigField::Tuning, ConfigField::Fan])
+            .await;
+
+        let tuning = miner.parse_tuning_config(&data)?;
+        assert_eq!(
+            tuning.target,
+            TuningTarget::Power(Power::from_watts(2000.0))
+        );
+        assert_eq!(miner.parse_fan_config(&data)?, FanConfig::manual(91));
+
+        for fan in [
+            json!([{ "ID": 1, "Max": 7700, "Speed": 5000, "Target": 0 }]),
+            json!([{ "ID": 1, "Max": 7700, "Speed": 5000, "Mode": "Auto", "Target": 5000 }]),
+        ] {
+            let data = HashMap::from([(ConfigField::Fan, fan)]);
+            assert_eq!(miner.parse_fan_config(&data)?, FanConfig::auto(None, None));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn build_mode_payload_maps_targets() {
+        assert_eq!(
+            AuradineV1::build_mode_payload(&TuningTarget::MiningMode(MiningMode::Low)),
+            json!({ "mode": "eco" })
+        );
+        assert_eq!(
+            AuradineV1::build_mode_payload(&AuradineV1::ths_target(160.0)),
+            json!({ "mode": "custom", "tune": "ths", "ths": 160 })
+        );
+    }
+
     #[tokio::test]
     async fn parse_is_mining_prefers_sleep_state() -> anyhow::Result<()> {
         let miner = AuradineV1::new(IpAddr::from([127, 0, 0, 1]), AuradineModel::AT1500);
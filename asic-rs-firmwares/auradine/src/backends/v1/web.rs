@@ -2,12 +2,15 @@ use std::{net::IpAddr, time::Duration};
 
 use anyhow::{Context, Result, anyhow, bail};
 use asic_rs_core::{
-    data::command::{MinerCommand, RPCCommandStatus},
+    data::{
+        command::{MinerCommand, RPCCommandStatus},
+        firmware::FirmwareImage,
+    },
     traits::miner::*,
 };
 use async_trait::async_trait;
 use once_cell::sync::OnceCell;
-use reqwest::{Client, Method, Response, StatusCode};
+use reqwest::{Client, Method, Response, StatusCode, multipart};
 use serde_json::{Map, Value, json};
 use tokio::sync::RwLock;
 
@@ -40,6 +43,10 @@ impl AuradineWebAPI {
         *self.token.get_mut() = None;
     }
 
+    pub fn username(&self) -> &str {
+        self.auth.username()
+    }
+
     fn endpoint_url(&self, command: &str) -> String {
         let endpoint = command.trim_start_matches('/');
         format!("http://{}:{}/{}", self.ip, self.port, endpoint)
@@ -160,6 +167,68 @@ impl AuradineWebAPI {
 
         Ok(response)
     }
+
+    /// Download the GCMiner log as plain text.
+    pub async fn read_logs(&self) -> Result<String> {
+        self.ensure_authenticated().await?;
+
+        let url = self.endpoint_url("log");
+        let token = self.token.read().await.clone();
+        let response = self
+            .execute_request(&url, &Method::GET, None, token)
+            .await?;
+
+        let status = response.status();
+        if !status.is_success() {
+            bail!("Log request failed with status code {status}");
+        }
+
+        response
+            .text()
+            .await
+            .map_err(|e| anyhow!("Failed to read log response body: {e}"))
+    }
+
+    /// Upload a firmware image to `firmware-upgrade`. The miner flashes it and
+    /// reboots on its own once the upload is accepted.
+    pub async fn upload_firmware(&self, image: FirmwareImage) -> Result<()> {
+        self.ensure_authenticated().await?;
+
+        let url = self.endpoint_url("firmware-upgrade");
+        let FirmwareImage { filename, bytes } = image;
+        let form = multipart::Form::new().part(
+            "file",
+            multipart::Part::bytes(bytes)
+                .file_name(filename)
+                .mime_str("application/octet-stream")
+                .context("failed to set firmware part mime type")?,
+        );
+
+        let mut request = self
+            .client()?
+            .post(url)
+            .multipart(form)
+            .timeout(self.timeout.max(Duration::from_secs(600)));
+        if let Some(ref token) = *self.token.read().await {
+            request = request.header("Token", token);
+        }
+
+        let response = request
+            .send()
+            .await
+            .context("firmware upload HTTP request failed")?;
+        let status = response.status();
+        let body = response
+            .text()
+            .await
+            .context("failed to read firmware upload response body")?;
+        if !status.is_success() {
+            bail!("Firmware upload failed with status code {status}: {body}");
+        }
+
+        RPCCommandStatus::from_auradine_v1(&body)?.into_result()?;
+        Ok(())
+    }
 }
 
 #[async_trait]
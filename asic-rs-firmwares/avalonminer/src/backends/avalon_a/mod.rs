@@ -1,18 +1,17 @@
-use std::{
-    collections::HashMap,
-    net::IpAddr,
-    str::FromStr,
-    time::{Duration, SystemTime, UNIX_EPOCH},
-};
+use std::{collections::HashMap, net::IpAddr, str::FromStr, time::Duration};
 
 use anyhow;
 use asic_rs_core::{
     config::{
-        collector::{ConfigCollector, ConfigField, ConfigLocation},
+        collector::{ConfigCollector, ConfigExtractor, ConfigField, ConfigLocation},
+        fan::FanConfig,
         pools::PoolGroupConfig,
+        scaling::ScalingConfig,
+        tuning::TuningConfig,
     },
     data::{
         board::{BoardData, ChipData, MinerControlBoard},
+        capabilities::TuningCapabilities,
         collector::{
             DataCollector, DataExtensions, DataExtractor, DataField, DataLocation, get_by_pointer,
         },
@@ -30,9 +29,12 @@ use async_trait::async_trait;
 use macaddr::MacAddr;
 use measurements::{AngularVelocity, Frequency, Power, Temperature, Voltage};
 use rpc::AvalonMinerRPCAPI;
-use serde_json::{Value, json};
+use serde_json::Value;
 
-use crate::firmware::AvalonStockFirmware;
+use crate::{
+    backends::workmode::{parse_tuning_config, parse_workmode, workmode_capabilities},
+    firmware::AvalonStockFirmware,
+};
 
 mod rpc;
 
@@ -47,7 +49,7 @@ impl AvalonAMiner {
     pub fn new(ip: IpAddr, model: impl MinerModel) -> Self {
         Self {
             ip,
-            rpc: AvalonMinerRPCAPI::new(ip),
+            rpc: AvalonMinerRPCAPI::new(ip, Self::default_auth()),
             device_info: DeviceInfo::new(
                 model,
                 AvalonStockFirmware::default(),
@@ -87,33 +89,7 @@ impl Restart for AvalonAMiner {
 #[async_trait]
 impl Pause for AvalonAMiner {
     async fn pause(&self, after: Option<Duration>) -> anyhow::Result<bool> {
-        let offset = after.unwrap_or(Duration::from_secs(5));
-        let shutdown_time = SystemTime::now() + offset;
-
-        let timestamp = shutdown_time
-            .duration_since(UNIX_EPOCH)
-            .map_err(|_| anyhow::anyhow!("shutdown time is before UNIX epoch"))?
-            .as_secs();
-
-        let data = self
-            .rpc
-            .send_command(
-                "ascset",
-                false,
-                Some(json!(["0", format!("softoff,1:{}", timestamp)])),
-            )
-            .await?;
-
-        if let Some(status) = data.get("STATUS").and_then(|s| s.as_array())
-            && !status.is_empty()
-            && let Some(status_code) = status[0].get("STATUS").and_then(|s| s.as_str())
-            && status_code == "S"
-            && let Some(msg) = status[0].get("Msg").and_then(|m| m.as_str())
-        {
-            return Ok(msg == "ASC 0 set OK");
-        }
-
-        Ok(false)
+        self.rpc.soft_off(after).await
     }
     fn supports_pause(&self) -> bool {
         true
@@ -122,44 +98,7 @@ impl Pause for AvalonAMiner {
 #[async_trait]
 impl Resume for AvalonAMiner {
     async fn resume(&self, after: Option<Duration>) -> anyhow::Result<bool> {
-        let offset = after.unwrap_or(Duration::from_secs(5));
-        let shutdown_time = SystemTime::now() + offset;
-
-        let timestamp = shutdown_time
-            .duration_since(UNIX_EPOCH)
-            .map_err(|_| anyhow::anyhow!("shutdown time is before UNIX epoch"))?
-            .as_secs();
-
-        let result = self
-            .rpc
-            .send_command(
-                "ascset",
-                false,
-                Some(json!(["0", format!("softon,1:{}", timestamp)])),
-            )
-            .await;
-
-        match result {
-            Ok(data) => {
-                if let Some(status) = data.get("STATUS").and_then(|s| s.as_array())
-                    && !status.is_empty()
-                    && let Some(status_code) = status[0].get("STATUS").and_then(|s| s.as_str())
-                    && status_code == "S"
-                    && let Some(msg) = status[0].get("Msg").and_then(|m| m.as_str())
-                {
-                    return Ok(msg == "ASC 0 set OK");
-                }
-                Ok(false)
-            }
-            // softon closes the connection without responding — treat as success
-            Err(e)
-                if e.to_string().contains("No data received")
-                    || e.to_string().contains("timed out") =>
-            {
-                Ok(true)
-            }
-            Err(e) => Err(e),
-        }
+        self.rpc.soft_on(after).await
     }
     fn supports_resume(&self) -> bool {
         true
@@ -178,6 +117,8 @@ impl ReadLogs for AvalonAMiner {
     }
 }
 
+impl SetSyslogTarget for AvalonAMiner {}
+
 impl FactoryReset for AvalonAMiner {
     fn supports_factory_reset(&self) -> bool {
         false
@@ -187,23 +128,7 @@ impl FactoryReset for AvalonAMiner {
 #[async_trait]
 impl SetFaultLight for AvalonAMiner {
     async fn set_fault_light(&self, fault: bool) -> anyhow::Result<bool> {
-        let command = if fault { "1-1" } else { "1-0" };
-
-        let data = self
-            .rpc
-            .send_command("ascset", false, Some(json!(["0", "led", command])))
-            .await?;
-
-        if let Some(status) = data.get("STATUS").and_then(|s| s.as_array())
-            && let Some(msg) = status
-                .first()
-                .and_then(|s| s.get("Msg"))
-                .and_then(|m| m.as_str())
-        {
-            return Ok(msg == "ASC 0 set OK");
-        }
-
-        Err(anyhow::anyhow!("Failed to set fault light to {}", command))
+        self.rpc.set_fault_light(fault).await
     }
     fn supports_set_fault_light(&self) -> bool {
         true
@@ -213,23 +138,7 @@ impl SetFaultLight for AvalonAMiner {
 #[async_trait]
 impl SetPowerLimit for AvalonAMiner {
     async fn set_power_limit(&self, limit: Power) -> anyhow::Result<bool> {
-        let data = self
-            .rpc
-            .send_command(
-                "ascset",
-                false,
-                Some(json!(["0", "worklevel,set", limit.to_string()])),
-            )
-            .await?;
-
-        if let Some(status) = data.get("STATUS").and_then(|s| s.as_array())
-            && !status.is_empty()
-            && let Some(msg) = status[0].get("Msg").and_then(|m| m.as_str())
-        {
-            return Ok(msg == "ASC 0 set OK");
-        }
-
-        Err(anyhow::anyhow!("Failed to set power limit"))
+        self.rpc.set_power_limit(limit).await
     }
     fn supports_set_power_limit(&self) -> bool {
         true
@@ -247,15 +156,33 @@ impl SupportsPoolsConfig for AvalonAMiner {
             .collect())
     }
 
+    async fn set_pools_config(&self, config: Vec<PoolGroupConfig>) -> anyhow::Result<bool> {
+        self.rpc.set_pools(config).await
+    }
+
     fn supports_pools_config(&self) -> bool {
-        false
+        true
     }
 }
 
 impl GetConfigsLocations for AvalonAMiner {
-    #[allow(unused_variables)]
     fn get_configs_locations(&self, data_field: ConfigField) -> Vec<ConfigLocation> {
-        vec![]
+        const RPC_STATS: MinerCommand = MinerCommand::RPC {
+            command: "stats",
+            parameters: None,
+        };
+
+        match data_field {
+            ConfigField::Tuning => vec![(
+                RPC_STATS,
+                ConfigExtractor {
+                    func: get_by_pointer,
+                    key: Some("/STATS/0/MM ID0/WORKMODE"),
+                    tag: None,
+                },
+            )],
+            _ => vec![],
+        }
     }
 }
 
@@ -381,6 +308,14 @@ impl GetDataLocations for AvalonAMiner {
                     tag: None,
                 },
             )],
+            DataField::TuningCapabilities => vec![(
+                RPC_STATS,
+                DataExtractor {
+                    func: get_by_pointer,
+                    key: Some("/STATS/0/MM ID0/WORKMODE"),
+                    tag: None,
+                },
+            )],
             DataField::LightFlashing => vec![(
                 RPC_STATS,
                 DataExtractor {
@@ -468,8 +403,12 @@ impl GetControlBoardVersion for AvalonAMiner {
     }
 }
 
+impl GetControlBoard for AvalonAMiner {}
+
 impl GetHostname for AvalonAMiner {}
 
+impl GetNetwork for AvalonAMiner {}
+
 impl GetApiVersion for AvalonAMiner {
     fn parse_api_version(&self, data: &HashMap<DataField, Value>) -> Option<String> {
         data.extract::<String>(DataField::ApiVersion)
@@ -664,6 +603,8 @@ impl GetFans for AvalonAMiner {
 
 impl GetPsuFans for AvalonAMiner {}
 
+impl GetPsus for AvalonAMiner {}
+
 impl GetWattage for AvalonAMiner {
     fn parse_wattage(&self, data: &HashMap<DataField, Value>) -> Option<Power> {
         let wattage = data.get(&DataField::Wattage).and_then(|v| v.as_array())?;
@@ -683,7 +624,16 @@ impl GetTuningTarget for AvalonAMiner {
 }
 
 impl GetScaledTuningTarget for AvalonAMiner {}
-impl GetTuningCapabilities for AvalonAMiner {}
+impl GetTuningCapabilities for AvalonAMiner {
+    fn parse_tuning_capabilities(
+        &self,
+        data: &HashMap<DataField, Value>,
+    ) -> Option<TuningCapabilities> {
+        data.get(&DataField::TuningCapabilities)
+            .and_then(parse_workmode)
+            .map(|_| workmode_capabilities())
+    }
+}
 impl GetLightFlashing for AvalonAMiner {
     fn parse_light_flashing(&self, data: &HashMap<DataField, Value>) -> Option<bool> {
         data.extract::<bool>(DataField::LightFlashing)
@@ -749,19 +699,59 @@ impl UpgradeFirmware for AvalonAMiner {
     }
 }
 
-impl HasAuth for AvalonAMiner {}
-impl HasDefaultAuth for AvalonAMiner {}
+impl HasDefaultAuth for AvalonAMiner {
+    fn default_auth() -> MinerAuth {
+        MinerAuth::new("root", "root")
+    }
+}
+
+impl HasAuth for AvalonAMiner {
+    fn set_auth(&mut self, auth: MinerAuth) {
+        self.rpc.set_auth(auth);
+    }
+}
 
 #[async_trait]
 impl SupportsTuningConfig for AvalonAMiner {
+    /// Mining modes select the `ascset` workmode (eco, standard, super); power
+    /// targets set the work level.
+    async fn set_tuning_config(
+        &self,
+        config: TuningConfig,
+        _scaling_config: Option<ScalingConfig>,
+    ) -> anyhow::Result<bool> {
+        config.ensure_target_only()?;
+        match config.target {
+            TuningTarget::MiningMode(mode) => self.rpc.set_workmode(mode).await,
+            TuningTarget::Power(limit) => self.set_power_limit(limit).await,
+            other => anyhow::bail!("Unsupported tuning target for AvalonMiner: {other:?}"),
+        }
+    }
+
+    fn parse_tuning_config(
+        &self,
+        data: &HashMap<ConfigField, Value>,
+    ) -> anyhow::Result<TuningConfig> {
+        parse_tuning_config(data.get(&ConfigField::Tuning))
+    }
+
     fn supports_tuning_config(&self) -> bool {
-        false
+        true
     }
 }
 
 #[async_trait]
 impl SupportsFanConfig for AvalonAMiner {
+    async fn set_fan_config(&self, config: FanConfig) -> anyhow::Result<bool> {
+        self.rpc.set_fan_config(config).await
+    }
+
     fn supports_fan_config(&self) -> bool {
+        true
+    }
+
+    /// Stats only report the current fan ratio, not whether it is fixed.
+    fn supports_get_fan_config(&self) -> bool {
         false
     }
 }
@@ -769,6 +759,9 @@ impl SupportsFanConfig for AvalonAMiner {
 impl SupportsTemperatureConfig for AvalonAMiner {}
 impl GetTuningPercent for AvalonAMiner {}
 impl SetTuningPercent for AvalonAMiner {}
+impl GetApplianceConfig for AvalonAMiner {}
+impl SetApplianceConfig for AvalonAMiner {}
+impl SupportsNetworkConfig for AvalonAMiner {}
 
 #[cfg(test)]
 mod tests {
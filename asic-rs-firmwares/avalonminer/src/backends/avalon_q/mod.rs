@@ -1,18 +1,18 @@
-use std::{
-    collections::HashMap,
-    net::IpAddr,
-    str::FromStr,
-    time::{Duration, SystemTime, UNIX_EPOCH},
-};
+use std::{collections::HashMap, net::IpAddr, str::FromStr, time::Duration};
 
 use anyhow;
 use asic_rs_core::{
     config::{
-        collector::{ConfigCollector, ConfigField, ConfigLocation},
+        appliance::ApplianceConfig,
+        collector::{ConfigCollector, ConfigExtractor, ConfigField, ConfigLocation},
+        fan::FanConfig,
         pools::PoolGroupConfig,
+        scaling::ScalingConfig,
+        tuning::TuningConfig,
     },
     data::{
         board::{BoardData, ChipData},
+        capabilities::TuningCapabilities,
         collector::{
             DataCollector, DataExtensions, DataExtractor, DataField, DataLocation, get_by_pointer,
         },
@@ -29,9 +29,12 @@ use async_trait::async_trait;
 use macaddr::MacAddr;
 use measurements::{AngularVelocity, Power, Temperature, Voltage};
 use rpc::AvalonMinerRPCAPI;
-use serde_json::{Value, json};
+use serde_json::Value;
 
-use crate::firmware::AvalonStockFirmware;
+use crate::{
+    backends::workmode::{parse_tuning_config, parse_workmode, workmode_capabilities},
+    firmware::AvalonStockFirmware,
+};
 
 mod rpc;
 
@@ -46,7 +49,7 @@ impl AvalonQMiner {
     pub fn new(ip: IpAddr, model: impl MinerModel) -> Self {
         Self {
             ip,
-            rpc: AvalonMinerRPCAPI::new(ip),
+            rpc: AvalonMinerRPCAPI::new(ip, Self::default_auth()),
             device_info: DeviceInfo::new(
                 model,
                 AvalonStockFirmware::default(),
@@ -57,13 +60,7 @@ impl AvalonQMiner {
 
     /// Reboot the miner
     pub async fn reboot(&self) -> anyhow::Result<bool> {
-        let data = self.rpc.send_command("restart", false, None).await?;
-
-        if let Some(status) = data.get("STATUS").and_then(|s| s.as_str()) {
-            return Ok(status == "RESTART");
-        }
-
-        Ok(false)
+        self.rpc.reboot().await
     }
 }
 
@@ -82,33 +79,7 @@ impl APIClient for AvalonQMiner {
 #[async_trait]
 impl Pause for AvalonQMiner {
     async fn pause(&self, after: Option<Duration>) -> anyhow::Result<bool> {
-        let offset = after.unwrap_or(Duration::from_secs(5));
-        let shutdown_time = SystemTime::now() + offset;
-
-        let timestamp = shutdown_time
-            .duration_since(UNIX_EPOCH)
-            .map_err(|_| anyhow::anyhow!("shutdown time is before UNIX epoch"))?
-            .as_secs();
-
-        let data = self
-            .rpc
-            .send_command(
-                "ascset",
-                false,
-                Some(json!(["0", format!("softoff,1:{}", timestamp)])),
-            )
-            .await?;
-
-        if let Some(status) = data.get("STATUS").and_then(|s| s.as_array())
-            && !status.is_empty()
-            && let Some(status_code) = status[0].get("STATUS").and_then(|s| s.as_str())
-            && status_code == "S"
-            && let Some(msg) = status[0].get("Msg").and_then(|m| m.as_str())
-        {
-            return Ok(msg == "ASC 0 set OK");
-        }
-
-        Ok(false)
+        self.rpc.soft_off(after).await
     }
     fn supports_pause(&self) -> bool {
         true
@@ -117,44 +88,7 @@ impl Pause for AvalonQMiner {
 #[async_trait]
 impl Resume for AvalonQMiner {
     async fn resume(&self, after: Option<Duration>) -> anyhow::Result<bool> {
-        let offset = after.unwrap_or(Duration::from_secs(5));
-        let shutdown_time = SystemTime::now() + offset;
-
-        let timestamp = shutdown_time
-            .duration_since(UNIX_EPOCH)
-            .map_err(|_| anyhow::anyhow!("shutdown time is before UNIX epoch"))?
-            .as_secs();
-
-        let result = self
-            .rpc
-            .send_command(
-                "ascset",
-                false,
-                Some(json!(["0", format!("softon,1:{}", timestamp)])),
-            )
-            .await;
-
-        match result {
-            Ok(data) => {
-                if let Some(status) = data.get("STATUS").and_then(|s| s.as_array())
-                    && !status.is_empty()
-                    && let Some(status_code) = status[0].get("STATUS").and_then(|s| s.as_str())
-                    && status_code == "S"
-                    && let Some(msg) = status[0].get("Msg").and_then(|m| m.as_str())
-                {
-                    return Ok(msg == "ASC 0 set OK");
-                }
-                Ok(false)
-            }
-            // softon closes the connection without responding — treat as success
-            Err(e)
-                if e.to_string().contains("No data received")
-                    || e.to_string().contains("timed out") =>
-            {
-                Ok(true)
-            }
-            Err(e) => Err(e),
-        }
+        self.rpc.soft_on(after).await
     }
     fn supports_resume(&self) -> bool {
         true
@@ -173,6 +107,8 @@ impl ReadLogs for AvalonQMiner {
     }
 }
 
+impl SetSyslogTarget for AvalonQMiner {}
+
 impl FactoryReset for AvalonQMiner {
     fn supports_factory_reset(&self) -> bool {
         false
@@ -182,23 +118,7 @@ impl FactoryReset for AvalonQMiner {
 #[async_trait]
 impl SetFaultLight for AvalonQMiner {
     async fn set_fault_light(&self, fault: bool) -> anyhow::Result<bool> {
-        let command = if fault { "1-1" } else { "1-0" };
-
-        let data = self
-            .rpc
-            .send_command("ascset", false, Some(json!(["0", "led", command])))
-            .await?;
-
-        if let Some(status) = data.get("STATUS").and_then(|s| s.as_array())
-            && let Some(msg) = status
-                .first()
-                .and_then(|s| s.get("Msg"))
-                .and_then(|m| m.as_str())
-        {
-            return Ok(msg == "ASC 0 set OK");
-        }
-
-        Err(anyhow::anyhow!("Failed to set fault light to {}", command))
+        self.rpc.set_fault_light(fault).await
     }
     fn supports_set_fault_light(&self) -> bool {
         true
@@ -208,23 +128,7 @@ impl SetFaultLight for AvalonQMiner {
 #[async_trait]
 impl SetPowerLimit for AvalonQMiner {
     async fn set_power_limit(&self, limit: Power) -> anyhow::Result<bool> {
-        let data = self
-            .rpc
-            .send_command(
-                "ascset",
-                false,
-                Some(json!(["0", "worklevel,set", limit.to_string()])),
-            )
-            .await?;
-
-        if let Some(status) = data.get("STATUS").and_then(|s| s.as_array())
-            && !status.is_empty()
-            && let Some(msg) = status[0].get("Msg").and_then(|m| m.as_str())
-        {
-            return Ok(msg == "ASC 0 set OK");
-        }
-
-        Err(anyhow::anyhow!("Failed to set power limit"))
+        self.rpc.set_power_limit(limit).await
     }
     fn supports_set_power_limit(&self) -> bool {
         true
@@ -242,22 +146,43 @@ impl SupportsPoolsConfig for AvalonQMiner {
             .collect())
     }
 
+    async fn set_pools_config(&self, config: Vec<PoolGroupConfig>) -> anyhow::Result<bool> {
+        self.rpc.set_pools(config).await
+    }
+
     fn supports_pools_config(&self) -> bool {
-        false
+        true
     }
 }
 
 #[async_trait]
 impl Restart for AvalonQMiner {
+    async fn restart(&self) -> anyhow::Result<bool> {
+        self.reboot().await
+    }
     fn supports_restart(&self) -> bool {
-        false
+        true
     }
 }
 
 impl GetConfigsLocations for AvalonQMiner {
-    #[allow(unused_variables)]
     fn get_configs_locations(&self, data_field: ConfigField) -> Vec<ConfigLocation> {
-        vec![]
+        const RPC_STATS: MinerCommand = MinerCommand::RPC {
+            command: "stats",
+            parameters: None,
+        };
+
+        match data_field {
+            ConfigField::Tuning => vec![(
+                RPC_STATS,
+                ConfigExtractor {
+                    func: get_by_pointer,
+                    key: Some("/STATS/0/MM ID0:Summary/STATS/WORKMODE"),
+                    tag: None,
+                },
+            )],
+            _ => vec![],
+        }
     }
 }
 
@@ -375,6 +300,22 @@ impl GetDataLocations for AvalonQMiner {
                     tag: None,
                 },
             )],
+            DataField::TuningCapabilities => vec![(
+                RPC_STATS,
+                DataExtractor {
+                    func: get_by_pointer,
+                    key: Some("/STATS/0/MM ID0:Summary/STATS/WORKMODE"),
+                    tag: None,
+                },
+            )],
+            DataField::Appliance => vec![(
+                RPC_STATS,
+                DataExtractor {
+                    func: get_by_pointer,
+                    key: Some("/STATS/0/MM ID0:Summary/STATS"),
+                    tag: None,
+                },
+            )],
             DataField::LightFlashing => vec![(
                 RPC_STATS,
                 DataExtractor {
@@ -446,6 +387,8 @@ impl GetSerialNumber for AvalonQMiner {}
 
 impl GetHostname for AvalonQMiner {}
 
+impl GetNetwork for AvalonQMiner {}
+
 impl GetApiVersion for AvalonQMiner {
     fn parse_api_version(&self, data: &HashMap<DataField, Value>) -> Option<String> {
         data.extract::<String>(DataField::ApiVersion)
@@ -460,6 +403,8 @@ impl GetFirmwareVersion for AvalonQMiner {
 
 impl GetControlBoardVersion for AvalonQMiner {}
 
+impl GetControlBoard for AvalonQMiner {}
+
 impl GetHashboards for AvalonQMiner {
     fn parse_hashboards(&self, data: &HashMap<DataField, Value>) -> Vec<BoardData> {
         let mut hashboards: Vec<BoardData> =
@@ -619,6 +564,8 @@ impl GetFans for AvalonQMiner {
 
 impl GetPsuFans for AvalonQMiner {}
 
+impl GetPsus for AvalonQMiner {}
+
 impl GetWattage for AvalonQMiner {
     fn parse_wattage(&self, data: &HashMap<DataField, Value>) -> Option<Power> {
         data.extract_map::<f64, _>(DataField::Wattage, Power::from_watts)
@@ -633,7 +580,16 @@ impl GetTuningTarget for AvalonQMiner {
 }
 
 impl GetScaledTuningTarget for AvalonQMiner {}
-impl GetTuningCapabilities for AvalonQMiner {}
+impl GetTuningCapabilities for AvalonQMiner {
+    fn parse_tuning_capabilities(
+        &self,
+        data: &HashMap<DataField, Value>,
+    ) -> Option<TuningCapabilities> {
+        data.get(&DataField::TuningCapabilities)
+            .and_then(parse_workmode)
+            .map(|_| workmode_capabilities())
+    }
+}
 impl GetLightFlashing for AvalonQMiner {
     fn parse_light_flashing(&self, data: &HashMap<DataField, Value>) -> Option<bool> {
         data.extract::<bool>(DataField::LightFlashing)
@@ -699,19 +655,59 @@ impl UpgradeFirmware for AvalonQMiner {
     }
 }
 
-impl HasAuth for AvalonQMiner {}
-impl HasDefaultAuth for AvalonQMiner {}
+impl HasDefaultAuth for AvalonQMiner {
+    fn default_auth() -> MinerAuth {
+        MinerAuth::new("root", "root")
+    }
+}
+
+impl HasAuth for AvalonQMiner {
+    fn set_auth(&mut self, auth: MinerAuth) {
+        self.rpc.set_auth(auth);
+    }
+}
 
 #[async_trait]
 impl SupportsTuningConfig for AvalonQMiner {
+    /// Mining modes select the `ascset` workmode (eco, standard, super); power
+    /// targets set the work level.
+    async fn set_tuning_config(
+        &self,
+        config: TuningConfig,
+        _scaling_config: Option<ScalingConfig>,
+    ) -> anyhow::Result<bool> {
+        config.ensure_target_only()?;
+        match config.target {
+            TuningTarget::MiningMode(mode) => self.rpc.set_workmode(mode).await,
+            TuningTarget::Power(limit) => self.set_power_limit(limit).await,
+            other => anyhow::bail!("Unsupported tuning target for AvalonMiner: {other:?}"),
+        }
+    }
+
+    fn parse_tuning_config(
+        &self,
+        data: &HashMap<ConfigField, Value>,
+    ) -> anyhow::Result<TuningConfig> {
+        parse_tuning_config(data.get(&ConfigField::Tuning))
+    }
+
     fn supports_tuning_config(&self) -> bool {
-        false
+        true
     }
 }
 
 #[async_trait]
 impl SupportsFanConfig for AvalonQMiner {
+    async fn set_fan_config(&self, config: FanConfig) -> anyhow::Result<bool> {
+        self.rpc.set_fan_config(config).await
+    }
+
     fn supports_fan_config(&self) -> bool {
+        true
+    }
+
+    /// Stats only report the current fan ratio, not whether it is fixed.
+    fn supports_get_fan_config(&self) -> bool {
         false
     }
 }
@@ -720,10 +716,43 @@ impl SupportsTemperatureConfig for AvalonQMiner {}
 impl GetTuningPercent for AvalonQMiner {}
 impl SetTuningPercent for AvalonQMiner {}
 
+impl GetApplianceConfig for AvalonQMiner {
+    fn parse_appliance_config(&self, data: &HashMap<DataField, Value>) -> Option<ApplianceConfig> {
+        let stats = data.get(&DataField::Appliance)?;
+        Some(ApplianceConfig {
+            screen_enabled: stats
+                .get("LcdOnoff")
+                .and_then(Value::as_u64)
+                .map(|v| v == 1),
+        })
+    }
+}
+
+#[async_trait]
+impl SetApplianceConfig for AvalonQMiner {
+    async fn set_appliance_config(&self, config: ApplianceConfig) -> anyhow::Result<bool> {
+        match config.screen_enabled {
+            Some(enabled) => {
+                self.rpc
+                    .ascset(&["lcd", if enabled { "0:1" } else { "0:0" }])
+                    .await
+            }
+            None => Ok(true),
+        }
+    }
+
+    fn supports_set_appliance_config(&self) -> bool {
+        true
+    }
+}
+
+impl SupportsNetworkConfig for AvalonQMiner {}
+
 #[cfg(test)]
 mod tests {
-    use asic_rs_core::test::api::MockAPIClient;
+    use asic_rs_core::{data::miner::MiningMode, test::api::MockAPIClient};
     use asic_rs_makes_avalon::models::AvalonMinerModel;
+    use serde_json::json;
 
     use super::*;
     use crate::test::json::{DEVS_COMMAND, PARSED_STATS_COMMAND, POOLS_COMMAND, VERSION_COMMAND};
@@ -820,6 +849,44 @@ mod tests {
         );
         assert_eq!(miner_data.fans.len(), 4);
         assert_eq!(miner_data.hashboards[0].chips.len(), 160);
+        assert_eq!(
+            miner_data.appliance.and_then(|a| a.screen_enabled),
+            Some(true)
+        );
+
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_avalon_home_q_workmode() -> anyhow::Result<()> {
+        let miner = AvalonQMiner::new(IpAddr::from([127, 0, 0, 1]), AvalonMinerModel::AvalonHomeQ);
+
+        let mut results = HashMap::new();
+        let stats_cmd: MinerCommand = MinerCommand::RPC {
+            command: "stats",
+            parameters: None,
+        };
+        results.insert(stats_cmd, Value::from_str(PARSED_STATS_COMMAND)?);
+        let mock_api = MockAPIClient::new(results);
+
+        let mut collector = ConfigCollector::new_with_client(&miner, &mock_api);
+        let data = collector.collect(&[ConfigField::Tuning]).await;
+        assert_eq!(
+            miner.parse_tuning_config(&data)?.target,
+            TuningTarget::MiningMode(MiningMode::Low)
+        );
+
+        let mut collector = DataCollector::new_with_client(&miner, &mock_api);
+        let data = collector.collect(&[DataField::TuningCapabilities]).await;
+        let presets = miner
+            .parse_tuning_capabilities(&data)
+            .and_then(|c| c.presets)
+            .expect("workmode presets");
+        assert_eq!(presets.presets.len(), 3);
+        assert_eq!(
+            presets.default,
+            Some(TuningTarget::MiningMode(MiningMode::Normal))
+        );
 
         Ok(())
     }
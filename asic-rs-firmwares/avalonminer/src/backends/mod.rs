@@ -1,6 +1,7 @@
 pub mod avalon_a;
 pub mod avalon_q;
 pub(crate) mod rpc;
+pub(crate) mod workmode;
 
 use std::{any::Any, net::IpAddr};
 
@@ -1,29 +1,63 @@
-use std::{collections::HashMap, net::IpAddr, sync::LazyLock};
+use std::{
+    collections::HashMap,
+    net::IpAddr,
+    sync::LazyLock,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 
 use anyhow;
 use asic_rs_core::{
-    data::command::{MinerCommand, RPCCommandStatus},
+    config::{fan::FanConfig, pools::PoolGroupConfig},
+    data::{
+        command::{MinerCommand, RPCCommandStatus},
+        miner::MiningMode,
+    },
     errors::RPCError,
-    traits::miner::{APIClient, RPCAPIClient},
-    util::{DEFAULT_RPC_TIMEOUT, connect_tcp_stream, read_stream_response, write_all_with_timeout},
+    traits::miner::{APIClient, MinerAuth, RPCAPIClient},
+    util::{
+        DEFAULT_RPC_TIMEOUT, connect_tcp_stream, is_expected_write_error, read_stream_response,
+        write_all_with_timeout,
+    },
 };
 use async_trait::async_trait;
+use measurements::Power;
 use regex::Regex;
 use serde_json::{Value, json};
 
+use crate::backends::workmode::workmode_index;
+
 static STATS_RE: LazyLock<Option<Regex>> = LazyLock::new(|| Regex::new(r"(\w+)\[([^]]+)]").ok());
 static NESTED_STATS_RE: LazyLock<Option<Regex>> =
     LazyLock::new(|| Regex::new(r"'([^']+)':\{([^}]*)}").ok());
 
+/// Number of pool slots `setpool` can write.
+pub(crate) const POOL_SLOTS: usize = 3;
+
 #[derive(Debug)]
 pub struct AvalonMinerRPCAPI {
     ip: IpAddr,
     port: u16,
+    auth: MinerAuth,
 }
 
 impl AvalonMinerRPCAPI {
-    pub fn new(ip: IpAddr) -> Self {
-        Self { ip, port: 4028 }
+    pub fn new(ip: IpAddr, auth: MinerAuth) -> Self {
+        Self {
+            ip,
+            port: 4028,
+            auth,
+        }
+    }
+
+    #[cfg(test)]
+    pub fn with_port(mut self, port: u16) -> Self {
+        self.port = port;
+        self
+    }
+
+    /// Set the web credentials `setpool` authenticates with.
+    pub fn set_auth(&mut self, auth: MinerAuth) {
+        self.auth = auth;
     }
 
     fn parse_rpc_result(&self, response: &str) -> anyhow::Result<Value> {
@@ -142,6 +176,138 @@ impl AvalonMinerRPCAPI {
         }
         outer
     }
+
+    /// Send an `ascset` command to device 0, returning whether the miner acknowledged it.
+    pub async fn ascset(&self, args: &[&str]) -> anyhow::Result<bool> {
+        let params = std::iter::once("0")
+            .chain(args.iter().copied())
+            .collect::<Vec<_>>();
+        let data = self
+            .send_command("ascset", false, Some(json!(params)))
+            .await?;
+
+        Ok(data
+            .pointer("/STATUS/0/Msg")
+            .and_then(|m| m.as_str())
+            .is_some_and(|msg| msg == "ASC 0 set OK"))
+    }
+
+    /// Reboot the control board. The miner drops the connection instead of replying.
+    pub async fn reboot(&self) -> anyhow::Result<bool> {
+        match self.ascset(&["reboot", "0"]).await {
+            Err(e) if is_expected_write_error(&e) => Ok(true),
+            result => result,
+        }
+    }
+
+    /// Stop hashing `after` from now (5 seconds by default).
+    pub async fn soft_off(&self, after: Option<Duration>) -> anyhow::Result<bool> {
+        let timestamp = soft_power_timestamp(after)?;
+        self.ascset(&[&format!("softoff,1:{timestamp}")]).await
+    }
+
+    /// Resume hashing `after` from now (5 seconds by default).
+    pub async fn soft_on(&self, after: Option<Duration>) -> anyhow::Result<bool> {
+        let timestamp = soft_power_timestamp(after)?;
+        match self.ascset(&[&format!("softon,1:{timestamp}")]).await {
+            // softon closes the connection without responding
+            Err(e) if is_expected_write_error(&e) => Ok(true),
+            result => result,
+        }
+    }
+
+    pub async fn set_fault_light(&self, fault: bool) -> anyhow::Result<bool> {
+        self.ascset(&["led", if fault { "1-1" } else { "1-0" }])
+            .await
+    }
+
+    pub async fn set_power_limit(&self, limit: Power) -> anyhow::Result<bool> {
+        self.ascset(&["worklevel,set", &limit.to_string()]).await
+    }
+
+    pub async fn set_workmode(&self, mode: MiningMode) -> anyhow::Result<bool> {
+        self.ascset(&["workmode", "set", workmode_index(mode)])
+            .await
+    }
+
+    /// Auto hands fan control back to the firmware, which keeps its own
+    /// temperature target.
+    pub async fn set_fan_config(&self, config: FanConfig) -> anyhow::Result<bool> {
+        config.ensure_no_min_fans()?;
+        let speed = match config {
+            FanConfig::Auto { .. } => "-1".to_string(),
+            FanConfig::Manual { fan_speed, .. } if fan_speed <= 100 => fan_speed.to_string(),
+            FanConfig::Manual { fan_speed, .. } => {
+                anyhow::bail!("Fan speed must be between 0 and 100, got {fan_speed}")
+            }
+        };
+        self.ascset(&["fan-spd", &speed]).await
+    }
+
+    /// Write the pools in order to the miner's slots, clearing any slot left over.
+    pub async fn set_pools(&self, config: Vec<PoolGroupConfig>) -> anyhow::Result<bool> {
+        let pools = config
+            .into_iter()
+            .flat_map(|group| group.pools)
+            .collect::<Vec<_>>();
+        if pools.is_empty() {
+            anyhow::bail!("At least one pool is required");
+        }
+        if pools.len() > POOL_SLOTS {
+            anyhow::bail!(
+                "AvalonMiner supports at most {POOL_SLOTS} pools, got {}",
+                pools.len()
+            );
+        }
+
+        let mut success = true;
+        for idx in 0..POOL_SLOTS {
+            success &= match pools.get(idx) {
+                Some(pool) => {
+                    self.set_pool(idx, &pool.url.to_string(), &pool.username, &pool.password)
+                        .await?
+                }
+                None => self.set_pool(idx, "", "", "").await?,
+            };
+        }
+        Ok(success)
+    }
+
+    /// Replace the pool in slot `idx` (0-2). The web credentials are required by `setpool`.
+    pub async fn set_pool(
+        &self,
+        idx: usize,
+        url: &str,
+        username: &str,
+        password: &str,
+    ) -> anyhow::Result<bool> {
+        let idx = idx.to_string();
+        let data = self
+            .send_command(
+                "setpool",
+                false,
+                Some(json!([
+                    self.auth.username(),
+                    self.auth.password(),
+                    idx,
+                    url,
+                    username,
+                    password
+                ])),
+            )
+            .await?;
+
+        Ok(data.pointer("/STATUS/0/STATUS").and_then(|s| s.as_str()) == Some("S"))
+    }
+}
+
+/// Unix timestamp `after` from now (5 seconds by default) for `softoff`/`softon`.
+fn soft_power_timestamp(after: Option<Duration>) -> anyhow::Result<u64> {
+    let at = SystemTime::now() + after.unwrap_or(Duration::from_secs(5));
+    Ok(at
+        .duration_since(UNIX_EPOCH)
+        .map_err(|_| anyhow::anyhow!("shutdown time is before UNIX epoch"))?
+        .as_secs())
 }
 
 #[async_trait]
@@ -184,7 +350,8 @@ impl RPCAPIClient for AvalonMinerRPCAPI {
         let response = read_stream_response(&mut stream, DEFAULT_RPC_TIMEOUT).await?;
 
         if response.is_empty() {
-            anyhow::bail!("No data received from miner");
+            // The miner closed the connection without replying.
+            return Err(RPCError::ConnectionReset.into());
         }
 
         if response == "Socket connect failed: Connection refused" {
@@ -219,7 +386,10 @@ mod parse_rpc_result_nested_tests {
     use crate::test::json::{STATS_COMMAND, VERSION_COMMAND};
 
     fn test_rpc() -> AvalonMinerRPCAPI {
-        AvalonMinerRPCAPI::new(IpAddr::V4(Ipv4Addr::LOCALHOST))
+        AvalonMinerRPCAPI::new(
+            IpAddr::V4(Ipv4Addr::LOCALHOST),
+            MinerAuth::new("root", "root"),
+        )
     }
 
     #[test]
@@ -288,3 +458,109 @@ mod parse_rpc_result_nested_tests {
         assert_eq!(val.pointer("/VERSION/0/API"), Some(&json!("3.7")));
     }
 }
+
+#[cfg(test)]
+mod control_tests {
+    use std::net::{IpAddr, Ipv4Addr};
+
+    use asic_rs_core::{config::pools::PoolConfig, data::pool::PoolURL, test::rpc::mock_rpc};
+    use serde_json::json;
+    use tokio::net::TcpListener;
+
+    use super::*;
+
+    fn rpc(port: u16) -> AvalonMinerRPCAPI {
+        AvalonMinerRPCAPI::new(
+            IpAddr::V4(Ipv4Addr::LOCALHOST),
+            MinerAuth::new("admin", "secret"),
+        )
+        .with_port(port)
+    }
+
+    fn pools(urls: &[&str]) -> Vec<PoolGroupConfig> {
+        vec![PoolGroupConfig {
+            name: String::new(),
+            quota: 1,
+            pools: urls
+                .iter()
+                .map(|url| PoolConfig {
+                    url: PoolURL::from(url.to_string()),
+                    username: "worker".into(),
+                    password: "x".into(),
+                })
+                .collect(),
+        }]
+    }
+
+    #[tokio::test]
+    async fn set_pools_uses_auth_and_clears_unused_slots() {
+        let (port, requests, server) = mock_rpc(POOL_SLOTS, |_| {
+            Some(json!({ "STATUS": [{ "STATUS": "S", "Msg": "ok" }] }))
+        })
+        .await
+        .unwrap();
+
+        let ok = rpc(port)
+            .set_pools(pools(&["stratum+tcp://pool.example.com:3333"]))
+            .await
+            .unwrap();
+        server.await.unwrap().unwrap();
+
+        assert!(ok);
+        let parameters = requests
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|r| r["parameter"].as_str().unwrap_or_default().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            parameters,
+            [
+                "admin,secret,0,stratum+tcp://pool.example.com:3333,worker,x",
+                "admin,secret,1,,,",
+                "admin,secret,2,,,",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn set_pools_rejects_more_pools_than_slots() {
+        let err = rpc(1)
+            .set_pools(pools(&[
+                "stratum+tcp://a.example.com:3333",
+                "stratum+tcp://b.example.com:3333",
+                "stratum+tcp://c.example.com:3333",
+                "stratum+tcp://d.example.com:3333",
+            ]))
+            .await
+            .unwrap_err();
+
+        assert!(err.to_string().contains("at most 3 pools"));
+    }
+
+    #[tokio::test]
+    async fn reboot_succeeds_when_miner_closes_connection() {
+        let (port, requests, server) = mock_rpc(1, |_| None).await.unwrap();
+
+        assert!(rpc(port).reboot().await.unwrap());
+        server.await.unwrap().unwrap();
+        assert_eq!(
+            requests.lock().unwrap()[0]["parameter"],
+            json!("0,reboot,0")
+        );
+    }
+
+    #[tokio::test]
+    async fn reboot_reports_connection_failure() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        drop(listener);
+
+        let err = rpc(port).reboot().await.unwrap_err();
+
+        assert!(matches!(
+            err.downcast_ref::<RPCError>(),
+            Some(RPCError::ConnectionFailed)
+        ));
+    }
+}
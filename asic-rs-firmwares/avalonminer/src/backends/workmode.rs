@@ -0,0 +1,69 @@
+use asic_rs_core::{
+    config::tuning::TuningConfig,
+    data::{
+        capabilities::{PresetTuningCapabilities, TuningCapabilities},
+        miner::{MiningMode, TuningTarget},
+    },
+};
+use serde_json::Value;
+
+/// `ascset` workmode index for a mining mode (0 eco, 1 standard, 2 super).
+pub(crate) fn workmode_index(mode: MiningMode) -> &'static str {
+    match mode {
+        MiningMode::Low => "0",
+        MiningMode::Normal => "1",
+        MiningMode::High => "2",
+    }
+}
+
+/// Mining mode for a `WORKMODE` stats value.
+pub(crate) fn parse_workmode(value: &Value) -> Option<MiningMode> {
+    let index = value
+        .as_u64()
+        .or_else(|| value.as_array()?.first()?.as_u64())?;
+
+    match index {
+        0 => Some(MiningMode::Low),
+        1 => Some(MiningMode::Normal),
+        2 => Some(MiningMode::High),
+        _ => None,
+    }
+}
+
+/// Tuning config for a `WORKMODE` stats value.
+pub(crate) fn parse_tuning_config(value: Option<&Value>) -> anyhow::Result<TuningConfig> {
+    value
+        .and_then(parse_workmode)
+        .map(|mode| TuningConfig::new(TuningTarget::MiningMode(mode)))
+        .ok_or_else(|| anyhow::anyhow!("No workmode in AvalonMiner stats"))
+}
+
+pub(crate) fn workmode_capabilities() -> TuningCapabilities {
+    TuningCapabilities {
+        presets: Some(PresetTuningCapabilities {
+            default: Some(TuningTarget::MiningMode(MiningMode::Normal)),
+            presets: [MiningMode::Low, MiningMode::Normal, MiningMode::High]
+                .into_iter()
+                .map(TuningTarget::MiningMode)
+                .collect(),
+        }),
+        ..Default::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn workmode_round_trips() {
+        for mode in [MiningMode::Low, MiningMode::Normal, MiningMode::High] {
+            let index: u64 = workmode_index(mode).parse().unwrap();
+            assert_eq!(parse_workmode(&json!(index)), Some(mode));
+        }
+        assert_eq!(parse_workmode(&json!([2])), Some(MiningMode::High));
+        assert_eq!(parse_workmode(&json!(3)), None);
+    }
+}
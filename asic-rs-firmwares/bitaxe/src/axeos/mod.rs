@@ -0,0 +1,5 @@
+//! Settings and OTA helpers for AxeOS (ESP-Miner) and the firmwares derived
+//! from it, such as NerdAxe.
+
+pub mod ota;
+pub mod settings;
@@ -0,0 +1,53 @@
+use std::{net::IpAddr, time::Duration};
+
+use anyhow::{Context, bail};
+use asic_rs_core::{
+    data::firmware::{self, FirmwareImage},
+    traits::miner::GetFirmwareVersion,
+};
+use reqwest::Client;
+
+const UPGRADE_TIMEOUT: Duration = Duration::from_secs(3 * 60);
+
+/// Flash an image through one of the OTA endpoints picked by
+/// [`ota_endpoint`](super::settings::ota_endpoint).
+///
+/// AxeOS writes the image to flash while the request is in flight, so the
+/// response only arrives once the partition has been written.
+pub async fn upload_ota(
+    client: &Client,
+    ip: IpAddr,
+    port: u16,
+    endpoint: &str,
+    image: FirmwareImage,
+) -> anyhow::Result<()> {
+    if image.bytes.is_empty() {
+        bail!("Firmware image {} is empty", image.filename);
+    }
+
+    let url = format!("http://{ip}:{port}/api/{endpoint}");
+    let response = client
+        .post(url)
+        .header("Content-Type", "application/octet-stream")
+        .body(image.bytes)
+        .timeout(Duration::from_secs(300))
+        .send()
+        .await
+        .context("OTA upload HTTP request failed")?;
+
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        bail!("OTA upload failed with status code {}: {}", status, body);
+    }
+    Ok(())
+}
+
+/// Wait for the miner to boot the image flashed through `system/OTA`, which
+/// must report a firmware version other than `previous`.
+pub async fn wait_for_upgraded_version<M: GetFirmwareVersion + ?Sized>(
+    miner: &M,
+    previous: &str,
+) -> anyhow::Result<String> {
+    firmware::wait_for_upgraded_version(miner, previous, None, UPGRADE_TIMEOUT).await
+}
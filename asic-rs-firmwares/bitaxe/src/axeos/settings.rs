@@ -0,0 +1,374 @@
+use anyhow::{anyhow, bail};
+use asic_rs_core::{
+    config::{
+        fan::FanConfig,
+        pools::{PoolConfig, PoolGroupConfig},
+        tuning::TuningConfig,
+    },
+    data::{
+        hashrate::{HashRate, HashRateUnit},
+        miner::TuningTarget,
+        pool::{PoolScheme, PoolURL},
+    },
+};
+use measurements::{Frequency, Voltage};
+use serde_json::{Map, Value, json};
+
+/// AxeOS versions without a configurable fan target regulate towards 60C.
+pub const DEFAULT_FAN_TARGET_TEMP: f64 = 60.0;
+
+/// AxeOS reports some flags as `0`/`1` and newer releases as booleans.
+fn get_flag(info: &Value, key: &str) -> Option<bool> {
+    let value = info.get(key)?;
+    value.as_bool().or_else(|| value.as_u64().map(|v| v != 0))
+}
+
+fn parse_pool(info: &Value, prefix: &str) -> Option<PoolConfig> {
+    let key = |suffix: &str| {
+        if prefix.is_empty() {
+            format!("stratum{suffix}")
+        } else {
+            format!("{prefix}Stratum{suffix}")
+        }
+    };
+
+    let host = info.get(key("URL"))?.as_str()?;
+    if host.is_empty() {
+        return None;
+    }
+    let port = info
+        .get(key("Port"))
+        .and_then(Value::as_u64)
+        .and_then(|p| u16::try_from(p).ok())?;
+
+    Some(PoolConfig {
+        url: PoolURL {
+            scheme: PoolScheme::StratumV1,
+            host: host.to_string(),
+            port,
+            pubkey: None,
+        },
+        username: info
+            .get(key("User"))
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string(),
+        password: info
+            .get(key("Password"))
+            .and_then(Value::as_str)
+            .unwrap_or_default()
+            .to_string(),
+    })
+}
+
+/// Map the primary and fallback stratum settings from `system/info` to a
+/// single pool group.
+///
+/// AxeOS does not report stratum passwords, so they are left empty unless the
+/// firmware includes them.
+pub fn parse_pools_config(info: &Value) -> Vec<PoolGroupConfig> {
+    let pools: Vec<PoolConfig> = [parse_pool(info, ""), parse_pool(info, "fallback")]
+        .into_iter()
+        .flatten()
+        .collect();
+
+    if pools.is_empty() {
+        return vec![];
+    }
+
+    vec![PoolGroupConfig {
+        name: String::from("default"),
+        quota: 1,
+        pools,
+    }]
+}
+
+fn insert_pool(settings: &mut Map<String, Value>, prefix: &str, pool: &PoolConfig) {
+    let key = |suffix: &str| {
+        if prefix.is_empty() {
+            format!("stratum{suffix}")
+        } else {
+            format!("{prefix}Stratum{suffix}")
+        }
+    };
+
+    settings.insert(key("URL"), json!(pool.url.host));
+    settings.insert(key("Port"), json!(pool.url.port));
+    settings.insert(key("User"), json!(pool.username));
+    // An empty password is one AxeOS did not report, keep the stored one.
+    if !pool.password.is_empty() {
+        settings.insert(key("Password"), json!(pool.password));
+    }
+}
+
+/// Blank out a pool slot, as AxeOS keeps any setting missing from the payload.
+fn clear_pool(settings: &mut Map<String, Value>, prefix: &str) {
+    for suffix in ["URL", "User", "Password"] {
+        settings.insert(format!("{prefix}Stratum{suffix}"), json!(""));
+    }
+}
+
+/// Build the `PATCH system` payload for a pool config.
+///
+/// AxeOS has a single primary pool and an optional fallback, so only one group
+/// with at most two Stratum V1 pools can be applied. A single pool clears the
+/// fallback.
+pub fn pools_config_payload(config: &[PoolGroupConfig]) -> anyhow::Result<Value> {
+    let [group] = config else {
+        bail!(
+            "AxeOS supports exactly one pool group, got {}",
+            config.len()
+        );
+    };
+
+    let (primary, fallback) = match group.pools.as_slice() {
+        [primary] => (primary, None),
+        [primary, fallback] => (primary, Some(fallback)),
+        pools => bail!(
+            "AxeOS supports a primary and a fallback pool, got {} pools",
+            pools.len()
+        ),
+    };
+
+    for pool in group.pools.iter() {
+        if pool.url.scheme != PoolScheme::StratumV1 {
+            bail!(
+                "AxeOS only supports stratum+tcp pools, got {}",
+                pool.url.host
+            );
+        }
+    }
+
+    let mut settings = Map::new();
+    insert_pool(&mut settings, "", primary);
+    match fallback {
+        Some(fallback) => insert_pool(&mut settings, "fallback", fallback),
+        None => clear_pool(&mut settings, "fallback"),
+    }
+
+    Ok(Value::Object(settings))
+}
+
+pub fn parse_fan_config(info: &Value) -> anyhow::Result<FanConfig> {
+    let auto = get_flag(info, "autofanspeed")
+        .ok_or_else(|| anyhow!("No autofanspeed setting in system info"))?;
+
+    if auto {
+        let target_temp = info
+            .get("temptarget")
+            .and_then(Value::as_f64)
+            .unwrap_or(DEFAULT_FAN_TARGET_TEMP);
+        Ok(FanConfig::auto(target_temp, None))
+    } else {
+        info.get("fanspeed")
+            .and_then(Value::as_u64)
+            .map(FanConfig::manual)
+            .ok_or_else(|| anyhow!("No fanspeed setting in system info"))
+    }
+}
+
+pub fn fan_config_payload(config: &FanConfig) -> anyhow::Result<Value> {
+    match config {
+        FanConfig::Auto { target_temp, .. } => {
+            let mut payload = json!({ "autofanspeed": 1 });
+            if let Some(target_temp) = target_temp {
+                payload["temptarget"] = json!(target_temp.round().max(0.0) as u64);
+            }
+            Ok(payload)
+        }
+        FanConfig::Manual { fan_speed, .. } => {
+            if *fan_speed > 100 {
+                bail!("Fan speed must be a percentage, got {fan_speed}");
+            }
+            Ok(json!({
+                "autofanspeed": 0,
+                "fanspeed": fan_speed,
+            }))
+        }
+    }
+}
+
+/// Hashrate in GH/s produced per MHz of ASIC frequency.
+fn gigahash_per_megahertz(info: &Value) -> Option<f64> {
+    let cores = info.get("smallCoreCount").and_then(Value::as_u64)?;
+    let chips = info.get("asicCount").and_then(Value::as_u64)?;
+    let rate = (cores * chips) as f64 / 1000.0;
+    (rate > 0.0).then_some(rate)
+}
+
+/// Express the configured ASIC frequency as a hashrate target, along with the
+/// configured frequency and core voltage.
+pub fn parse_tuning_config(info: &Value) -> anyhow::Result<TuningConfig> {
+    let frequency = info
+        .get("frequency")
+        .and_then(Value::as_f64)
+        .ok_or_else(|| anyhow!("No frequency setting in system info"))?;
+    let rate = gigahash_per_megahertz(info)
+        .ok_or_else(|| anyhow!("No core or ASIC count in system info"))?;
+
+    let hashrate = HashRate {
+        value: frequency * rate,
+        unit: HashRateUnit::GigaHash,
+        algo: String::from("SHA256"),
+    }
+    .as_unit(HashRateUnit::default());
+
+    let config = TuningConfig::new(TuningTarget::HashRate(hashrate))
+        .with_frequency(Frequency::from_megahertz(frequency));
+    Ok(match info.get("coreVoltage").and_then(Value::as_u64) {
+        Some(voltage) => config.with_voltage(Voltage::from_millivolts(voltage as f64)),
+        None => config,
+    })
+}
+
+/// Build the `PATCH system` payload for a tuning config.
+///
+/// A frequency is written as is. Otherwise the hashrate target is converted to
+/// an ASIC frequency using the core and ASIC counts from `system/info`. A
+/// voltage also sets the core voltage.
+pub fn tuning_config_payload(config: &TuningConfig, info: &Value) -> anyhow::Result<Value> {
+    let frequency = match (&config.frequency, &config.target) {
+        (Some(frequency), _) => frequency.as_megahertz().round(),
+        (None, TuningTarget::HashRate(hashrate)) => {
+            let rate = gigahash_per_megahertz(info)
+                .ok_or_else(|| anyhow!("No core or ASIC count in system info"))?;
+            (hashrate.clone().as_unit(HashRateUnit::GigaHash).value / rate).round()
+        }
+        (None, TuningTarget::Power(_)) => {
+            bail!("Power tuning target is not supported on AxeOS without a frequency")
+        }
+        (None, TuningTarget::MiningMode(_)) => {
+            bail!("MiningMode tuning target is not supported on AxeOS without a frequency")
+        }
+    };
+    if frequency <= 0.0 {
+        bail!("ASIC frequency must be positive");
+    }
+
+    let mut settings = Map::new();
+    settings.insert("frequency".to_string(), json!(frequency as u64));
+    if let Some(voltage) = config.voltage {
+        let millivolts = voltage.as_millivolts().round();
+        if millivolts <= 0.0 {
+            bail!("Core voltage must be positive");
+        }
+        settings.insert("coreVoltage".to_string(), json!(millivolts as u64));
+    }
+
+    Ok(Value::Object(settings))
+}
+
+/// Pick the OTA endpoint for an image: AxeOS takes the web UI bundle
+/// (`www.bin`) and the firmware (`esp-miner.bin`) on separate endpoints.
+pub fn ota_endpoint(filename: &str) -> anyhow::Result<&'static str> {
+    let filename = filename.to_ascii_lowercase();
+    if filename.contains("factory") {
+        bail!("Factory images must be flashed over USB, use esp-miner.bin or www.bin instead");
+    }
+    if filename.contains("www") {
+        Ok("system/OTAWWW")
+    } else {
+        Ok("system/OTA")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use measurements::Power;
+
+    use super::*;
+    use crate::test::json::v2_0_0::SYSTEM_INFO_COMMAND;
+
+    fn info() -> Value {
+        Value::from_str(SYSTEM_INFO_COMMAND).unwrap()
+    }
+
+    #[test]
+    fn pools_round_trip() {
+        let pools = parse_pools_config(&info());
+        assert_eq!(pools.len(), 1);
+        assert_eq!(pools[0].pools.len(), 2);
+        assert_eq!(pools[0].pools[1].url.host, "btc2.example.pool");
+
+        let payload = pools_config_payload(&pools).unwrap();
+        assert_eq!(payload["stratumURL"], "btc.example.pool");
+        assert_eq!(payload["stratumPort"], 3333);
+        assert_eq!(payload["fallbackStratumUser"], "asic-rs.test");
+    }
+
+    #[test]
+    fn unreported_passwords_are_kept() {
+        let mut pools = parse_pools_config(&info());
+        assert_eq!(pools[0].pools[0].password, "");
+
+        let payload = pools_config_payload(&pools).unwrap();
+        assert!(payload.get("stratumPassword").is_none());
+        assert!(payload.get("fallbackStratumPassword").is_none());
+
+        pools[0].pools[0].password = String::from("secret");
+        let payload = pools_config_payload(&pools).unwrap();
+        assert_eq!(payload["stratumPassword"], "secret");
+    }
+
+    #[test]
+    fn single_pool_clears_fallback() {
+        let mut pools = parse_pools_config(&info());
+        pools[0].pools.truncate(1);
+
+        let payload = pools_config_payload(&pools).unwrap();
+        assert_eq!(payload["stratumURL"], "btc.example.pool");
+        assert_eq!(payload["fallbackStratumURL"], "");
+        assert_eq!(payload["fallbackStratumUser"], "");
+        assert_eq!(payload["fallbackStratumPassword"], "");
+    }
+
+    #[test]
+    fn too_many_pools_are_rejected() {
+        let mut pools = parse_pools_config(&info());
+        let extra = pools[0].pools[0].clone();
+        pools[0].pools.push(extra);
+        assert!(pools_config_payload(&pools).is_err());
+    }
+
+    #[test]
+    fn fan_config_is_parsed() {
+        assert_eq!(
+            parse_fan_config(&info()).unwrap(),
+            FanConfig::auto(DEFAULT_FAN_TARGET_TEMP, None)
+        );
+        assert_eq!(
+            parse_fan_config(&json!({"autofanspeed": false, "fanspeed": 80})).unwrap(),
+            FanConfig::manual(80)
+        );
+    }
+
+    #[test]
+    fn tuning_round_trip() {
+        let config = parse_tuning_config(&info()).unwrap();
+        assert_eq!(config.voltage, Some(Voltage::from_millivolts(1166.0)));
+        assert_eq!(config.frequency, Some(Frequency::from_megahertz(490.0)));
+        assert_eq!(config.algorithm(), None);
+
+        let payload = tuning_config_payload(&config, &info()).unwrap();
+        assert_eq!(payload, json!({"frequency": 490, "coreVoltage": 1166}));
+    }
+
+    #[test]
+    fn tuning_frequency_is_written_directly() {
+        let config = TuningConfig::new(TuningTarget::Power(Power::from_watts(15.0)));
+        assert!(tuning_config_payload(&config, &info()).is_err());
+
+        let config = config.with_frequency(Frequency::from_megahertz(525.0));
+        let payload = tuning_config_payload(&config, &info()).unwrap();
+        assert_eq!(payload, json!({"frequency": 525}));
+    }
+
+    #[test]
+    fn ota_images_are_routed() {
+        assert_eq!(ota_endpoint("esp-miner.bin").unwrap(), "system/OTA");
+        assert_eq!(ota_endpoint("www.bin").unwrap(), "system/OTAWWW");
+        assert!(ota_endpoint("esp-miner-factory-401-v2.4.5.bin").is_err());
+    }
+}
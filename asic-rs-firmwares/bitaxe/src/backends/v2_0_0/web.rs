@@ -4,7 +4,7 @@ use once_cell::sync::OnceCell;
 
 use anyhow;
 use asic_rs_core::{
-    data::command::MinerCommand,
+    data::{command::MinerCommand, firmware::FirmwareImage},
     traits::miner::{APIClient, WebAPIClient},
 };
 use async_trait::async_trait;
@@ -12,6 +12,8 @@ use reqwest::{Client, Method, Response};
 use serde_json::Value;
 use tokio::time::timeout;
 
+use crate::axeos::ota;
+
 /// Bitaxe WebAPI client for communicating with Bitaxe and similar miners
 #[derive(Debug)]
 pub struct BitaxeWebAPI {
@@ -24,7 +26,7 @@ pub struct BitaxeWebAPI {
 
 #[async_trait]
 #[allow(dead_code)]
-trait Bitaxe200WebAPI: WebAPIClient {
+pub(crate) trait Bitaxe200WebAPI: WebAPIClient {
     /// Get system information
     async fn system_info(&self) -> anyhow::Result<Value> {
         self.send_command("system/info", false, None, Method::GET)
@@ -86,8 +88,24 @@ impl WebAPIClient for BitaxeWebAPI {
             match result {
                 Ok(response) => {
                     if response.status().is_success() {
-                        match response.json().await {
-                            Ok(json_data) => return Ok(json_data),
+                        match response.text().await {
+                            Ok(text) => match serde_json::from_str(&text) {
+                                Ok(json_data) => return Ok(json_data),
+                                // AxeOS answers settings and restart requests with
+                                // an empty or plain text body.
+                                Err(_) if method != Method::GET => {
+                                    return Ok(if text.is_empty() {
+                                        Value::Null
+                                    } else {
+                                        Value::String(text)
+                                    });
+                                }
+                                Err(e) => {
+                                    if attempt == self.retries {
+                                        return Err(BitaxeError::ParseError(e.to_string()))?;
+                                    }
+                                }
+                            },
                             Err(e) => {
                                 if attempt == self.retries {
                                     return Err(BitaxeError::ParseError(e.to_string()))?;
@@ -135,6 +153,11 @@ impl BitaxeWebAPI {
         self.client.get_or_try_init(Self::build_client)
     }
 
+    /// Upload an OTA image to `endpoint` (`system/OTA` or `system/OTAWWW`).
+    pub async fn upload_ota(&self, endpoint: &str, image: FirmwareImage) -> anyhow::Result<()> {
+        ota::upload_ota(self.client()?, self.ip, self.port, endpoint, image).await
+    }
+
     /// Execute the actual HTTP request
     async fn execute_request(
         &self,
@@ -3,8 +3,11 @@ use std::{collections::HashMap, net::IpAddr, str::FromStr, time::Duration};
 use anyhow;
 use asic_rs_core::{
     config::{
-        collector::{ConfigCollector, ConfigField, ConfigLocation},
+        collector::{ConfigCollector, ConfigExtractor, ConfigField, ConfigLocation},
+        fan::FanConfig,
         pools::PoolGroupConfig,
+        scaling::ScalingConfig,
+        tuning::TuningConfig,
     },
     data::{
         board::{BoardData, ChipData, MinerControlBoard},
@@ -15,6 +18,7 @@ use asic_rs_core::{
         command::MinerCommand,
         device::{DeviceInfo, HashAlgorithm},
         fan::FanData,
+        firmware::{FirmwareImage, version_before_upgrade},
         hashrate::{HashRate, HashRateUnit},
         message::{MessageSeverity, MinerMessage},
         pool::{PoolData, PoolGroupData, PoolScheme, PoolURL},
@@ -27,9 +31,12 @@ use async_trait::async_trait;
 use macaddr::MacAddr;
 use measurements::{AngularVelocity, Frequency, Power, Temperature, Voltage};
 use serde_json::Value;
-use web::BitaxeWebAPI;
+use web::{Bitaxe200WebAPI, BitaxeWebAPI};
 
-use crate::firmware::BitaxeFirmware;
+use crate::{
+    axeos::{ota, settings},
+    firmware::BitaxeFirmware,
+};
 
 mod web;
 
@@ -61,9 +68,23 @@ impl APIClient for Bitaxe290 {
 }
 
 impl GetConfigsLocations for Bitaxe290 {
-    #[allow(unused_variables)]
     fn get_configs_locations(&self, data_field: ConfigField) -> Vec<ConfigLocation> {
-        vec![]
+        const WEB_SYSTEM_INFO: MinerCommand = MinerCommand::WebAPI {
+            command: "system/info",
+            parameters: None,
+        };
+
+        match data_field {
+            ConfigField::Pools | ConfigField::Fan | ConfigField::Tuning => vec![(
+                WEB_SYSTEM_INFO,
+                ConfigExtractor {
+                    func: get_by_pointer,
+                    key: Some(""),
+                    tag: None,
+                },
+            )],
+            _ => vec![],
+        }
     }
 }
 
@@ -235,6 +256,9 @@ impl GetHostname for Bitaxe290 {
         data.extract::<String>(DataField::Hostname)
     }
 }
+
+impl GetNetwork for Bitaxe290 {}
+
 impl GetApiVersion for Bitaxe290 {
     fn parse_api_version(&self, data: &HashMap<DataField, Value>) -> Option<String> {
         data.extract::<String>(DataField::ApiVersion)
@@ -254,6 +278,9 @@ impl GetControlBoardVersion for Bitaxe290 {
             .and_then(|s| BitaxeControlBoard::parse(&s).map(|cb| cb.into()))
     }
 }
+
+impl GetControlBoard for Bitaxe290 {}
+
 impl GetHashboards for Bitaxe290 {
     fn parse_hashboards(&self, data: &HashMap<DataField, Value>) -> Vec<BoardData> {
         let mut board = BoardData::new(0, self.device_info.hardware.chips_for_board(0));
@@ -357,6 +384,9 @@ impl GetFans for Bitaxe290 {
 impl GetPsuFans for Bitaxe290 {
     // N/A
 }
+
+impl GetPsus for Bitaxe290 {}
+
 impl GetFluidTemperature for Bitaxe290 {
     // N/A
 }
@@ -481,24 +511,35 @@ impl SetPowerLimit for Bitaxe290 {
 
 #[async_trait]
 impl SupportsPoolsConfig for Bitaxe290 {
-    async fn get_pools_config(&self) -> anyhow::Result<Vec<PoolGroupConfig>> {
-        Ok(self
-            .get_pools()
-            .await
-            .iter()
-            .map(|g| g.clone().into())
-            .collect())
+    fn parse_pools_config(
+        &self,
+        data: &HashMap<ConfigField, Value>,
+    ) -> anyhow::Result<Vec<PoolGroupConfig>> {
+        data.get(&ConfigField::Pools)
+            .map(settings::parse_pools_config)
+            .ok_or_else(|| anyhow::anyhow!("No pool settings in system info"))
+    }
+
+    async fn set_pools_config(&self, config: Vec<PoolGroupConfig>) -> anyhow::Result<bool> {
+        let payload = settings::pools_config_payload(&config)?;
+        self.web.update_settings(payload).await?;
+        Ok(true)
     }
 
     fn supports_pools_config(&self) -> bool {
-        false
+        true
     }
 }
 
 #[async_trait]
 impl Restart for Bitaxe290 {
+    async fn restart(&self) -> anyhow::Result<bool> {
+        self.web.restart().await?;
+        Ok(true)
+    }
+
     fn supports_restart(&self) -> bool {
-        false
+        true
     }
 }
 
@@ -528,6 +569,8 @@ impl ReadLogs for Bitaxe290 {
     }
 }
 
+impl SetSyslogTarget for Bitaxe290 {}
+
 impl FactoryReset for Bitaxe290 {
     fn supports_factory_reset(&self) -> bool {
         false
@@ -543,8 +586,22 @@ impl SupportsScalingConfig for Bitaxe290 {
 
 #[async_trait]
 impl UpgradeFirmware for Bitaxe290 {
+    async fn upgrade_firmware(&self, image: FirmwareImage) -> anyhow::Result<bool> {
+        let endpoint = settings::ota_endpoint(&image.filename)?;
+        // Only the firmware image reboots the device, the web UI is swapped live.
+        if endpoint != "system/OTA" {
+            self.web.upload_ota(endpoint, image).await?;
+            return Ok(true);
+        }
+
+        let previous = version_before_upgrade(self).await?;
+        self.web.upload_ota(endpoint, image).await?;
+        ota::wait_for_upgraded_version(self, &previous).await?;
+        Ok(true)
+    }
+
     fn supports_upgrade_firmware(&self) -> bool {
-        false
+        true
     }
 }
 
@@ -553,18 +610,54 @@ impl HasDefaultAuth for Bitaxe290 {}
 
 #[async_trait]
 impl SupportsTuningConfig for Bitaxe290 {
+    async fn set_tuning_config(
+        &self,
+        config: TuningConfig,
+        _scaling_config: Option<ScalingConfig>,
+    ) -> anyhow::Result<bool> {
+        let info = self.web.system_info().await?;
+        let payload = settings::tuning_config_payload(&config, &info)?;
+        self.web.update_settings(payload).await?;
+        Ok(true)
+    }
+
+    fn parse_tuning_config(
+        &self,
+        data: &HashMap<ConfigField, Value>,
+    ) -> anyhow::Result<TuningConfig> {
+        data.get(&ConfigField::Tuning)
+            .ok_or_else(|| anyhow::anyhow!("No tuning settings in system info"))
+            .and_then(settings::parse_tuning_config)
+    }
+
     fn supports_tuning_config(&self) -> bool {
-        false
+        true
     }
 }
 
 #[async_trait]
 impl SupportsFanConfig for Bitaxe290 {
+    async fn set_fan_config(&self, config: FanConfig) -> anyhow::Result<bool> {
+        config.ensure_no_min_fans()?;
+        let payload = settings::fan_config_payload(&config)?;
+        self.web.update_settings(payload).await?;
+        Ok(true)
+    }
+
+    fn parse_fan_config(&self, data: &HashMap<ConfigField, Value>) -> anyhow::Result<FanConfig> {
+        data.get(&ConfigField::Fan)
+            .ok_or_else(|| anyhow::anyhow!("No fan settings in system info"))
+            .and_then(settings::parse_fan_config)
+    }
+
     fn supports_fan_config(&self) -> bool {
-        false
+        true
     }
 }
 
 impl SupportsTemperatureConfig for Bitaxe290 {}
 impl GetTuningPercent for Bitaxe290 {}
 impl SetTuningPercent for Bitaxe290 {}
+impl GetApplianceConfig for Bitaxe290 {}
+impl SetApplianceConfig for Bitaxe290 {}
+impl SupportsNetworkConfig for Bitaxe290 {}
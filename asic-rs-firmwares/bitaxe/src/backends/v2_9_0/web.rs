@@ -4,7 +4,7 @@ use async_trait::async_trait;
 use reqwest::Method;
 use serde_json::Value;
 
-pub use super::super::v2_0_0::web::BitaxeWebAPI;
+pub(crate) use super::super::v2_0_0::web::{Bitaxe200WebAPI, BitaxeWebAPI};
 
 #[async_trait]
 #[allow(dead_code)]
@@ -1,3 +1,4 @@
+pub mod axeos;
 mod backends;
 pub mod firmware;
 pub(crate) mod test;
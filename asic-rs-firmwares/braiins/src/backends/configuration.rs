@@ -0,0 +1,668 @@
+//! Mapping between the asic-rs config types and the Braiins OS tuner, cooling
+//! and DPS (dynamic performance scaling) settings, for both the GraphQL API
+//! (25.03, 25.05) and the REST API (25.07 and newer), plus the REST network
+//! settings.
+
+use anyhow::{anyhow, bail};
+use asic_rs_core::{
+    config::{
+        fan::FanConfig, network::NetworkConfig, scaling::ScalingConfig,
+        temperature::TemperatureConfig, tuning::TuningConfig,
+    },
+    data::{
+        command::MinerCommand,
+        hashrate::{HashRate, HashRateUnit},
+        miner::TuningTarget,
+    },
+};
+use measurements::Power;
+use serde_json::{Map, Value, json};
+
+/// REST `save_action` that persists the change and applies it right away.
+/// Required by 25.07, ignored by 26.04.
+const SAVE_AND_APPLY: u32 = 2;
+
+/// REST `protocol` of a DHCP configured interface.
+const NETWORK_PROTOCOL_DHCP: i64 = 1;
+
+fn terahash(value: f64) -> TuningTarget {
+    TuningTarget::HashRate(HashRate {
+        value,
+        unit: HashRateUnit::TeraHash,
+        algo: String::from("SHA256"),
+    })
+}
+
+fn tuning_mode_error(target: &TuningTarget) -> anyhow::Error {
+    anyhow!(
+        "Braiins OS tunes to a power or hashrate target, got {:?}",
+        target
+    )
+}
+
+fn check_fan_speed(speed: u64) -> anyhow::Result<u64> {
+    if speed > 100 {
+        bail!("Fan speed must be a percentage, got {speed}");
+    }
+    Ok(speed)
+}
+
+fn percent_to_ratio(speed: u64) -> anyhow::Result<f64> {
+    Ok(check_fan_speed(speed)? as f64 / 100.0)
+}
+
+// GraphQL (25.03, 25.05)
+
+pub(crate) const GQL_CONFIG: MinerCommand = MinerCommand::GraphQL {
+    command: r#"{
+        bosminer {
+            config {
+                ... on BosminerConfig {
+                    tempControl { mode targetTemp hotTemp dangerousTemp }
+                    fanControl { speed minFans minFanSpeed }
+                    autotuning { mode powerTarget hashrateTarget }
+                    performanceScaling {
+                        enabled
+                        powerStep
+                        minPowerTarget
+                        shutdownEnabled
+                        shutdownDuration
+                    }
+                }
+            }
+        }
+    }"#,
+};
+
+pub(crate) fn gql_parse_tuning(autotuning: &Value) -> anyhow::Result<TuningConfig> {
+    let power = autotuning
+        .get("powerTarget")
+        .and_then(Value::as_f64)
+        .map(|w| TuningTarget::Power(Power::from_watts(w)));
+    let hashrate = autotuning
+        .get("hashrateTarget")
+        .and_then(Value::as_f64)
+        .map(terahash);
+
+    let target = match autotuning.get("mode").and_then(Value::as_str) {
+        Some("HASHRATE_TARGET") => hashrate.or(power),
+        _ => power.or(hashrate),
+    };
+    target
+        .map(TuningConfig::new)
+        .ok_or_else(|| anyhow!("No power or hashrate target in autotuning config"))
+}
+
+/// Build the `AutotuningIn` input for a tuning config, optionally with DPS.
+pub(crate) fn gql_autotuning_input(
+    config: &TuningConfig,
+    scaling: Option<&ScalingConfig>,
+) -> anyhow::Result<Value> {
+    config.ensure_target_only()?;
+    let mut input = match &config.target {
+        TuningTarget::Power(power) => json!({
+            "mode": "POWER_TARGET",
+            "powerTarget": power.as_watts().round() as i64,
+        }),
+        TuningTarget::HashRate(hashrate) => json!({
+            "mode": "HASHRATE_TARGET",
+            "hashrateTarget": hashrate.clone().as_unit(HashRateUnit::TeraHash).value,
+        }),
+        target => return Err(tuning_mode_error(target)),
+    };
+    if let Some(scaling) = scaling {
+        input["performanceScaling"] = gql_scaling_input(scaling);
+    }
+    Ok(input)
+}
+
+pub(crate) fn gql_parse_fan(config: &Value) -> anyhow::Result<FanConfig> {
+    let temp_control = config
+        .get("tempControl")
+        .ok_or_else(|| anyhow!("No tempControl in bosminer config"))?;
+    let fan_control = config.get("fanControl");
+
+    let config = match temp_control.get("mode").and_then(Value::as_str) {
+        Some("MANUAL") => fan_control
+            .and_then(|f| f.get("speed"))
+            .and_then(Value::as_u64)
+            .map(FanConfig::manual)
+            .ok_or_else(|| anyhow!("No manual fan speed in bosminer config")),
+        _ => {
+            let target_temp = temp_control
+                .get("targetTemp")
+                .and_then(Value::as_f64)
+                .ok_or_else(|| anyhow!("No target temperature in bosminer config"))?;
+            let idle_speed = fan_control
+                .and_then(|f| f.get("minFanSpeed"))
+                .and_then(Value::as_u64);
+            Ok(FanConfig::auto(target_temp, idle_speed))
+        }
+    }?;
+    Ok(
+        match fan_control
+            .and_then(|f| f.get("minFans"))
+            .and_then(Value::as_u64)
+        {
+            Some(min_fans) => config.with_min_fans(min_fans),
+            None => config,
+        },
+    )
+}
+
+/// Build the `TempAndFansIn` input for a fan config. The idle speed of an
+/// automatic config maps to the minimum fan speed.
+pub(crate) fn gql_fan_input(config: &FanConfig) -> anyhow::Result<Value> {
+    let mut input = match config {
+        FanConfig::Auto {
+            target_temp,
+            idle_speed,
+            ..
+        } => {
+            let target_temp = target_temp.ok_or_else(|| {
+                anyhow!("Braiins OS automatic fan control needs a target temperature")
+            })?;
+            let mut input = json!({ "mode": "AUTO", "targetTemp": target_temp });
+            if let Some(idle_speed) = idle_speed {
+                input["minFanSpeed"] = json!(check_fan_speed(*idle_speed)?);
+            }
+            input
+        }
+        FanConfig::Manual { fan_speed, .. } => json!({
+            "mode": "MANUAL",
+            "speed": check_fan_speed(*fan_speed)?,
+        }),
+    };
+    if let Some(min_fans) = config.min_fans() {
+        input["minFans"] = json!(min_fans);
+    }
+    Ok(input)
+}
+
+pub(crate) fn gql_parse_temperature(temp_control: &Value) -> TemperatureConfig {
+    TemperatureConfig {
+        hot: temp_control.get("hotTemp").and_then(Value::as_f64),
+        danger: temp_control.get("dangerousTemp").and_then(Value::as_f64),
+        minimum: None,
+    }
+}
+
+pub(crate) fn gql_temperature_input(config: &TemperatureConfig) -> anyhow::Result<Value> {
+    if config.minimum.is_some() {
+        bail!("Braiins OS does not support a minimum temperature");
+    }
+    let mut input = Map::new();
+    if let Some(hot) = config.hot {
+        input.insert("hotTemp".to_string(), json!(hot));
+    }
+    if let Some(danger) = config.danger {
+        input.insert("dangerousTemp".to_string(), json!(danger));
+    }
+    Ok(Value::Object(input))
+}
+
+pub(crate) fn gql_parse_scaling(scaling: &Value) -> anyhow::Result<ScalingConfig> {
+    let step = scaling
+        .get("powerStep")
+        .and_then(Value::as_u64)
+        .and_then(|s| u32::try_from(s).ok())
+        .ok_or_else(|| anyhow!("No power step in performance scaling config"))?;
+    let minimum = scaling
+        .get("minPowerTarget")
+        .and_then(Value::as_u64)
+        .and_then(|m| u32::try_from(m).ok())
+        .ok_or_else(|| anyhow!("No minimum power target in performance scaling config"))?;
+
+    let mut config = ScalingConfig::new(step, minimum);
+    if let Some(shutdown) = scaling.get("shutdownEnabled").and_then(Value::as_bool) {
+        config = config.with_shutdown(shutdown);
+    }
+    if let Some(duration) = scaling.get("shutdownDuration").and_then(Value::as_f64) {
+        config = config.with_shutdown_duration(duration as f32);
+    }
+    Ok(config)
+}
+
+/// Build the `PerformanceScalingSetter` input, enabling DPS.
+pub(crate) fn gql_scaling_input(config: &ScalingConfig) -> Value {
+    let mut input = json!({
+        "enabled": true,
+        "powerStep": config.step,
+        "minPowerTarget": config.minimum,
+    });
+    if let Some(shutdown) = config.shutdown {
+        input["shutdownEnabled"] = json!(shutdown);
+    }
+    if let Some(duration) = config.shutdown_duration {
+        input["shutdownDuration"] = json!(duration);
+    }
+    input
+}
+
+// REST (25.07 and newer)
+
+pub(crate) fn rest_parse_tuning(tuner: &Value) -> anyhow::Result<TuningConfig> {
+    let power = tuner
+        .pointer("/power_target/watt")
+        .and_then(Value::as_f64)
+        .map(|w| TuningTarget::Power(Power::from_watts(w)));
+    let hashrate = tuner
+        .pointer("/hashrate_target/terahash_per_second")
+        .and_then(Value::as_f64)
+        .map(terahash);
+
+    let target = match tuner.get("tuner_mode").and_then(Value::as_i64) {
+        Some(2) => hashrate.or(power),
+        _ => power.or(hashrate),
+    };
+    target
+        .map(TuningConfig::new)
+        .ok_or_else(|| anyhow!("No power or hashrate target in tuner config"))
+}
+
+/// Endpoint and body setting the tuner target.
+pub(crate) fn rest_tuning_request(config: &TuningConfig) -> anyhow::Result<(&'static str, Value)> {
+    config.ensure_target_only()?;
+    match &config.target {
+        TuningTarget::Power(power) => Ok((
+            "performance/power-target",
+            json!({ "watt": power.as_watts().round() as i64 }),
+        )),
+        TuningTarget::HashRate(hashrate) => Ok((
+            "performance/hashrate-target",
+            json!({
+                "terahash_per_second": hashrate.clone().as_unit(HashRateUnit::TeraHash).value,
+            }),
+        )),
+        target => Err(tuning_mode_error(target)),
+    }
+}
+
+fn rest_degrees(mode: &Value, key: &str) -> Option<f64> {
+    mode.get(key)?.get("degree_c")?.as_f64()
+}
+
+/// Return the active cooling mode name (`auto`, `manual`, `immersion`, ...)
+/// and its settings from a `CoolingConfiguration`.
+fn rest_cooling_mode(temperature: &Value) -> anyhow::Result<(&str, &Value)> {
+    temperature
+        .get("mode")
+        .and_then(Value::as_object)
+        .and_then(|mode| mode.iter().next())
+        .map(|(name, settings)| (name.as_str(), settings))
+        .ok_or_else(|| anyhow!("No cooling mode in miner configuration"))
+}
+
+pub(crate) fn rest_parse_fan(temperature: &Value) -> anyhow::Result<FanConfig> {
+    let (mode, settings) = rest_cooling_mode(temperature)?;
+    let config = match mode {
+        "manual" | "disabled" => settings
+            .get("fan_speed_ratio")
+            .and_then(Value::as_f64)
+            .map(|ratio| FanConfig::manual((ratio * 100.0).round() as u64))
+            .ok_or_else(|| anyhow!("No fan speed in {mode} cooling mode")),
+        _ => {
+            let target_temp = rest_degrees(settings, "target_temperature")
+                .ok_or_else(|| anyhow!("No target temperature in {mode} cooling mode"))?;
+            let idle_speed = settings.get("min_fan_speed").and_then(Value::as_u64);
+            Ok(FanConfig::auto(target_temp, idle_speed))
+        }
+    }?;
+    Ok(
+        match temperature
+            .get("minimum_required_fans")
+            .and_then(Value::as_u64)
+        {
+            Some(min_fans) => config.with_min_fans(min_fans),
+            None => config,
+        },
+    )
+}
+
+/// Body for `PUT cooling/mode` selecting auto or manual fan control. The idle
+/// speed of an automatic config maps to the minimum fan speed.
+pub(crate) fn rest_cooling_mode_request(config: &FanConfig) -> anyhow::Result<Value> {
+    match config {
+        FanConfig::Auto {
+            target_temp,
+            idle_speed,
+            ..
+        } => {
+            let target_temp = target_temp.ok_or_else(|| {
+                anyhow!("Braiins OS automatic fan control needs a target temperature")
+            })?;
+            let mut auto = json!({ "target_temperature": { "degree_c": target_temp } });
+            if let Some(idle_speed) = idle_speed {
+                auto["min_fan_speed"] = json!(check_fan_speed(*idle_speed)?);
+            }
+            Ok(json!({ "auto": auto }))
+        }
+        FanConfig::Manual { fan_speed, .. } => Ok(json!({
+            "manual": { "fan_speed_ratio": percent_to_ratio(*fan_speed)? },
+        })),
+    }
+}
+
+pub(crate) fn rest_parse_temperature(temperature: &Value) -> anyhow::Result<TemperatureConfig> {
+    let (_, settings) = rest_cooling_mode(temperature)?;
+    Ok(TemperatureConfig {
+        hot: rest_degrees(settings, "hot_temperature"),
+        danger: rest_degrees(settings, "dangerous_temperature"),
+        minimum: None,
+    })
+}
+
+/// Body for `PUT cooling/min-required-fans`, which is set apart from the
+/// cooling mode.
+pub(crate) fn rest_min_fans_request(min_fans: u64) -> Value {
+    json!({
+        "save_action": SAVE_AND_APPLY,
+        "min_fans_count": min_fans,
+    })
+}
+
+/// Body for `PUT cooling/mode` updating the thresholds of the active cooling
+/// mode, which leaves its other settings untouched.
+pub(crate) fn rest_temperature_request(
+    temperature: &Value,
+    config: &TemperatureConfig,
+) -> anyhow::Result<Value> {
+    if config.minimum.is_some() {
+        bail!("Braiins OS does not support a minimum temperature");
+    }
+    let (mode, _) = rest_cooling_mode(temperature)?;
+
+    let mut settings = Map::new();
+    if let Some(hot) = config.hot {
+        settings.insert("hot_temperature".to_string(), json!({ "degree_c": hot }));
+    }
+    if let Some(danger) = config.danger {
+        settings.insert(
+            "dangerous_temperature".to_string(),
+            json!({ "degree_c": danger }),
+        );
+    }
+    Ok(json!({ mode: settings }))
+}
+
+pub(crate) fn rest_parse_scaling(dps: &Value) -> anyhow::Result<ScalingConfig> {
+    let watts = |pointer: &str| {
+        dps.pointer(pointer)
+            .and_then(Value::as_u64)
+            .and_then(|w| u32::try_from(w).ok())
+    };
+    let step = watts("/power_step/watt").ok_or_else(|| anyhow!("No DPS power step"))?;
+    let minimum =
+        watts("/min_power_target/watt").ok_or_else(|| anyhow!("No DPS minimum power target"))?;
+
+    let mut config = ScalingConfig::new(step, minimum);
+    if let Some(shutdown) = dps.get("shutdown_enabled").and_then(Value::as_bool) {
+        config = config.with_shutdown(shutdown);
+    }
+    if let Some(hours) = dps
+        .pointer("/shutdown_duration/hours")
+        .and_then(Value::as_f64)
+    {
+        config = config.with_shutdown_duration(hours as f32);
+    }
+    Ok(config)
+}
+
+/// Body for `PUT performance/dps`, enabling DPS on the power target.
+pub(crate) fn rest_dps_request(config: &ScalingConfig) -> Value {
+    let mut request = json!({
+        "save_action": SAVE_AND_APPLY,
+        "enable": true,
+        "target": {
+            "target": {
+                "powertarget": {
+                    "power_step": { "watt": config.step },
+                    "min_power_target": { "watt": config.minimum },
+                }
+            }
+        },
+    });
+    if let Some(shutdown) = config.shutdown {
+        request["enable_shutdown"] = json!(shutdown);
+    }
+    if let Some(duration) = config.shutdown_duration {
+        request["shutdown_duration"] = json!({ "hours": duration.round() as u32 });
+    }
+    request
+}
+
+pub(crate) fn rest_parse_network(network: &Value) -> anyhow::Result<NetworkConfig> {
+    let protocol = network
+        .get("protocol")
+        .and_then(Value::as_i64)
+        .ok_or_else(|| anyhow!("No protocol in network info"))?;
+    let addr = |value: Option<&Value>| value.and_then(Value::as_str)?.parse().ok();
+    let lan = network.pointer("/networks/0");
+
+    Ok(NetworkConfig {
+        dhcp: protocol == NETWORK_PROTOCOL_DHCP,
+        ip: addr(lan.and_then(|lan| lan.get("address"))),
+        netmask: addr(lan.and_then(|lan| lan.get("netmask"))),
+        gateway: addr(network.get("default_gateway")),
+        dns: network
+            .get("dns_servers")
+            .and_then(Value::as_array)
+            .map(|servers| servers.iter().filter_map(|s| addr(Some(s))).collect())
+            .filter(|dns: &Vec<_>| !dns.is_empty()),
+        hostname: network
+            .get("hostname")
+            .and_then(Value::as_str)
+            .map(String::from),
+    })
+}
+
+/// The member of the network `protocol` oneof for `config`, with its name.
+fn network_protocol(config: &NetworkConfig) -> (&'static str, Value) {
+    if config.dhcp {
+        return ("dhcp", json!({}));
+    }
+    let protocol = json!({
+        "address": config.ip.map(|ip| ip.to_string()),
+        "netmask": config.netmask.map(|mask| mask.to_string()),
+        "gateway": config.gateway.map(|gateway| gateway.to_string()),
+        "dns_servers": config
+            .dns
+            .iter()
+            .flatten()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>(),
+    });
+    ("static", protocol)
+}
+
+/// Body for `PATCH network/configuration`.
+pub(crate) fn rest_network_request(config: &NetworkConfig) -> Value {
+    let (name, protocol) = network_protocol(config);
+    let mut request = json!({ "protocol": { name: protocol } });
+    if let Some(hostname) = &config.hostname {
+        request["hostname"] = json!(hostname);
+    }
+    request
+}
+
+/// `SetNetworkConfigurationRequest` in the proto JSON mapping, where the
+/// members of the `protocol` oneof sit at the top level.
+pub(crate) fn grpc_network_request(config: &NetworkConfig) -> Value {
+    let (name, protocol) = network_protocol(config);
+    let mut request = json!({ name: protocol });
+    if let Some(hostname) = &config.hostname {
+        request["hostname"] = json!(hostname);
+    }
+    request
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn gql_tuning_round_trip() -> anyhow::Result<()> {
+        let autotuning = json!({
+            "mode": "HASHRATE_TARGET",
+            "powerTarget": 3000,
+            "hashrateTarget": 110.0,
+        });
+        let config = gql_parse_tuning(&autotuning)?;
+        assert_eq!(config.target, terahash(110.0));
+
+        let input = gql_autotuning_input(&config, Some(&ScalingConfig::new(100, 2000)))?;
+        assert_eq!(input["mode"], "HASHRATE_TARGET");
+        assert_eq!(input["hashrateTarget"], 110.0);
+        assert_eq!(input["performanceScaling"]["powerStep"], 100);
+        Ok(())
+    }
+
+    #[test]
+    fn gql_fan_manual() -> anyhow::Result<()> {
+        let config = json!({
+            "tempControl": { "mode": "MANUAL", "targetTemp": 75.0 },
+            "fanControl": { "speed": 60, "minFans": 1 },
+        });
+        let fan = gql_parse_fan(&config)?;
+        assert_eq!(fan, FanConfig::manual(60).with_min_fans(1));
+        assert_eq!(
+            gql_fan_input(&fan)?,
+            json!({ "mode": "MANUAL", "speed": 60, "minFans": 1 })
+        );
+        assert_eq!(
+            gql_fan_input(&FanConfig::manual(60))?,
+            json!({ "mode": "MANUAL", "speed": 60 })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn rest_fan_and_temperature() -> anyhow::Result<()> {
+        let temperature = json!({
+            "minimum_required_fans": 1,
+            "mode": {
+                "auto": {
+                    "target_temperature": { "degree_c": 70.0 },
+                    "hot_temperature": { "degree_c": 85.0 },
+                    "dangerous_temperature": { "degree_c": 95.0 },
+                    "min_fan_speed": 20,
+                }
+            }
+        });
+        let fan = rest_parse_fan(&temperature)?;
+        assert_eq!(fan, FanConfig::auto(70.0, Some(20)).with_min_fans(1));
+        assert_eq!(
+            rest_min_fans_request(fan.min_fans().unwrap_or_default()),
+            json!({ "save_action": SAVE_AND_APPLY, "min_fans_count": 1 })
+        );
+
+        let thresholds = rest_parse_temperature(&temperature)?;
+        assert_eq!(thresholds.hot, Some(85.0));
+        assert_eq!(thresholds.danger, Some(95.0));
+
+        let request = rest_temperature_request(
+            &temperature,
+            &TemperatureConfig {
+                hot: Some(80.0),
+                danger: None,
+                minimum: None,
+            },
+        )?;
+        assert_eq!(
+            request,
+            json!({ "auto": { "hot_temperature": { "degree_c": 80.0 } } })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn rest_scaling_round_trip() -> anyhow::Result<()> {
+        let dps = json!({
+            "enabled": true,
+            "power_step": { "watt": 20 },
+            "min_power_target": { "watt": 3210 },
+            "shutdown_enabled": true,
+            "shutdown_duration": { "hours": 4 },
+        });
+        let config = rest_parse_scaling(&dps)?;
+        assert_eq!((config.step, config.minimum), (20, 3210));
+        assert_eq!(config.shutdown, Some(true));
+        assert_eq!(config.shutdown_duration, Some(4.0));
+
+        let request = rest_dps_request(&config);
+        assert_eq!(
+            request["target"]["target"]["powertarget"]["min_power_target"]["watt"],
+            3210
+        );
+        assert_eq!(request["shutdown_duration"], json!({ "hours": 4 }));
+        Ok(())
+    }
+
+    #[test]
+    fn rest_network_round_trip() -> anyhow::Result<()> {
+        let network = json!({
+            "default_gateway": "10.107.0.1",
+            "dns_servers": ["10.107.0.1"],
+            "hostname": "Antminer",
+            "networks": [{ "address": "10.107.0.125", "netmask": "255.255.255.0" }],
+            "protocol": 2,
+        });
+        let config = rest_parse_network(&network)?;
+        assert!(!config.dhcp);
+        assert_eq!(config.ip, Some("10.107.0.125".parse()?));
+        assert_eq!(config.dns, Some(vec!["10.107.0.1".parse()?]));
+
+        let request = rest_network_request(&config);
+        assert_eq!(request["protocol"]["static"]["gateway"], "10.107.0.1");
+        assert_eq!(
+            request["protocol"]["static"]["dns_servers"],
+            json!(["10.107.0.1"])
+        );
+        assert_eq!(request["hostname"], "Antminer");
+
+        assert_eq!(
+            rest_network_request(&NetworkConfig::dhcp()),
+            json!({ "protocol": { "dhcp": {} } })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn grpc_network_request_flattens_the_protocol() -> anyhow::Result<()> {
+        let config = NetworkConfig::static_ip(
+            "10.107.0.125".parse()?,
+            "255.255.255.0".parse()?,
+            "10.107.0.1".parse()?,
+            vec!["1.1.1.1".parse()?],
+        )
+        .with_hostname("miner-01");
+        let request = grpc_network_request(&config);
+        assert_eq!(request["static"]["address"], "10.107.0.125");
+        assert_eq!(request["static"]["dns_servers"], json!(["1.1.1.1"]));
+        assert_eq!(request["hostname"], "miner-01");
+        assert!(request.get("protocol").is_none());
+
+        assert_eq!(
+            grpc_network_request(&NetworkConfig::dhcp()),
+            json!({ "dhcp": {} })
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn mining_mode_is_rejected() {
+        let config = TuningConfig::new(TuningTarget::MiningMode(
+            asic_rs_core::data::miner::MiningMode::High,
+        ));
+        assert!(rest_tuning_request(&config).is_err());
+        assert!(gql_autotuning_input(&config, None).is_err());
+    }
+
+    #[test]
+    fn chip_set_points_are_rejected() {
+        let config = TuningConfig::new(TuningTarget::Power(Power::from_watts(3000.0)))
+            .with_frequency(measurements::Frequency::from_megahertz(650.0));
+        assert!(rest_tuning_request(&config).is_err());
+        assert!(gql_autotuning_input(&config, None).is_err());
+    }
+}
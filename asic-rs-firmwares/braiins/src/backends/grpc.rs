@@ -0,0 +1,431 @@
+use std::{collections::HashSet, net::IpAddr, time::Duration};
+
+use anyhow::{Context, anyhow, bail};
+use asic_rs_core::{
+    data::command::MinerCommand,
+    traits::{
+        auth::{ExposeSecret, MinerAuth},
+        miner::{APIClient, GRPCClient},
+    },
+};
+use async_trait::async_trait;
+use once_cell::sync::OnceCell;
+use prost::Message;
+use prost_reflect::{DescriptorPool, DynamicMessage, MessageDescriptor, SerializeOptions};
+use prost_types::FileDescriptorProto;
+use serde_json::{Value, json};
+use tokio::sync::RwLock;
+use tonic::{
+    Code, Request, Status,
+    client::Grpc,
+    codec::{Codec, DecodeBuf, Decoder, EncodeBuf, Encoder},
+    codegen::http::uri::PathAndQuery,
+    metadata::MetadataValue,
+    transport::{Channel, Endpoint},
+};
+use tonic_reflection::pb::v1alpha::{
+    ServerReflectionRequest, server_reflection_client::ServerReflectionClient,
+    server_reflection_request::MessageRequest, server_reflection_response::MessageResponse,
+};
+
+const LOGIN: &str = "braiins.bos.v1.AuthenticationService/Login";
+
+pub(crate) const GRPC_MINER_DETAILS: MinerCommand = MinerCommand::GRPC {
+    command: "braiins.bos.v1.MinerService/GetMinerDetails",
+};
+pub(crate) const GRPC_NETWORK_INFO: MinerCommand = MinerCommand::GRPC {
+    command: "braiins.bos.v1.NetworkService/GetNetworkInfo",
+};
+
+/// Encodes and decodes messages described at runtime, so no generated stubs
+/// are needed for the Braiins OS API.
+#[derive(Clone)]
+struct DynamicCodec {
+    output: MessageDescriptor,
+}
+
+struct DynamicEncoder;
+
+struct DynamicDecoder {
+    output: MessageDescriptor,
+}
+
+impl Codec for DynamicCodec {
+    type Encode = DynamicMessage;
+    type Decode = DynamicMessage;
+    type Encoder = DynamicEncoder;
+    type Decoder = DynamicDecoder;
+
+    fn encoder(&mut self) -> Self::Encoder {
+        DynamicEncoder
+    }
+
+    fn decoder(&mut self) -> Self::Decoder {
+        DynamicDecoder {
+            output: self.output.clone(),
+        }
+    }
+}
+
+impl Encoder for DynamicEncoder {
+    type Item = DynamicMessage;
+    type Error = Status;
+
+    fn encode(&mut self, item: Self::Item, dst: &mut EncodeBuf<'_>) -> Result<(), Self::Error> {
+        item.encode(dst)
+            .map_err(|e| Status::internal(format!("failed to encode request: {e}")))
+    }
+}
+
+impl Decoder for DynamicDecoder {
+    type Item = DynamicMessage;
+    type Error = Status;
+
+    fn decode(&mut self, src: &mut DecodeBuf<'_>) -> Result<Option<Self::Item>, Self::Error> {
+        let mut message = DynamicMessage::new(self.output.clone());
+        message
+            .merge(src)
+            .map_err(|e| Status::internal(format!("failed to decode response: {e}")))?;
+        Ok(Some(message))
+    }
+}
+
+/// Render a response in the shape of the public REST API: proto field names,
+/// enum numbers and every field present, defaults included.
+fn to_json(message: &DynamicMessage) -> anyhow::Result<Value> {
+    let options = SerializeOptions::new()
+        .use_proto_field_name(true)
+        .use_enum_numbers(true)
+        .skip_default_fields(false)
+        .stringify_64_bit_integers(false);
+    Ok(message.serialize_with_options(serde_json::value::Serializer, &options)?)
+}
+
+/// Client for the Braiins OS public gRPC API.
+///
+/// Message types are loaded from the miner through gRPC server reflection the
+/// first time a service is used. Responses are returned as JSON with the proto
+/// field names and enum numbers, the same shape the public REST API uses, so
+/// the REST parsers in `configuration` apply to both.
+///
+/// The 25.03 and 25.05 backends use it for the serial number, control board
+/// and network settings, which their GraphQL API does not expose. All other
+/// data and config on those versions still come from GraphQL and the web API;
+/// no backend collects over gRPC alone.
+#[derive(Debug)]
+pub struct BraiinsGRPCAPI {
+    ip: IpAddr,
+    port: u16,
+    timeout: Duration,
+    auth: MinerAuth,
+    channel: OnceCell<Channel>,
+    descriptors: RwLock<DescriptorPool>,
+    token: RwLock<Option<String>>,
+}
+
+impl BraiinsGRPCAPI {
+    pub fn new(ip: IpAddr, auth: MinerAuth) -> Self {
+        Self {
+            ip,
+            port: 50051,
+            timeout: Duration::from_secs(10),
+            auth,
+            channel: OnceCell::new(),
+            descriptors: RwLock::new(DescriptorPool::new()),
+            token: RwLock::new(None),
+        }
+    }
+
+    pub fn set_auth(&mut self, auth: MinerAuth) {
+        self.auth = auth;
+        *self.token.get_mut() = None;
+    }
+
+    fn channel(&self) -> anyhow::Result<Channel> {
+        self.channel
+            .get_or_try_init(|| {
+                Ok::<_, anyhow::Error>(
+                    Endpoint::from_shared(format!("http://{}:{}", self.ip, self.port))?
+                        .connect_timeout(Duration::from_secs(5))
+                        .timeout(self.timeout)
+                        .connect_lazy(),
+                )
+            })
+            .cloned()
+    }
+
+    /// Fetch the file descriptors defining `service`, with their dependencies,
+    /// through server reflection.
+    async fn load_service(&self, service: &str) -> anyhow::Result<()> {
+        let mut client = ServerReflectionClient::new(self.channel()?);
+        let mut files: Vec<FileDescriptorProto> = Vec::new();
+        let mut pending = vec![MessageRequest::FileContainingSymbol(service.to_string())];
+
+        while let Some(request) = pending.pop() {
+            let request = ServerReflectionRequest {
+                host: String::new(),
+                message_request: Some(request),
+            };
+            let response = client
+                .server_reflection_info(tokio_stream::once(request))
+                .await?
+                .into_inner()
+                .message()
+                .await?
+                .and_then(|response| response.message_response)
+                .ok_or_else(|| anyhow!("Empty gRPC reflection response for {service}"))?;
+            let protos = match response {
+                MessageResponse::FileDescriptorResponse(response) => response.file_descriptor_proto,
+                MessageResponse::ErrorResponse(error) => {
+                    bail!(
+                        "gRPC reflection failed for {service}: {}",
+                        error.error_message
+                    )
+                }
+                _ => bail!("Unexpected gRPC reflection response for {service}"),
+            };
+            for proto in protos {
+                let file = FileDescriptorProto::decode(proto.as_slice())?;
+                if !files.iter().any(|f| f.name == file.name) {
+                    files.push(file);
+                }
+            }
+
+            // Servers usually send the transitive dependencies along; ask for
+            // any that are still missing.
+            let pool = self.descriptors.read().await;
+            let known: HashSet<&str> = files.iter().map(|f| f.name()).collect();
+            pending.extend(
+                files
+                    .iter()
+                    .flat_map(|f| f.dependency.iter())
+                    .filter(|dep| !known.contains(dep.as_str()))
+                    .filter(|dep| pool.get_file_by_name(dep).is_none())
+                    .map(|dep| MessageRequest::FileByFilename(dep.clone()))
+                    .collect::<Vec<_>>(),
+            );
+        }
+
+        let mut pool = self.descriptors.write().await;
+        let files: Vec<_> = files
+            .into_iter()
+            .filter(|f| pool.get_file_by_name(f.name()).is_none())
+            .collect();
+        pool.add_file_descriptor_protos(files)
+            .with_context(|| format!("Invalid descriptors for {service}"))
+    }
+
+    /// Resolve `package.Service/Method` to its request and response types.
+    async fn method(
+        &self,
+        command: &str,
+    ) -> anyhow::Result<(MessageDescriptor, MessageDescriptor)> {
+        let (service, method) = command
+            .split_once('/')
+            .ok_or_else(|| anyhow!("Invalid gRPC method {command:?}"))?;
+
+        if self
+            .descriptors
+            .read()
+            .await
+            .get_service_by_name(service)
+            .is_none()
+        {
+            self.load_service(service).await?;
+        }
+
+        let pool = self.descriptors.read().await;
+        let method = pool
+            .get_service_by_name(service)
+            .and_then(|s| s.methods().find(|m| m.name() == method))
+            .ok_or_else(|| anyhow!("gRPC method {command} is not offered by this miner"))?;
+        Ok((method.input(), method.output()))
+    }
+
+    async fn call(
+        &self,
+        command: &str,
+        parameters: Option<Value>,
+        token: Option<String>,
+    ) -> anyhow::Result<Value> {
+        let (input, output) = self.method(command).await?;
+        let message = DynamicMessage::deserialize(input, parameters.unwrap_or_else(|| json!({})))
+            .with_context(|| format!("Invalid request for {command}"))?;
+
+        let mut request = Request::new(message);
+        if let Some(token) = token {
+            request
+                .metadata_mut()
+                .insert("authorization", MetadataValue::try_from(token.as_str())?);
+        }
+
+        let mut grpc = Grpc::new(self.channel()?);
+        grpc.ready()
+            .await
+            .map_err(|e| Status::unavailable(e.to_string()))?;
+        let response = grpc
+            .unary(
+                request,
+                PathAndQuery::try_from(format!("/{command}"))?,
+                DynamicCodec { output },
+            )
+            .await?
+            .into_inner();
+
+        to_json(&response)
+    }
+
+    async fn ensure_authenticated(&self) -> anyhow::Result<()> {
+        if self.token.read().await.is_some() {
+            return Ok(());
+        }
+        let mut guard = self.token.write().await;
+        if guard.is_some() {
+            return Ok(());
+        }
+
+        let token = match &self.auth {
+            MinerAuth::TokenAuth(token) => token.expose_secret().to_string(),
+            MinerAuth::UserAndPass(_) => {
+                let login = json!({
+                    "username": self.auth.username(),
+                    "password": self.auth.password(),
+                });
+                self.call(LOGIN, Some(login), None)
+                    .await?
+                    .get("token")
+                    .and_then(Value::as_str)
+                    .filter(|token| !token.is_empty())
+                    .map(String::from)
+                    .ok_or_else(|| anyhow!("No token in Braiins gRPC login response"))?
+            }
+            MinerAuth::KeyAuth(_) => bail!("Private key auth is only accepted over SSH"),
+        };
+        *guard = Some(token);
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl GRPCClient for BraiinsGRPCAPI {
+    async fn send_command(
+        &self,
+        command: &str,
+        _privileged: bool,
+        parameters: Option<Value>,
+    ) -> anyhow::Result<Value> {
+        self.ensure_authenticated().await?;
+        let token = self.token.read().await.clone();
+        match self.call(command, parameters.clone(), token).await {
+            Err(e)
+                if e.downcast_ref::<Status>()
+                    .is_some_and(|status| status.code() == Code::Unauthenticated) =>
+            {
+                // The session expired; log in again once.
+                *self.token.write().await = None;
+                self.ensure_authenticated().await?;
+                let token = self.token.read().await.clone();
+                self.call(command, parameters, token).await
+            }
+            result => result,
+        }
+    }
+}
+
+#[async_trait]
+impl APIClient for BraiinsGRPCAPI {
+    async fn get_api_result(&self, command: &MinerCommand) -> anyhow::Result<Value> {
+        match command {
+            MinerCommand::GRPC { command } => self.send_command(command, false, None).await,
+            _ => Err(anyhow!("Unsupported command type for Braiins gRPC API")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use prost_types::{
+        DescriptorProto, EnumDescriptorProto, EnumValueDescriptorProto, FieldDescriptorProto,
+        field_descriptor_proto::{Label, Type},
+    };
+
+    use super::*;
+    use crate::backends::configuration::rest_parse_network;
+
+    fn field(name: &str, number: i32, r#type: Type, label: Label) -> FieldDescriptorProto {
+        FieldDescriptorProto {
+            name: Some(name.to_string()),
+            json_name: None,
+            number: Some(number),
+            r#type: Some(r#type as i32),
+            label: Some(label as i32),
+            type_name: (r#type == Type::Enum).then(|| ".test.NetworkProtocol".to_string()),
+            ..Default::default()
+        }
+    }
+
+    /// A trimmed down `GetNetworkInfoResponse`.
+    fn network_info() -> MessageDescriptor {
+        let file = FileDescriptorProto {
+            name: Some("test.proto".to_string()),
+            package: Some("test".to_string()),
+            syntax: Some("proto3".to_string()),
+            enum_type: vec![EnumDescriptorProto {
+                name: Some("NetworkProtocol".to_string()),
+                value: ["NETWORK_PROTOCOL_UNSPECIFIED", "NETWORK_PROTOCOL_DHCP"]
+                    .iter()
+                    .zip(0..)
+                    .map(|(name, number)| EnumValueDescriptorProto {
+                        name: Some(name.to_string()),
+                        number: Some(number),
+                        ..Default::default()
+                    })
+                    .collect(),
+                ..Default::default()
+            }],
+            message_type: vec![DescriptorProto {
+                name: Some("GetNetworkInfoResponse".to_string()),
+                field: vec![
+                    field("hostname", 1, Type::String, Label::Optional),
+                    field("protocol", 2, Type::Enum, Label::Optional),
+                    field("dns_servers", 3, Type::String, Label::Repeated),
+                    field("default_gateway", 4, Type::String, Label::Optional),
+                ],
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        let pool = DescriptorPool::from_file_descriptor_set(prost_types::FileDescriptorSet {
+            file: vec![file],
+        })
+        .unwrap();
+        pool.get_message_by_name("test.GetNetworkInfoResponse")
+            .unwrap()
+    }
+
+    #[test]
+    fn responses_use_the_rest_api_shape() -> anyhow::Result<()> {
+        let message = DynamicMessage::deserialize(
+            network_info(),
+            json!({
+                "hostname": "miner-01",
+                "protocol": "NETWORK_PROTOCOL_DHCP",
+                "dns_servers": ["10.0.0.1"],
+            }),
+        )?;
+        let mut encoded = Vec::new();
+        message.encode(&mut encoded)?;
+        let decoded = DynamicMessage::decode(network_info(), encoded.as_slice())?;
+
+        let value = to_json(&decoded)?;
+        assert_eq!(value["protocol"], 1);
+        assert_eq!(value["default_gateway"], "");
+        assert_eq!(value["dns_servers"], json!(["10.0.0.1"]));
+
+        let config = rest_parse_network(&value)?;
+        assert!(config.dhcp);
+        assert_eq!(config.hostname.as_deref(), Some("miner-01"));
+        assert_eq!(config.dns, Some(vec!["10.0.0.1".parse()?]));
+        Ok(())
+    }
+}
@@ -1,3 +1,6 @@
+pub(crate) mod configuration;
+pub mod grpc;
+pub(crate) mod upgrade;
 pub(crate) mod util;
 pub mod v21_09;
 pub mod v25_03;
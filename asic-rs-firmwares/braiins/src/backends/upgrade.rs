@@ -0,0 +1,278 @@
+use std::{fmt, net::IpAddr, time::Duration};
+
+use anyhow::{Context, bail};
+use asic_rs_core::{
+    data::firmware::{self, FirmwareImage, upload_body},
+    traits::miner::GetFirmwareVersion,
+};
+use reqwest::{Client, multipart};
+use serde_json::json;
+use sha2::{Digest, Sha256};
+
+const UPGRADE_TIMEOUT: Duration = Duration::from_secs(15 * 60);
+
+/// Control board families Braiins OS ships separate upgrade images for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum BraiinsPlatform {
+    CVITek,
+    BeagleBoneBlack,
+    AMLogic,
+    Xilinx,
+    BraiinsCB,
+}
+
+impl fmt::Display for BraiinsPlatform {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::CVITek => write!(f, "CVITek"),
+            Self::BeagleBoneBlack => write!(f, "BeagleBone Black"),
+            Self::AMLogic => write!(f, "AMLogic"),
+            Self::Xilinx => write!(f, "Xilinx"),
+            Self::BraiinsCB => write!(f, "Braiins control board"),
+        }
+    }
+}
+
+impl BraiinsPlatform {
+    const ALL: [Self; 5] = [
+        Self::CVITek,
+        Self::BeagleBoneBlack,
+        Self::AMLogic,
+        Self::Xilinx,
+        Self::BraiinsCB,
+    ];
+
+    /// Filename tokens Braiins uses to mark the target platform of an image,
+    /// e.g. `braiins-os_am3-aml_ssh_26.04.tar`.
+    fn filename_tokens(&self) -> &'static [&'static str] {
+        match self {
+            Self::CVITek => &["cvi", "cvitek"],
+            Self::BeagleBoneBlack => &["bbb"],
+            Self::AMLogic => &["aml", "amlogic"],
+            Self::Xilinx => &["am1", "s9", "zynq", "xil"],
+            Self::BraiinsCB => &["bmm", "bmm1", "bcb"],
+        }
+    }
+
+    /// Parse the REST `control_board_soc_family` enum value.
+    pub(crate) fn from_soc_family(value: u64) -> Option<Self> {
+        match value {
+            1 => Some(Self::CVITek),
+            2 => Some(Self::BeagleBoneBlack),
+            3 => Some(Self::AMLogic),
+            4 => Some(Self::Xilinx),
+            5 => Some(Self::BraiinsCB),
+            _ => None,
+        }
+    }
+
+    /// Parse the GraphQL `bos.info.controlBoardSocFamily` string.
+    pub(crate) fn from_soc_family_name(value: &str) -> Option<Self> {
+        let value = value.to_ascii_lowercase();
+        if value.contains("cvi") {
+            Some(Self::CVITek)
+        } else if value.contains("beagle") || value.contains("bbb") {
+            Some(Self::BeagleBoneBlack)
+        } else if value.contains("aml") {
+            Some(Self::AMLogic)
+        } else if value.contains("xilinx") || value.contains("zynq") {
+            Some(Self::Xilinx)
+        } else if value.contains("braiins") || value.contains("bcb") {
+            Some(Self::BraiinsCB)
+        } else {
+            None
+        }
+    }
+
+    fn from_filename(filename: &str) -> Vec<Self> {
+        let filename = filename.to_ascii_lowercase();
+        let tokens: Vec<&str> = filename
+            .split(|c: char| !c.is_ascii_alphanumeric())
+            .filter(|t| !t.is_empty())
+            .collect();
+        Self::ALL
+            .into_iter()
+            .filter(|platform| {
+                platform
+                    .filename_tokens()
+                    .iter()
+                    .any(|token| tokens.contains(token))
+            })
+            .collect()
+    }
+}
+
+/// Reject images whose filename names a different control board than the one
+/// the miner is running on.
+///
+/// Images without a recognizable platform marker are passed through and left
+/// for the firmware to validate.
+pub(crate) fn check_image_platform(
+    image: &FirmwareImage,
+    platform: Option<BraiinsPlatform>,
+) -> anyhow::Result<()> {
+    if image.bytes.is_empty() {
+        bail!("Firmware image {} is empty", image.filename);
+    }
+    let Some(platform) = platform else {
+        return Ok(());
+    };
+    let targets = BraiinsPlatform::from_filename(&image.filename);
+    if !targets.is_empty() && !targets.contains(&platform) {
+        let targets = targets
+            .iter()
+            .map(ToString::to_string)
+            .collect::<Vec<_>>()
+            .join(", ");
+        bail!(
+            "Firmware image {} is built for {} control boards, but this miner runs on {}",
+            image.filename,
+            targets,
+            platform
+        );
+    }
+    Ok(())
+}
+
+pub(crate) async fn sha256_hex(bytes: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    for chunk in bytes.chunks(64 * 1024) {
+        hasher.update(chunk);
+        tokio::task::yield_now().await;
+    }
+    format!("{:x}", hasher.finalize())
+}
+
+/// Upload a system upgrade image to the REST `upgrade/system-upgrade`
+/// endpoint of the 26.04+ web API.
+///
+/// The miner reboots into the new image once the upload is accepted.
+pub(crate) async fn post_system_upgrade(
+    client: &Client,
+    ip: IpAddr,
+    port: u16,
+    token: Option<String>,
+    timeout: Duration,
+    image: FirmwareImage,
+) -> anyhow::Result<()> {
+    let url = format!("http://{ip}:{port}/api/v1/upgrade/system-upgrade");
+
+    let FirmwareImage { filename, bytes } = image;
+    let size = bytes.len() as u64;
+    let metadata = json!({
+        "size": size,
+        "hash": sha256_hex(&bytes).await,
+        "discard_settings": false,
+        "disable_cleanup": false,
+    });
+    let form = multipart::Form::new()
+        .part(
+            "metadata",
+            multipart::Part::text(metadata.to_string())
+                .mime_str("application/json")
+                .context("failed to set upgrade metadata mime type")?,
+        )
+        .part(
+            "data",
+            multipart::Part::stream_with_length(upload_body(ip, bytes), size)
+                .file_name(filename)
+                .mime_str("application/octet-stream")
+                .context("failed to set firmware part mime type")?,
+        );
+
+    let mut request = client
+        .post(&url)
+        .multipart(form)
+        .timeout(timeout.max(Duration::from_secs(600)));
+    if let Some(token) = token {
+        request = request.header("Authorization", token);
+    }
+
+    let response = request
+        .send()
+        .await
+        .context("firmware upload HTTP request failed")?;
+
+    let status = response.status();
+    match status.as_u16() {
+        200..=299 => Ok(()),
+        404 | 405 => {
+            bail!("This Braiins OS version does not accept firmware uploads over the REST API")
+        }
+        409 => bail!("Another system task is already in progress on the miner"),
+        _ => {
+            let body = response.text().await.unwrap_or_default();
+            bail!(
+                "Firmware upload failed with status code {}: {}",
+                status,
+                body
+            )
+        }
+    }
+}
+
+/// Wait for the miner to come back from an upgrade reporting a firmware
+/// version different from `previous`.
+pub(crate) async fn wait_for_upgraded_version<M: GetFirmwareVersion>(
+    miner: &M,
+    previous: &str,
+) -> anyhow::Result<String> {
+    firmware::wait_for_upgraded_version(miner, previous, None, UPGRADE_TIMEOUT).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn image(filename: &str) -> FirmwareImage {
+        FirmwareImage::new(filename.to_string(), vec![0; 16])
+    }
+
+    #[test]
+    fn matching_platform_is_accepted() {
+        assert!(
+            check_image_platform(
+                &image("braiins-os_am3-aml_ssh_26.04.tar"),
+                Some(BraiinsPlatform::AMLogic)
+            )
+            .is_ok()
+        );
+    }
+
+    #[test]
+    fn mismatched_platform_is_rejected() {
+        let err = check_image_platform(
+            &image("braiins-os_am3-bbb_ssh_26.04.tar"),
+            Some(BraiinsPlatform::AMLogic),
+        )
+        .unwrap_err();
+
+        assert!(err.to_string().contains("BeagleBone Black"));
+        assert!(err.to_string().contains("AMLogic"));
+    }
+
+    #[test]
+    fn unmarked_image_is_passed_through() {
+        assert!(check_image_platform(&image("upgrade.tar"), Some(BraiinsPlatform::CVITek)).is_ok());
+        assert!(check_image_platform(&image("braiins-os_am3-bbb.tar"), None).is_ok());
+    }
+
+    #[test]
+    fn empty_image_is_rejected() {
+        let empty = FirmwareImage::new("braiins-os.tar".to_string(), vec![]);
+        assert!(check_image_platform(&empty, None).is_err());
+    }
+
+    #[test]
+    fn graphql_soc_family_names_are_parsed() {
+        assert_eq!(
+            BraiinsPlatform::from_soc_family_name("AMLogic"),
+            Some(BraiinsPlatform::AMLogic)
+        );
+        assert_eq!(
+            BraiinsPlatform::from_soc_family_name("CVITEK"),
+            Some(BraiinsPlatform::CVITek)
+        );
+        assert_eq!(BraiinsPlatform::from_soc_family_name("unknown"), None);
+    }
+}
@@ -1,8 +1,14 @@
+use asic_rs_core::config::syslog::{SyslogProtocol, SyslogTarget};
+use asic_rs_core::data::board::MinerControlBoard;
 use asic_rs_core::data::capabilities::{
     HashRateTuningCapabilities, PowerTuningCapabilities, TuningCapabilities,
 };
+use asic_rs_core::data::control_board::ControlBoardData;
 use asic_rs_core::data::hashrate::{HashRate, HashRateUnit};
 use asic_rs_core::data::miner::TuningTarget;
+use asic_rs_core::data::psu::PsuData;
+use asic_rs_makes_antminer::hardware::AntMinerControlBoard;
+use asic_rs_makes_braiins::hardware::BraiinsControlBoard;
 use measurements::Power;
 use serde_json::Value;
 
@@ -110,3 +116,144 @@ fn parse_tagged_tuning_target(value: &Value, prefix: &str) -> Option<TuningTarge
         _ => power.or(hashrate),
     }
 }
+
+/// Build [`PsuData`] from the `psu_info` object of `GetMinerDetails`, which
+/// the REST and gRPC BraiinsOS backends return in the same shape.
+pub(crate) fn psu_info(info: &Value) -> Option<PsuData> {
+    let text = |key: &str| info.get(key).and_then(Value::as_str).map(String::from);
+    let psu = PsuData {
+        model: text("model_name"),
+        serial_number: text("serial_number"),
+        firmware_version: text("fw_version"),
+        ..PsuData::new(0)
+    };
+    (psu.model.is_some() || psu.serial_number.is_some()).then_some(psu)
+}
+
+/// Map the `control_board_soc_family` enum of `GetMinerDetails`, shared by the
+/// REST and gRPC BraiinsOS backends, to a control board.
+pub(crate) fn soc_family_control_board(value: u64) -> Option<MinerControlBoard> {
+    match value {
+        1 => Some(AntMinerControlBoard::CVITek.into()),
+        2 => Some(AntMinerControlBoard::BeagleBoneBlack.into()),
+        3 => Some(AntMinerControlBoard::AMLogic.into()),
+        4 => Some(AntMinerControlBoard::Xilinx.into()),
+        5 => Some(BraiinsControlBoard::BraiinsCB.into()),
+        _ => None,
+    }
+}
+
+/// Read a `/proc/meminfo` line such as `MemTotal:  250832 kB`, in bytes.
+fn meminfo_bytes(meminfo: &str, key: &str) -> Option<u64> {
+    meminfo.lines().find_map(|line| {
+        let value = line.strip_prefix(key)?.strip_prefix(':')?;
+        let kb: u64 = value.split_whitespace().next()?.parse().ok()?;
+        Some(kb * 1024)
+    })
+}
+
+/// Build [`ControlBoardData`] from Linux system files read over SSH, tagged
+/// `loadavg` (`/proc/loadavg`), `meminfo` (`/proc/meminfo`), `storage`
+/// (`df -k` of the data partition) and `kernel` (`uname -r`).
+pub(crate) fn parse_linux_stats(stats: &Value) -> Option<ControlBoardData> {
+    let output = |tag: &str| stats.get(tag).and_then(Value::as_str);
+
+    let cpu_load =
+        output("loadavg").and_then(|loadavg| loadavg.split_whitespace().next()?.parse().ok());
+
+    let meminfo = output("meminfo").unwrap_or_default();
+    let memory_total = meminfo_bytes(meminfo, "MemTotal");
+    let memory_available = meminfo_bytes(meminfo, "MemAvailable").or_else(|| {
+        ["MemFree", "Buffers", "Cached"]
+            .iter()
+            .map(|key| meminfo_bytes(meminfo, key))
+            .sum()
+    });
+    let memory_used = memory_total
+        .zip(memory_available)
+        .map(|(total, available)| total.saturating_sub(available));
+
+    // BusyBox wraps long filesystem names onto their own line, so read the
+    // size columns from the end of the last line.
+    let storage = output("storage")
+        .and_then(|df| df.lines().last())
+        .map(|line| line.split_whitespace().collect::<Vec<_>>())
+        .filter(|columns| columns.len() >= 5);
+    let storage_column = |offset: usize| {
+        let columns = storage.as_ref()?;
+        let kb: u64 = columns[columns.len() - offset].parse().ok()?;
+        Some(kb * 1024)
+    };
+
+    let control_board = ControlBoardData {
+        cpu_load,
+        memory_used,
+        memory_total,
+        storage_total: storage_column(5),
+        storage_used: storage_column(4),
+        kernel_version: output("kernel")
+            .map(str::trim)
+            .filter(|kernel| !kernel.is_empty())
+            .map(String::from),
+        ..Default::default()
+    };
+    (!control_board.is_empty()).then_some(control_board)
+}
+
+/// Build the shell command that points OpenWrt's `logd` at a remote syslog
+/// receiver, or stops forwarding with `None`, and restarts it.
+pub(crate) fn syslog_uci_command(target: Option<&SyslogTarget>) -> String {
+    const SYSTEM: &str = "system.@system[0]";
+    let update = match target {
+        Some(target) => {
+            let protocol = match target.protocol {
+                SyslogProtocol::Udp => "udp",
+                SyslogProtocol::Tcp => "tcp",
+            };
+            format!(
+                "uci set {SYSTEM}.log_ip='{}' && uci set {SYSTEM}.log_port='{}' && uci set {SYSTEM}.log_proto='{protocol}'",
+                target.host, target.port
+            )
+        }
+        None => format!(
+            "uci -q delete {SYSTEM}.log_ip; uci -q delete {SYSTEM}.log_port; uci -q delete {SYSTEM}.log_proto; true"
+        ),
+    };
+    format!("{update} && uci commit system && /etc/init.d/log restart")
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn parse_linux_stats_test() {
+        let stats = json!({
+            "loadavg": "1.52 1.31 1.20 2/97 2811\n",
+            "meminfo": "MemTotal:         250832 kB\nMemFree:           61620 kB\nMemAvailable:     146140 kB\nBuffers:            4520 kB\n",
+            "storage": "Filesystem           1K-blocks      Used Available Use% Mounted on\n/dev/ubi0_1\n                         52392     13108     39284  25% /overlay\n",
+            "kernel": "4.9.113\n",
+        });
+        let control_board = parse_linux_stats(&stats).unwrap();
+        assert_eq!(control_board.cpu_load, Some(1.52));
+        assert_eq!(control_board.memory_total, Some(250832 * 1024));
+        assert_eq!(control_board.memory_used, Some((250832 - 146140) * 1024));
+        assert_eq!(control_board.storage_total, Some(52392 * 1024));
+        assert_eq!(control_board.storage_used, Some(13108 * 1024));
+        assert_eq!(control_board.kernel_version.as_deref(), Some("4.9.113"));
+
+        assert_eq!(parse_linux_stats(&json!({})), None);
+    }
+
+    #[test]
+    fn syslog_uci_command_test() {
+        let target = SyslogTarget::tcp("10.0.0.5".parse().unwrap()).with_port(1514);
+        assert_eq!(
+            syslog_uci_command(Some(&target)),
+            "uci set system.@system[0].log_ip='10.0.0.5' && uci set system.@system[0].log_port='1514' && uci set system.@system[0].log_proto='tcp' && uci commit system && /etc/init.d/log restart"
+        );
+        assert!(syslog_uci_command(None).starts_with("uci -q delete system.@system[0].log_ip;"));
+    }
+}
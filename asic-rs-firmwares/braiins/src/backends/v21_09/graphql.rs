@@ -208,6 +208,68 @@ impl BraiinsGraphQLAPI {
             && result.pointer("/bos/factoryReset").is_some())
     }
 
+    /// Apply an `AutotuningIn` input (tuner target and performance scaling).
+    /// Available from 25.03 on.
+    pub async fn update_autotuning(&self, input: Value) -> anyhow::Result<()> {
+        let mutation = r#"mutation ($input: AutotuningIn!) {
+            bosminer {
+                config {
+                    updateAutotuning(input: $input, apply: true) {
+                        ... on Error {
+                            message
+                        }
+                    }
+                }
+            }
+        }"#;
+
+        let result = self
+            .send_graphql_command(mutation, true, Some(json!({ "input": input })))
+            .await?;
+
+        match result.pointer("/bosminer/config/updateAutotuning/message") {
+            Some(message) => Err(anyhow::anyhow!("Failed to update autotuning: {message}")),
+            None => Ok(()),
+        }
+    }
+
+    /// Apply a `TempAndFansIn` input (temperature control and fan settings).
+    /// Available from 25.03 on.
+    pub async fn update_temp_and_fans(&self, input: Value) -> anyhow::Result<()> {
+        let mutation = r#"mutation ($input: TempAndFansIn!) {
+            bosminer {
+                config {
+                    updateTempAndFans(input: $input, apply: true) {
+                        ... on Error {
+                            message
+                        }
+                    }
+                }
+            }
+        }"#;
+
+        let result = self
+            .send_graphql_command(mutation, true, Some(json!({ "input": input })))
+            .await?;
+
+        match result.pointer("/bosminer/config/updateTempAndFans/message") {
+            Some(message) => Err(anyhow::anyhow!(
+                "Failed to update temperature and fans: {message}"
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Returns the control board SoC family, which GraphQL reports from 25.03 on.
+    pub async fn control_board_soc_family(&self) -> Option<String> {
+        self.send_graphql_command("{ bos { info { controlBoardSocFamily } } }", true, None)
+            .await
+            .ok()?
+            .pointer("/bos/info/controlBoardSocFamily")
+            .and_then(Value::as_str)
+            .map(String::from)
+    }
+
     pub async fn read_logs(&self) -> anyhow::Result<String> {
         let log_query = r#"query ($target: LogTarget!) {
             bos {
@@ -5,6 +5,7 @@ use asic_rs_core::{
     config::{
         collector::{ConfigCollector, ConfigField, ConfigLocation},
         pools::PoolGroupConfig,
+        syslog::SyslogTarget,
     },
     data::{
         board::BoardData,
@@ -13,13 +14,17 @@ use asic_rs_core::{
             DataCollector, DataExtensions, DataExtractor, DataField, DataLocation, get_by_pointer,
         },
         command::MinerCommand,
+        control_board::ControlBoardData,
         device::{DeviceInfo, HashAlgorithm},
         fan::FanData,
+        firmware::{self, FirmwareImage},
         hashrate::{HashRate, HashRateUnit},
         message::{MessageSeverity, MinerMessage},
         miner::TuningTarget,
+        network::{NetworkData, SYSFS_LINK_STATS_COMMAND},
         pool::{PoolData, PoolGroupData, PoolURL},
     },
+    ssh::SSHClient,
     traits::{miner::*, model::MinerModel},
 };
 use async_trait::async_trait;
@@ -28,8 +33,12 @@ use measurements::{AngularVelocity, Frequency, Power, Temperature, Voltage};
 use serde_json::{Value, json};
 use web::BraiinsWebAPI;
 
-use crate::backends::util::{
-    parse_configured_tuning_target, parse_scaled_tuning_target, power_target_capabilities,
+use crate::backends::{
+    upgrade::{check_image_platform, wait_for_upgraded_version},
+    util::{
+        parse_configured_tuning_target, parse_linux_stats, parse_scaled_tuning_target,
+        power_target_capabilities, syslog_uci_command,
+    },
 };
 
 use crate::{
@@ -47,7 +56,9 @@ pub struct BraiinsV2109 {
     pub rpc: BraiinsRPCAPI,
     pub graphql: BraiinsGraphQLAPI,
     pub web: BraiinsWebAPI,
+    pub ssh: SSHClient,
     pub device_info: DeviceInfo,
+    ssh_auth_set: bool,
 }
 
 impl BraiinsV2109 {
@@ -57,8 +68,10 @@ impl BraiinsV2109 {
             ip,
             rpc: BraiinsRPCAPI::new(ip),
             graphql: BraiinsGraphQLAPI::new(ip, auth.clone()),
-            web: BraiinsWebAPI::new(ip, auth),
+            web: BraiinsWebAPI::new(ip, auth.clone()),
+            ssh: SSHClient::new(ip, auth),
             device_info: DeviceInfo::new(model, BraiinsFirmware::default(), HashAlgorithm::SHA256),
+            ssh_auth_set: false,
         }
     }
 }
@@ -70,6 +83,7 @@ impl APIClient for BraiinsV2109 {
             MinerCommand::RPC { .. } => self.rpc.get_api_result(command).await,
             MinerCommand::GraphQL { .. } => self.graphql.get_api_result(command).await,
             MinerCommand::WebAPI { .. } => self.web.get_api_result(command).await,
+            MinerCommand::SSH { .. } => self.ssh.get_api_result(command).await,
             _ => Err(anyhow::anyhow!("Unsupported command type for Braiins API")),
         }
     }
@@ -199,6 +213,21 @@ impl GetDataLocations for BraiinsV2109 {
             command: "admin/network/iface_status/lan",
             parameters: None,
         };
+        const SSH_LOADAVG: MinerCommand = MinerCommand::SSH {
+            command: "cat /proc/loadavg",
+        };
+        const SSH_MEMINFO: MinerCommand = MinerCommand::SSH {
+            command: "cat /proc/meminfo",
+        };
+        const SSH_STORAGE: MinerCommand = MinerCommand::SSH {
+            command: "df -k /overlay",
+        };
+        const SSH_KERNEL: MinerCommand = MinerCommand::SSH {
+            command: "uname -r",
+        };
+        const SSH_LINK_STATS: MinerCommand = MinerCommand::SSH {
+            command: SYSFS_LINK_STATS_COMMAND,
+        };
         const GQL_POWER_TARGET_META_QUERY: MinerCommand = MinerCommand::GraphQL {
             command: r#"{
                 bosminer {
@@ -232,6 +261,52 @@ impl GetDataLocations for BraiinsV2109 {
                     tag: None,
                 },
             )],
+            // Control board stats are only read over SSH, so they are opt-in:
+            // a filtered port 22 would otherwise stall every poll.
+            DataField::ControlBoard if !self.ssh_auth_set => vec![],
+            DataField::ControlBoard => vec![
+                (
+                    SSH_LOADAVG,
+                    DataExtractor {
+                        func: get_by_pointer,
+                        key: Some(""),
+                        tag: Some("loadavg"),
+                    },
+                ),
+                (
+                    SSH_MEMINFO,
+                    DataExtractor {
+                        func: get_by_pointer,
+                        key: Some(""),
+                        tag: Some("meminfo"),
+                    },
+                ),
+                (
+                    SSH_STORAGE,
+                    DataExtractor {
+                        func: get_by_pointer,
+                        key: Some(""),
+                        tag: Some("storage"),
+                    },
+                ),
+                (
+                    SSH_KERNEL,
+                    DataExtractor {
+                        func: get_by_pointer,
+                        key: Some(""),
+                        tag: Some("kernel"),
+                    },
+                ),
+            ],
+            DataField::Network if !self.ssh_auth_set => vec![],
+            DataField::Network => vec![(
+                SSH_LINK_STATS,
+                DataExtractor {
+                    func: get_by_pointer,
+                    key: Some(""),
+                    tag: Some("link_stats"),
+                },
+            )],
             DataField::Hostname => vec![(
                 GQL_SYSTEM,
                 DataExtractor {
@@ -407,6 +482,13 @@ impl GetHostname for BraiinsV2109 {
     }
 }
 
+impl GetNetwork for BraiinsV2109 {
+    fn parse_network(&self, data: &HashMap<DataField, Value>) -> Option<NetworkData> {
+        let link_stats = data.get(&DataField::Network)?.get("link_stats")?.as_str()?;
+        Some(NetworkData::default().with_sysfs_link_stats(link_stats))
+    }
+}
+
 impl GetApiVersion for BraiinsV2109 {
     fn parse_api_version(&self, data: &HashMap<DataField, Value>) -> Option<String> {
         data.extract::<String>(DataField::ApiVersion)
@@ -420,6 +502,14 @@ impl GetFirmwareVersion for BraiinsV2109 {
 }
 
 impl GetControlBoardVersion for BraiinsV2109 {}
+
+impl GetControlBoard for BraiinsV2109 {
+    fn parse_control_board(&self, data: &HashMap<DataField, Value>) -> Option<ControlBoardData> {
+        data.get(&DataField::ControlBoard)
+            .and_then(parse_linux_stats)
+    }
+}
+
 impl GetTuningCapabilities for BraiinsV2109 {
     fn parse_tuning_capabilities(
         &self,
@@ -554,6 +644,8 @@ impl GetFans for BraiinsV2109 {
 
 impl GetPsuFans for BraiinsV2109 {}
 
+impl GetPsus for BraiinsV2109 {}
+
 impl GetFluidTemperature for BraiinsV2109 {}
 
 impl GetWattage for BraiinsV2109 {
@@ -773,11 +865,21 @@ impl Restart for BraiinsV2109 {
                 }
             }
         }"#;
-        Ok(self
+        if self
             .graphql
             .send_command(mutation, true, None)
             .await
-            .is_ok())
+            .is_ok()
+        {
+            return Ok(true);
+        }
+
+        // bosminer may be hung badly enough that the GraphQL API is down;
+        // reboot the control board over SSH instead. The connection usually
+        // drops before an exit status is sent.
+        tracing::warn!(miner_ip = %self.ip, "GraphQL reboot failed, rebooting over SSH");
+        let output = self.ssh.execute("reboot").await?;
+        Ok(output.exit_status.is_none_or(|status| status == 0))
     }
     fn supports_restart(&self) -> bool {
         true
@@ -859,6 +961,30 @@ impl ReadLogs for BraiinsV2109 {
     }
 }
 
+#[async_trait]
+impl SetSyslogTarget for BraiinsV2109 {
+    async fn set_syslog_target(&self, target: Option<SyslogTarget>) -> anyhow::Result<bool> {
+        // BraiinsOS is OpenWrt based; remote logging is a `logd` setting with
+        // no GraphQL equivalent, so it is configured over SSH.
+        let output = self
+            .ssh
+            .execute(&syslog_uci_command(target.as_ref()))
+            .await?;
+        if !output.success() {
+            anyhow::bail!(
+                "Failed to set syslog target on {}: {}",
+                self.ip,
+                output.stderr.trim()
+            );
+        }
+        Ok(true)
+    }
+
+    fn supports_set_syslog_target(&self) -> bool {
+        true
+    }
+}
+
 #[async_trait]
 impl FactoryReset for BraiinsV2109 {
     async fn factory_reset(&self) -> anyhow::Result<bool> {
@@ -964,8 +1090,19 @@ impl SupportsScalingConfig for BraiinsV2109 {
 
 #[async_trait]
 impl UpgradeFirmware for BraiinsV2109 {
+    async fn upgrade_firmware(&self, image: FirmwareImage) -> anyhow::Result<bool> {
+        // 21.09 does not report its control board, so LuCI validates the image.
+        check_image_platform(&image, None)?;
+        let previous = firmware::version_before_upgrade(self).await?;
+        self.web.sysupgrade(image).await?;
+
+        let version = wait_for_upgraded_version(self, &previous).await?;
+        tracing::info!(miner_ip = %self.ip, version = %version, "Braiins firmware upgrade complete");
+        Ok(true)
+    }
+
     fn supports_upgrade_firmware(&self) -> bool {
-        false
+        true
     }
 }
 
@@ -978,7 +1115,19 @@ impl HasDefaultAuth for BraiinsV2109 {
 impl HasAuth for BraiinsV2109 {
     fn set_auth(&mut self, auth: MinerAuth) {
         self.web.set_auth(auth.clone());
-        self.graphql.set_auth(auth);
+        self.graphql.set_auth(auth.clone());
+        // The web password is root's login password, so SSH follows it until
+        // SSH has credentials of its own.
+        if !self.ssh_auth_set {
+            self.ssh.set_auth(auth);
+        }
+    }
+
+    /// Also enables collecting [`DataField::ControlBoard`] and the link stats
+    /// in [`DataField::Network`], which are only available over SSH.
+    fn set_ssh_auth(&mut self, auth: MinerAuth) {
+        self.ssh.set_auth(auth);
+        self.ssh_auth_set = true;
     }
 }
 
@@ -999,6 +1148,9 @@ impl SupportsFanConfig for BraiinsV2109 {
 impl SupportsTemperatureConfig for BraiinsV2109 {}
 impl GetTuningPercent for BraiinsV2109 {}
 impl SetTuningPercent for BraiinsV2109 {}
+impl GetApplianceConfig for BraiinsV2109 {}
+impl SetApplianceConfig for BraiinsV2109 {}
+impl SupportsNetworkConfig for BraiinsV2109 {}
 
 #[cfg(test)]
 mod tests {
@@ -1008,13 +1160,51 @@ mod tests {
     use super::*;
     use crate::test::json::v21_09::{
         GQL_BOARDS_COMMAND, GQL_POOLS_COMMAND, GQL_SYSTEM_COMMAND, GQL_TUNING_COMMAND,
-        VERSION_COMMAND, WEB_NET_CONF_COMMAND,
+        SSH_LINK_STATS_COMMAND, VERSION_COMMAND, WEB_NET_CONF_COMMAND,
     };
 
     fn gql(raw: &str) -> Value {
         Value::from_str(raw).unwrap()["data"].clone()
     }
 
+    #[test]
+    fn control_board_stats_need_ssh_credentials() {
+        let mut miner = BraiinsV2109::new(IpAddr::from([127, 0, 0, 1]), AntMinerModel::S9);
+        assert!(miner.get_locations(DataField::ControlBoard).is_empty());
+
+        miner.set_auth(MinerAuth::new("root", "secret"));
+        assert!(miner.get_locations(DataField::ControlBoard).is_empty());
+
+        miner.set_ssh_auth(MinerAuth::new("root", "secret"));
+        assert_eq!(miner.get_locations(DataField::ControlBoard).len(), 4);
+    }
+
+    #[tokio::test]
+    async fn link_stats_are_read_over_ssh() {
+        let mut miner = BraiinsV2109::new(IpAddr::from([127, 0, 0, 1]), AntMinerModel::S9);
+        assert!(miner.get_locations(DataField::Network).is_empty());
+        miner.set_ssh_auth(MinerAuth::new("root", "secret"));
+
+        let mut results = HashMap::new();
+        results.insert(
+            MinerCommand::SSH {
+                command: SYSFS_LINK_STATS_COMMAND,
+            },
+            Value::String(SSH_LINK_STATS_COMMAND.to_string()),
+        );
+        let mock_api = MockAPIClient::new(results);
+        let mut collector = DataCollector::new_with_client(&miner, &mock_api);
+        let data = collector.collect(&[DataField::Network]).await;
+
+        let network = miner.parse_network(&data).unwrap();
+        assert_eq!(network.interface.as_deref(), Some("eth0"));
+        assert_eq!(network.link_speed, Some(100));
+        assert_eq!(network.full_duplex, Some(true));
+        assert_eq!(network.rx_errors, Some(0));
+        assert_eq!(network.rx_dropped, Some(5213));
+        assert_eq!(network.tx_dropped, Some(0));
+    }
+
     #[tokio::test]
     async fn test_braiins_os() {
         let miner = BraiinsV2109::new(IpAddr::from([127, 0, 0, 1]), AntMinerModel::S9);
@@ -2,9 +2,16 @@ use std::{net::IpAddr, time::Duration};
 
 use once_cell::sync::OnceCell;
 
-use asic_rs_core::{data::command::MinerCommand, traits::miner::*};
+use anyhow::{Context, bail};
+use asic_rs_core::{
+    data::{
+        command::MinerCommand,
+        firmware::{FirmwareImage, upload_body},
+    },
+    traits::miner::*,
+};
 use async_trait::async_trait;
-use reqwest::{Client, Method};
+use reqwest::{Client, Method, multipart};
 use serde_json::Value;
 use tokio::sync::RwLock;
 
@@ -120,6 +127,75 @@ impl BraiinsWebAPI {
             .await
             .map_err(|e| anyhow::anyhow!("Luci parse error: {}", e))
     }
+
+    /// Flash a system upgrade image through LuCI's `flashops/sysupgrade` page.
+    ///
+    /// LuCI validates the uploaded image first and only starts flashing once the
+    /// verification step is confirmed; settings are kept across the upgrade.
+    pub async fn sysupgrade(&self, image: FirmwareImage) -> anyhow::Result<()> {
+        self.ensure_authenticated().await?;
+
+        let url = format!(
+            "http://{}:{}/cgi-bin/luci/admin/system/flashops/sysupgrade",
+            self.ip, self.port
+        );
+        let client = self.client()?;
+        let session = self.session_id.read().await.clone().unwrap_or_default();
+
+        let FirmwareImage { filename, bytes } = image;
+        let size = bytes.len() as u64;
+        let form = multipart::Form::new().text("keep", "1").part(
+            "image",
+            multipart::Part::stream_with_length(upload_body(self.ip, bytes), size)
+                .file_name(filename)
+                .mime_str("application/octet-stream")
+                .context("failed to set firmware part mime type")?,
+        );
+
+        let response = client
+            .post(&url)
+            .header("User-Agent", "BTC Tools v0.1")
+            .header("Cookie", format!("session_id={}", session))
+            .multipart(form)
+            .timeout(self.timeout.max(Duration::from_secs(600)))
+            .send()
+            .await
+            .context("firmware upload HTTP request failed")?;
+
+        let status = response.status();
+        let body = response
+            .text()
+            .await
+            .context("failed to read firmware upload response body")?;
+        if !status.is_success() {
+            bail!("Firmware upload failed with status code {}", status);
+        }
+        if body.contains("does not contain a supported format") {
+            bail!(
+                "Firmware image was rejected by the miner: unsupported image format for this platform"
+            );
+        }
+
+        let response = client
+            .post(&url)
+            .header("User-Agent", "BTC Tools v0.1")
+            .header("Cookie", format!("session_id={}", session))
+            .header("Content-Type", "application/x-www-form-urlencoded")
+            .body("step=2&keep=1")
+            .timeout(self.timeout)
+            .send()
+            .await
+            .context("firmware flash confirmation failed")?;
+        if !response.status().is_success() {
+            bail!(
+                "Firmware flash confirmation failed with status code {}",
+                response.status()
+            );
+        }
+
+        *self.session_id.write().await = None;
+        Ok(())
+    }
 }
 
 #[async_trait]
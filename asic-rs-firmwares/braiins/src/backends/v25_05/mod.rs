@@ -3,22 +3,30 @@ use std::{collections::HashMap, net::IpAddr, str::FromStr, time::Duration};
 use anyhow;
 use asic_rs_core::{
     config::{
-        collector::{ConfigCollector, ConfigField, ConfigLocation},
+        collector::{ConfigCollector, ConfigExtractor, ConfigField, ConfigLocation},
+        fan::FanConfig,
+        network::NetworkConfig,
         pools::PoolGroupConfig,
+        scaling::ScalingConfig,
+        temperature::TemperatureConfig,
+        tuning::TuningConfig,
     },
     data::{
-        board::BoardData,
+        board::{BoardData, MinerControlBoard},
         capabilities::TuningCapabilities,
         collector::{
             DataCollector, DataExtensions, DataExtractor, DataField, DataLocation, get_by_pointer,
         },
         command::MinerCommand,
+        control_board::ControlBoardData,
         device::{DeviceInfo, HashAlgorithm},
         fan::FanData,
+        firmware::{self, FirmwareImage},
         hashrate::{HashRate, HashRateUnit},
         message::{MessageSeverity, MinerMessage},
         miner::TuningTarget,
         pool::{PoolData, PoolGroupData, PoolURL},
+        psu::PsuData,
     },
     traits::{miner::*, model::MinerModel},
 };
@@ -29,8 +37,12 @@ use serde_json::{Value, json};
 
 use crate::{
     backends::{
+        configuration,
+        grpc::{BraiinsGRPCAPI, GRPC_MINER_DETAILS, GRPC_NETWORK_INFO},
+        upgrade::{BraiinsPlatform, check_image_platform, wait_for_upgraded_version},
         util::{
             parse_configured_tuning_target, parse_scaled_tuning_target, power_target_capabilities,
+            psu_info, soc_family_control_board,
         },
         v21_09::{graphql::BraiinsGraphQLAPI, rpc::BraiinsRPCAPI, web::BraiinsWebAPI},
     },
@@ -43,6 +55,7 @@ pub struct BraiinsV2505 {
     pub rpc: BraiinsRPCAPI,
     pub graphql: BraiinsGraphQLAPI,
     pub web: BraiinsWebAPI,
+    pub grpc: BraiinsGRPCAPI,
     pub device_info: DeviceInfo,
 }
 
@@ -53,7 +66,8 @@ impl BraiinsV2505 {
             ip,
             rpc: BraiinsRPCAPI::new(ip),
             graphql: BraiinsGraphQLAPI::new(ip, auth.clone()),
-            web: BraiinsWebAPI::new(ip, auth),
+            web: BraiinsWebAPI::new(ip, auth.clone()),
+            grpc: BraiinsGRPCAPI::new(ip, auth),
             device_info: DeviceInfo::new(model, BraiinsFirmware::default(), HashAlgorithm::SHA256),
         }
     }
@@ -66,15 +80,39 @@ impl APIClient for BraiinsV2505 {
             MinerCommand::RPC { .. } => self.rpc.get_api_result(command).await,
             MinerCommand::GraphQL { .. } => self.graphql.get_api_result(command).await,
             MinerCommand::WebAPI { .. } => self.web.get_api_result(command).await,
+            MinerCommand::GRPC { .. } => self.grpc.get_api_result(command).await,
             _ => Err(anyhow::anyhow!("Unsupported command type for Braiins API")),
         }
     }
 }
 
 impl GetConfigsLocations for BraiinsV2505 {
-    #[allow(unused_variables)]
     fn get_configs_locations(&self, data_field: ConfigField) -> Vec<ConfigLocation> {
-        vec![]
+        let key = match data_field {
+            ConfigField::Network => {
+                return vec![(
+                    GRPC_NETWORK_INFO,
+                    ConfigExtractor {
+                        func: get_by_pointer,
+                        key: Some(""),
+                        tag: None,
+                    },
+                )];
+            }
+            ConfigField::Tuning => "/bosminer/config/autotuning",
+            ConfigField::Fan => "/bosminer/config",
+            ConfigField::Temperature => "/bosminer/config/tempControl",
+            ConfigField::Scaling => "/bosminer/config/performanceScaling",
+            _ => return vec![],
+        };
+        vec![(
+            configuration::GQL_CONFIG,
+            ConfigExtractor {
+                func: get_by_pointer,
+                key: Some(key),
+                tag: None,
+            },
+        )]
     }
 }
 
@@ -212,6 +250,38 @@ impl GetDataLocations for BraiinsV2505 {
         };
 
         match data_field {
+            DataField::SerialNumber => vec![(
+                GRPC_MINER_DETAILS,
+                DataExtractor {
+                    func: get_by_pointer,
+                    key: Some("/serial_number"),
+                    tag: None,
+                },
+            )],
+            DataField::ControlBoard => vec![(
+                GRPC_MINER_DETAILS,
+                DataExtractor {
+                    func: get_by_pointer,
+                    key: Some("/kernel_version"),
+                    tag: None,
+                },
+            )],
+            DataField::Psus => vec![(
+                GRPC_MINER_DETAILS,
+                DataExtractor {
+                    func: get_by_pointer,
+                    key: Some("/psu_info"),
+                    tag: None,
+                },
+            )],
+            DataField::ControlBoardVersion => vec![(
+                GRPC_MINER_DETAILS,
+                DataExtractor {
+                    func: get_by_pointer,
+                    key: Some("/control_board_soc_family"),
+                    tag: None,
+                },
+            )],
             DataField::ApiVersion => vec![(
                 RPC_VERSION,
                 DataExtractor {
@@ -403,6 +473,8 @@ impl GetHostname for BraiinsV2505 {
     }
 }
 
+impl GetNetwork for BraiinsV2505 {}
+
 impl GetApiVersion for BraiinsV2505 {
     fn parse_api_version(&self, data: &HashMap<DataField, Value>) -> Option<String> {
         data.extract::<String>(DataField::ApiVersion)
@@ -415,7 +487,26 @@ impl GetFirmwareVersion for BraiinsV2505 {
     }
 }
 
-impl GetControlBoardVersion for BraiinsV2505 {}
+impl GetControlBoardVersion for BraiinsV2505 {
+    fn parse_control_board_version(
+        &self,
+        data: &HashMap<DataField, Value>,
+    ) -> Option<MinerControlBoard> {
+        data.extract::<u64>(DataField::ControlBoardVersion)
+            .and_then(soc_family_control_board)
+    }
+}
+
+impl GetControlBoard for BraiinsV2505 {
+    fn parse_control_board(&self, data: &HashMap<DataField, Value>) -> Option<ControlBoardData> {
+        data.extract::<String>(DataField::ControlBoard)
+            .map(|kernel_version| ControlBoardData {
+                kernel_version: Some(kernel_version),
+                ..Default::default()
+            })
+    }
+}
+
 impl GetTuningCapabilities for BraiinsV2505 {
     fn parse_tuning_capabilities(
         &self,
@@ -559,6 +650,15 @@ impl GetFans for BraiinsV2505 {
 
 impl GetPsuFans for BraiinsV2505 {}
 
+impl GetPsus for BraiinsV2505 {
+    fn parse_psus(&self, data: &HashMap<DataField, Value>) -> Vec<PsuData> {
+        data.get(&DataField::Psus)
+            .and_then(psu_info)
+            .into_iter()
+            .collect()
+    }
+}
+
 impl GetFluidTemperature for BraiinsV2505 {}
 
 impl GetWattage for BraiinsV2505 {
@@ -706,7 +806,12 @@ impl GetPools for BraiinsV2505 {
     }
 }
 
-impl GetSerialNumber for BraiinsV2505 {}
+impl GetSerialNumber for BraiinsV2505 {
+    fn parse_serial_number(&self, data: &HashMap<DataField, Value>) -> Option<String> {
+        data.extract::<String>(DataField::SerialNumber)
+            .filter(|serial| !serial.is_empty())
+    }
+}
 
 #[async_trait]
 impl SetFaultLight for BraiinsV2505 {
@@ -864,6 +969,8 @@ impl ReadLogs for BraiinsV2505 {
     }
 }
 
+impl SetSyslogTarget for BraiinsV2505 {}
+
 #[async_trait]
 impl FactoryReset for BraiinsV2505 {
     async fn factory_reset(&self) -> anyhow::Result<bool> {
@@ -961,15 +1068,46 @@ impl SupportsPoolsConfig for BraiinsV2505 {
 
 #[async_trait]
 impl SupportsScalingConfig for BraiinsV2505 {
+    async fn set_scaling_config(&self, config: ScalingConfig) -> anyhow::Result<bool> {
+        let input = json!({ "performanceScaling": configuration::gql_scaling_input(&config) });
+        self.graphql.update_autotuning(input).await?;
+        Ok(true)
+    }
+
+    fn parse_scaling_config(
+        &self,
+        data: &HashMap<ConfigField, Value>,
+    ) -> anyhow::Result<ScalingConfig> {
+        data.get(&ConfigField::Scaling)
+            .ok_or_else(|| anyhow::anyhow!("No performance scaling settings in bosminer config"))
+            .and_then(configuration::gql_parse_scaling)
+    }
+
     fn supports_scaling_config(&self) -> bool {
-        false
+        true
     }
 }
 
 #[async_trait]
 impl UpgradeFirmware for BraiinsV2505 {
+    async fn upgrade_firmware(&self, image: FirmwareImage) -> anyhow::Result<bool> {
+        let platform = self
+            .graphql
+            .control_board_soc_family()
+            .await
+            .as_deref()
+            .and_then(BraiinsPlatform::from_soc_family_name);
+        check_image_platform(&image, platform)?;
+        let previous = firmware::version_before_upgrade(self).await?;
+        self.web.sysupgrade(image).await?;
+
+        let version = wait_for_upgraded_version(self, &previous).await?;
+        tracing::info!(miner_ip = %self.ip, version = %version, "Braiins firmware upgrade complete");
+        Ok(true)
+    }
+
     fn supports_upgrade_firmware(&self) -> bool {
-        false
+        true
     }
 }
 
@@ -982,27 +1120,110 @@ impl HasDefaultAuth for BraiinsV2505 {
 impl HasAuth for BraiinsV2505 {
     fn set_auth(&mut self, auth: MinerAuth) {
         self.web.set_auth(auth.clone());
-        self.graphql.set_auth(auth);
+        self.graphql.set_auth(auth.clone());
+        self.grpc.set_auth(auth);
     }
 }
 
 #[async_trait]
 impl SupportsTuningConfig for BraiinsV2505 {
+    async fn set_tuning_config(
+        &self,
+        config: TuningConfig,
+        scaling_config: Option<ScalingConfig>,
+    ) -> anyhow::Result<bool> {
+        let input = configuration::gql_autotuning_input(&config, scaling_config.as_ref())?;
+        self.graphql.update_autotuning(input).await?;
+        Ok(true)
+    }
+
+    fn parse_tuning_config(
+        &self,
+        data: &HashMap<ConfigField, Value>,
+    ) -> anyhow::Result<TuningConfig> {
+        data.get(&ConfigField::Tuning)
+            .ok_or_else(|| anyhow::anyhow!("No autotuning settings in bosminer config"))
+            .and_then(configuration::gql_parse_tuning)
+    }
+
     fn supports_tuning_config(&self) -> bool {
-        false
+        true
     }
 }
 
 #[async_trait]
 impl SupportsFanConfig for BraiinsV2505 {
+    async fn set_fan_config(&self, config: FanConfig) -> anyhow::Result<bool> {
+        let input = configuration::gql_fan_input(&config)?;
+        self.graphql.update_temp_and_fans(input).await?;
+        Ok(true)
+    }
+
+    fn parse_fan_config(&self, data: &HashMap<ConfigField, Value>) -> anyhow::Result<FanConfig> {
+        data.get(&ConfigField::Fan)
+            .ok_or_else(|| anyhow::anyhow!("No fan settings in bosminer config"))
+            .and_then(configuration::gql_parse_fan)
+    }
+
     fn supports_fan_config(&self) -> bool {
-        false
+        true
+    }
+}
+
+#[async_trait]
+impl SupportsTemperatureConfig for BraiinsV2505 {
+    async fn set_temperature_config(&self, config: TemperatureConfig) -> anyhow::Result<bool> {
+        let input = configuration::gql_temperature_input(&config)?;
+        self.graphql.update_temp_and_fans(input).await?;
+        Ok(true)
+    }
+
+    fn parse_temperature_config(
+        &self,
+        data: &HashMap<ConfigField, Value>,
+    ) -> anyhow::Result<TemperatureConfig> {
+        data.get(&ConfigField::Temperature)
+            .map(configuration::gql_parse_temperature)
+            .ok_or_else(|| anyhow::anyhow!("No temperature settings in bosminer config"))
+    }
+
+    fn supports_temperature_config(&self) -> bool {
+        true
     }
 }
 
-impl SupportsTemperatureConfig for BraiinsV2505 {}
 impl GetTuningPercent for BraiinsV2505 {}
 impl SetTuningPercent for BraiinsV2505 {}
+impl GetApplianceConfig for BraiinsV2505 {}
+impl SetApplianceConfig for BraiinsV2505 {}
+
+#[async_trait]
+impl SupportsNetworkConfig for BraiinsV2505 {
+    async fn apply_network_config(&self, config: NetworkConfig) -> anyhow::Result<bool> {
+        let request = configuration::grpc_network_request(&config);
+        self.grpc
+            .send_command(
+                "braiins.bos.v1.NetworkService/SetNetworkConfiguration",
+                true,
+                Some(request),
+            )
+            .await?;
+        Ok(true)
+    }
+
+    fn parse_network_config(
+        &self,
+        data: &HashMap<ConfigField, Value>,
+    ) -> anyhow::Result<NetworkConfig> {
+        data.get(&ConfigField::Network)
+            .ok_or_else(|| anyhow::anyhow!("No network info"))
+            .and_then(configuration::rest_parse_network)
+    }
+
+    fn supports_network_config(&self) -> bool {
+        true
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -3,8 +3,13 @@ use std::{collections::HashMap, net::IpAddr, str::FromStr, time::Duration};
 use anyhow;
 use asic_rs_core::{
     config::{
-        collector::{ConfigCollector, ConfigField, ConfigLocation},
+        collector::{ConfigCollector, ConfigExtractor, ConfigField, ConfigLocation},
+        fan::FanConfig,
+        network::NetworkConfig,
         pools::PoolGroupConfig,
+        scaling::ScalingConfig,
+        temperature::TemperatureConfig,
+        tuning::TuningConfig,
     },
     data::{
         board::{BoardData, MinerControlBoard},
@@ -13,17 +18,17 @@ use asic_rs_core::{
             DataCollector, DataExtensions, DataExtractor, DataField, DataLocation, get_by_pointer,
         },
         command::MinerCommand,
+        control_board::ControlBoardData,
         device::{DeviceInfo, HashAlgorithm},
         fan::FanData,
         hashrate::{HashRate, HashRateUnit},
         message::{MessageSeverity, MinerMessage},
         miner::TuningTarget,
         pool::{PoolData, PoolGroupData, PoolURL},
+        psu::PsuData,
     },
     traits::{miner::*, model::MinerModel},
 };
-use asic_rs_makes_antminer::hardware::AntMinerControlBoard;
-use asic_rs_makes_braiins::hardware::BraiinsControlBoard;
 use async_trait::async_trait;
 use macaddr::MacAddr;
 use measurements::{AngularVelocity, Frequency, Power, Temperature, Voltage};
@@ -33,9 +38,10 @@ use web::BraiinsWebAPI;
 
 use crate::{
     backends::{
+        configuration,
         util::{
-            parse_configured_tuning_target, parse_scaled_tuning_target,
-            tuner_constraints_capabilities,
+            parse_configured_tuning_target, parse_scaled_tuning_target, psu_info,
+            soc_family_control_board, tuner_constraints_capabilities,
         },
         v21_09::graphql::BraiinsGraphQLAPI,
     },
@@ -76,9 +82,39 @@ impl APIClient for BraiinsV2507 {
 }
 
 impl GetConfigsLocations for BraiinsV2507 {
-    #[allow(unused_variables)]
     fn get_configs_locations(&self, data_field: ConfigField) -> Vec<ConfigLocation> {
-        vec![]
+        const WEB_MINER_CONFIGURATION: MinerCommand = MinerCommand::WebAPI {
+            command: "configuration/miner",
+            parameters: None,
+        };
+
+        let key = match data_field {
+            ConfigField::Network => {
+                return vec![(
+                    MinerCommand::WebAPI {
+                        command: "network",
+                        parameters: None,
+                    },
+                    ConfigExtractor {
+                        func: get_by_pointer,
+                        key: Some(""),
+                        tag: None,
+                    },
+                )];
+            }
+            ConfigField::Tuning => "/tuner",
+            ConfigField::Fan | ConfigField::Temperature => "/temperature",
+            ConfigField::Scaling => "/dps",
+            _ => return vec![],
+        };
+        vec![(
+            WEB_MINER_CONFIGURATION,
+            ConfigExtractor {
+                func: get_by_pointer,
+                key: Some(key),
+                tag: None,
+            },
+        )]
     }
 }
 
@@ -312,6 +348,22 @@ impl GetDataLocations for BraiinsV2507 {
                     tag: None,
                 },
             )],
+            DataField::ControlBoard => vec![(
+                WEB_MINER_DETAILS,
+                DataExtractor {
+                    func: get_by_pointer,
+                    key: Some("/kernel_version"),
+                    tag: None,
+                },
+            )],
+            DataField::Psus => vec![(
+                WEB_MINER_DETAILS,
+                DataExtractor {
+                    func: get_by_pointer,
+                    key: Some("/psu_info"),
+                    tag: None,
+                },
+            )],
             DataField::Messages => vec![(
                 GQL_EVENTS_QUERY,
                 DataExtractor {
@@ -364,6 +416,8 @@ impl GetHostname for BraiinsV2507 {
     }
 }
 
+impl GetNetwork for BraiinsV2507 {}
+
 impl GetApiVersion for BraiinsV2507 {
     fn parse_api_version(&self, data: &HashMap<DataField, Value>) -> Option<String> {
         let major = data.extract_nested::<f64>(DataField::ApiVersion, "major");
@@ -592,16 +646,18 @@ impl GetControlBoardVersion for BraiinsV2507 {
         &self,
         data: &HashMap<DataField, Value>,
     ) -> Option<MinerControlBoard> {
-        let cb_type = data.extract::<u64>(DataField::ControlBoardVersion)?;
-        match cb_type {
-            0 => None,
-            1 => Some(AntMinerControlBoard::CVITek).map(|cb| cb.into()),
-            2 => Some(AntMinerControlBoard::BeagleBoneBlack).map(|cb| cb.into()),
-            3 => Some(AntMinerControlBoard::AMLogic).map(|cb| cb.into()),
-            4 => Some(AntMinerControlBoard::Xilinx).map(|cb| cb.into()),
-            5 => Some(BraiinsControlBoard::BraiinsCB).map(|cb| cb.into()),
-            _ => None,
-        }
+        data.extract::<u64>(DataField::ControlBoardVersion)
+            .and_then(soc_family_control_board)
+    }
+}
+
+impl GetControlBoard for BraiinsV2507 {
+    fn parse_control_board(&self, data: &HashMap<DataField, Value>) -> Option<ControlBoardData> {
+        data.extract::<String>(DataField::ControlBoard)
+            .map(|kernel_version| ControlBoardData {
+                kernel_version: Some(kernel_version),
+                ..Default::default()
+            })
     }
 }
 
@@ -638,6 +694,15 @@ impl GetTuningCapabilities for BraiinsV2507 {
 
 impl GetPsuFans for BraiinsV2507 {}
 
+impl GetPsus for BraiinsV2507 {
+    fn parse_psus(&self, data: &HashMap<DataField, Value>) -> Vec<PsuData> {
+        data.get(&DataField::Psus)
+            .and_then(psu_info)
+            .into_iter()
+            .collect()
+    }
+}
+
 impl GetMessages for BraiinsV2507 {
     fn parse_messages(&self, data: &HashMap<DataField, Value>) -> Vec<MinerMessage> {
         let mut messages: Vec<MinerMessage> = Vec::new();
@@ -823,6 +888,8 @@ impl ReadLogs for BraiinsV2507 {
     }
 }
 
+impl SetSyslogTarget for BraiinsV2507 {}
+
 impl FactoryReset for BraiinsV2507 {
     fn supports_factory_reset(&self) -> bool {
         false
@@ -831,13 +898,35 @@ impl FactoryReset for BraiinsV2507 {
 
 #[async_trait]
 impl SupportsScalingConfig for BraiinsV2507 {
+    async fn set_scaling_config(&self, config: ScalingConfig) -> anyhow::Result<bool> {
+        self.web
+            .send_command(
+                "performance/dps",
+                true,
+                Some(configuration::rest_dps_request(&config)),
+                Method::PUT,
+            )
+            .await?;
+        Ok(true)
+    }
+
+    fn parse_scaling_config(
+        &self,
+        data: &HashMap<ConfigField, Value>,
+    ) -> anyhow::Result<ScalingConfig> {
+        data.get(&ConfigField::Scaling)
+            .ok_or_else(|| anyhow::anyhow!("No DPS settings in miner configuration"))
+            .and_then(configuration::rest_parse_scaling)
+    }
+
     fn supports_scaling_config(&self) -> bool {
-        false
+        true
     }
 }
 
 #[async_trait]
 impl UpgradeFirmware for BraiinsV2507 {
+    // The 25.07 public API has no upgrade endpoints; they arrived in 26.04.
     fn supports_upgrade_firmware(&self) -> bool {
         false
     }
@@ -858,21 +947,122 @@ impl HasAuth for BraiinsV2507 {
 
 #[async_trait]
 impl SupportsTuningConfig for BraiinsV2507 {
+    async fn set_tuning_config(
+        &self,
+        config: TuningConfig,
+        scaling_config: Option<ScalingConfig>,
+    ) -> anyhow::Result<bool> {
+        let (endpoint, body) = configuration::rest_tuning_request(&config)?;
+        self.web
+            .send_command(endpoint, true, Some(body), Method::PUT)
+            .await?;
+        if let Some(scaling_config) = scaling_config {
+            self.set_scaling_config(scaling_config).await?;
+        }
+        Ok(true)
+    }
+
+    fn parse_tuning_config(
+        &self,
+        data: &HashMap<ConfigField, Value>,
+    ) -> anyhow::Result<TuningConfig> {
+        data.get(&ConfigField::Tuning)
+            .ok_or_else(|| anyhow::anyhow!("No tuner settings in miner configuration"))
+            .and_then(configuration::rest_parse_tuning)
+    }
+
     fn supports_tuning_config(&self) -> bool {
-        false
+        true
     }
 }
 
 #[async_trait]
 impl SupportsFanConfig for BraiinsV2507 {
+    async fn set_fan_config(&self, config: FanConfig) -> anyhow::Result<bool> {
+        let body = configuration::rest_cooling_mode_request(&config)?;
+        self.web
+            .send_command("cooling/mode", true, Some(body), Method::PUT)
+            .await?;
+        if let Some(min_fans) = config.min_fans() {
+            let body = configuration::rest_min_fans_request(min_fans);
+            self.web
+                .send_command("cooling/min-required-fans", true, Some(body), Method::PUT)
+                .await?;
+        }
+        Ok(true)
+    }
+
+    fn parse_fan_config(&self, data: &HashMap<ConfigField, Value>) -> anyhow::Result<FanConfig> {
+        data.get(&ConfigField::Fan)
+            .ok_or_else(|| anyhow::anyhow!("No cooling settings in miner configuration"))
+            .and_then(configuration::rest_parse_fan)
+    }
+
     fn supports_fan_config(&self) -> bool {
-        false
+        true
+    }
+}
+
+#[async_trait]
+impl SupportsTemperatureConfig for BraiinsV2507 {
+    async fn set_temperature_config(&self, config: TemperatureConfig) -> anyhow::Result<bool> {
+        // Thresholds are set per cooling mode, so update the active one.
+        let miner_config = self
+            .web
+            .send_command("configuration/miner", true, None, Method::GET)
+            .await?;
+        let temperature = miner_config
+            .get("temperature")
+            .ok_or_else(|| anyhow::anyhow!("No cooling settings in miner configuration"))?;
+        let body = configuration::rest_temperature_request(temperature, &config)?;
+        self.web
+            .send_command("cooling/mode", true, Some(body), Method::PUT)
+            .await?;
+        Ok(true)
+    }
+
+    fn parse_temperature_config(
+        &self,
+        data: &HashMap<ConfigField, Value>,
+    ) -> anyhow::Result<TemperatureConfig> {
+        data.get(&ConfigField::Temperature)
+            .ok_or_else(|| anyhow::anyhow!("No cooling settings in miner configuration"))
+            .and_then(configuration::rest_parse_temperature)
+    }
+
+    fn supports_temperature_config(&self) -> bool {
+        true
     }
 }
 
-impl SupportsTemperatureConfig for BraiinsV2507 {}
 impl GetTuningPercent for BraiinsV2507 {}
 impl SetTuningPercent for BraiinsV2507 {}
+impl GetApplianceConfig for BraiinsV2507 {}
+impl SetApplianceConfig for BraiinsV2507 {}
+
+#[async_trait]
+impl SupportsNetworkConfig for BraiinsV2507 {
+    async fn apply_network_config(&self, config: NetworkConfig) -> anyhow::Result<bool> {
+        let body = configuration::rest_network_request(&config);
+        self.web
+            .send_command("network/configuration", true, Some(body), Method::PATCH)
+            .await?;
+        Ok(true)
+    }
+
+    fn parse_network_config(
+        &self,
+        data: &HashMap<ConfigField, Value>,
+    ) -> anyhow::Result<NetworkConfig> {
+        data.get(&ConfigField::Network)
+            .ok_or_else(|| anyhow::anyhow!("No network info"))
+            .and_then(configuration::rest_parse_network)
+    }
+
+    fn supports_network_config(&self) -> bool {
+        true
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -1002,6 +1192,8 @@ mod tests {
         assert_eq!(miner_data.serial_number, None);
         assert_eq!(miner_data.hashboards.len(), 3);
         assert_eq!(miner_data.fans.len(), 4);
+        assert_eq!(miner_data.psus.len(), 1);
+        assert_eq!(miner_data.psus[0].model.as_deref(), Some("APW121215d/e"));
         assert_eq!(miner_data.light_flashing, Some(false));
         assert!(miner_data.is_mining);
         assert_eq!(miner_data.wattage, Some(Power::from_watts(3137.0)));
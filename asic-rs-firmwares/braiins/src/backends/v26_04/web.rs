@@ -2,12 +2,17 @@ use once_cell::sync::OnceCell;
 use std::{net::IpAddr, time::Duration};
 
 use anyhow;
-use asic_rs_core::{data::command::MinerCommand, traits::miner::*};
+use asic_rs_core::{
+    data::{command::MinerCommand, firmware::FirmwareImage},
+    traits::miner::*,
+};
 use async_trait::async_trait;
 use reqwest::{Client, Method, Response};
 use serde_json::Value;
 use tokio::sync::RwLock;
 
+use crate::backends::upgrade::post_system_upgrade;
+
 /// Braiins WebAPI client
 #[derive(Debug)]
 #[allow(dead_code)]
@@ -115,6 +120,8 @@ impl BraiinsWebAPI {
             MinerAuth::UserAndPass(creds) => {
                 self.authenticate(creds.password.expose_secret()).await?
             }
+            // Private keys are only accepted over SSH.
+            MinerAuth::KeyAuth(_) => return Err(BraiinsError::AuthenticationFailed),
         };
         *self.bearer_token.write().await = Some(token);
 
@@ -166,6 +173,28 @@ impl BraiinsWebAPI {
         Ok(logs)
     }
 
+    /// Upload and apply a system upgrade image through `upgrade/system-upgrade`.
+    ///
+    /// The miner reboots into the new image once the upload is accepted, so the
+    /// cached bearer token is dropped and a fresh one is issued on the next request.
+    pub async fn system_upgrade(&self, image: FirmwareImage) -> anyhow::Result<()> {
+        if let Err(e) = self.ensure_authenticated().await {
+            return Err(anyhow::anyhow!("Failed to authenticate: {}", e));
+        }
+        let token = self.bearer_token.read().await.clone();
+        post_system_upgrade(
+            self.client()?,
+            self.ip,
+            self.port,
+            token,
+            self.timeout,
+            image,
+        )
+        .await?;
+        *self.bearer_token.write().await = None;
+        Ok(())
+    }
+
     /// Execute the actual HTTP request
     async fn execute_request(
         &self,
@@ -6,3 +6,4 @@ pub(crate) const GQL_SYSTEM_COMMAND: &str = include_str!("gql_system.json");
 pub(crate) const GQL_TUNING_COMMAND: &str = include_str!("gql_tuning.json");
 pub(crate) const VERSION_COMMAND: &str = include_str!("version.json");
 pub(crate) const WEB_NET_CONF_COMMAND: &str = include_str!("web_net_conf.json");
+pub(crate) const SSH_LINK_STATS_COMMAND: &str = include_str!("ssh_link_stats.txt");
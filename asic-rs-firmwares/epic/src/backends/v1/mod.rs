@@ -5,6 +5,7 @@ use asic_rs_core::{
     config::{
         collector::{ConfigCollector, ConfigExtractor, ConfigField, ConfigLocation},
         fan::FanConfig,
+        network::NetworkConfig,
         pools::{PoolConfig, PoolGroupConfig},
         scaling::ScalingConfig,
         tuning::TuningConfig,
@@ -15,6 +16,7 @@ use asic_rs_core::{
             DataCollector, DataExtensions, DataExtractor, DataField, DataLocation, get_by_pointer,
         },
         command::MinerCommand,
+        control_board::ControlBoardData,
         device::{DeviceInfo, HashAlgorithm},
         fan::FanData,
         firmware::FirmwareImage,
@@ -22,6 +24,7 @@ use asic_rs_core::{
         message::{MessageSeverity, MinerMessage},
         miner::TuningTarget,
         pool::{PoolData, PoolGroupData, PoolURL},
+        psu::PsuData,
     },
     traits::{miner::*, model::MinerModel},
     util::unix_timestamp_secs,
@@ -30,7 +33,7 @@ use asic_rs_makes_antminer::hardware::AntMinerControlBoard;
 use asic_rs_makes_epic::hardware::EPicControlBoard;
 use async_trait::async_trait;
 use macaddr::MacAddr;
-use measurements::{AngularVelocity, Frequency, Power, Temperature, Voltage};
+use measurements::{AngularVelocity, Current, Frequency, Power, Temperature, Voltage};
 use reqwest::Method;
 use serde_json::{Value, json};
 use web::PowerPlayWebAPI;
@@ -127,6 +130,17 @@ impl GetConfigsLocations for PowerPlayV1 {
             parameters: None,
         };
         match data_field {
+            ConfigField::Network => vec![(
+                MinerCommand::WebAPI {
+                    command: "network",
+                    parameters: None,
+                },
+                ConfigExtractor {
+                    func: get_by_pointer,
+                    key: Some(""),
+                    tag: None,
+                },
+            )],
             ConfigField::Fan => vec![(
                 WEB_SUMMARY,
                 ConfigExtractor {
@@ -232,6 +246,14 @@ impl GetDataLocations for PowerPlayV1 {
                     tag: None,
                 },
             )],
+            DataField::ControlBoard => vec![(
+                WEB_SUMMARY,
+                DataExtractor {
+                    func: get_by_pointer,
+                    key: Some("/OS Type"),
+                    tag: None,
+                },
+            )],
             DataField::Uptime => vec![(
                 WEB_SUMMARY,
                 DataExtractor {
@@ -248,6 +270,24 @@ impl GetDataLocations for PowerPlayV1 {
                     tag: None,
                 },
             )],
+            DataField::Psus => vec![
+                (
+                    WEB_SUMMARY,
+                    DataExtractor {
+                        func: get_by_pointer,
+                        key: Some("/Power Supply Stats"),
+                        tag: Some("stats"),
+                    },
+                ),
+                (
+                    WEB_CAPABILITIES,
+                    DataExtractor {
+                        func: get_by_pointer,
+                        key: Some("/Psu Info"),
+                        tag: Some("info"),
+                    },
+                ),
+            ],
             DataField::TuningTarget => vec![(
                 WEB_SUMMARY,
                 DataExtractor {
@@ -457,6 +497,8 @@ impl GetHostname for PowerPlayV1 {
     }
 }
 
+impl GetNetwork for PowerPlayV1 {}
+
 impl GetApiVersion for PowerPlayV1 {
     fn parse_api_version(&self, data: &HashMap<DataField, Value>) -> Option<String> {
         data.extract::<String>(DataField::ApiVersion)
@@ -503,6 +545,16 @@ impl GetControlBoardVersion for PowerPlayV1 {
     }
 }
 
+impl GetControlBoard for PowerPlayV1 {
+    fn parse_control_board(&self, data: &HashMap<DataField, Value>) -> Option<ControlBoardData> {
+        data.extract::<String>(DataField::ControlBoard)
+            .map(|os_version| ControlBoardData {
+                os_version: Some(os_version),
+                ..Default::default()
+            })
+    }
+}
+
 impl GetHashboards for PowerPlayV1 {
     fn parse_hashboards(&self, data: &HashMap<DataField, Value>) -> Vec<BoardData> {
         let mut hashboards: Vec<BoardData> =
@@ -836,6 +888,33 @@ impl GetFans for PowerPlayV1 {
 }
 
 impl GetPsuFans for PowerPlayV1 {}
+
+impl GetPsus for PowerPlayV1 {
+    fn parse_psus(&self, data: &HashMap<DataField, Value>) -> Vec<PsuData> {
+        let Some(psu) = data.get(&DataField::Psus) else {
+            return vec![];
+        };
+        // PSUs without PMBus telemetry report zero for readings they lack.
+        let reading = |key: &str| {
+            psu.pointer(&format!("/stats/{key}"))
+                .and_then(Value::as_f64)
+                .filter(|value| *value > 0.0)
+        };
+
+        vec![PsuData {
+            firmware_version: psu.pointer("/info/FW").map(|fw| match fw {
+                Value::String(fw) => fw.clone(),
+                fw => fw.to_string(),
+            }),
+            input_voltage: reading("Input Voltage").map(Voltage::from_volts),
+            input_current: reading("Input Current").map(Current::from_amperes),
+            output_voltage: reading("Output Voltage").map(Voltage::from_volts),
+            output_power: reading("Output Power").map(Power::from_watts),
+            ..PsuData::new(0)
+        }]
+    }
+}
+
 impl GetTuningCapabilities for PowerPlayV1 {}
 
 impl GetFluidTemperature for PowerPlayV1 {}
@@ -853,6 +932,85 @@ fn tuning_value_as_f64(value: &Value) -> Option<f64> {
         .or_else(|| value.as_u64().map(|v| v as f64))
 }
 
+/// The accepted range in watts of the power-target perpetual tune option from
+/// `capabilities`.
+fn power_tune_option(capabilities: &Value) -> Option<(f64, f64)> {
+    capabilities
+        .get("PerpetualTune")
+        .and_then(Value::as_array)?
+        .iter()
+        .find_map(|option| {
+            let algorithm = option.get("algorithm")?.as_str()?;
+            matches!(algorithm, "Power" | "PowerTune").then_some((
+                option.get("min").and_then(tuning_value_as_f64)?,
+                option.get("max").and_then(tuning_value_as_f64)?,
+            ))
+        })
+}
+
+/// The `perpetualtune/algo` request body that runs `config` with the throttle
+/// limits from `scaling_config`.
+fn perpetual_tune_payload(
+    config: &TuningConfig,
+    scaling_config: Option<ScalingConfig>,
+) -> anyhow::Result<Value> {
+    config.ensure_target_only()?;
+    let parse_algorithm = |algorithm_input: &str| -> anyhow::Result<&'static str> {
+        let normalized_algorithm = algorithm_input
+            .trim()
+            .to_ascii_lowercase()
+            .replace([' ', '_', '-'], "");
+
+        match normalized_algorithm.as_str() {
+            "chiptune" => Ok("ChipTune"),
+            "voltageoptimizer" => Ok("VoltageOptimizer"),
+            "power" | "powertune" => Ok("PowerTune"),
+            "boardtune" => Ok("BoardTune"),
+            _ => anyhow::bail!(
+                "Unsupported perpetual tune algorithm '{algorithm_input}' for ePIC PowerPlay"
+            ),
+        }
+    };
+
+    let (algorithm, target) = match &config.target {
+        TuningTarget::Power(power) => (
+            "PowerTune",
+            to_non_negative_u32_target(power.as_watts(), "power")?,
+        ),
+        TuningTarget::HashRate(hashrate) => {
+            let algorithm_input = config.algorithm.as_deref().ok_or_else(|| {
+                anyhow::anyhow!("TuningConfig.algorithm is required for hashrate tuning")
+            })?;
+            let algorithm = parse_algorithm(algorithm_input)?;
+            anyhow::ensure!(
+                algorithm != "PowerTune",
+                "Hashrate tuning target cannot be used with PowerTune algorithm"
+            );
+            let target = to_non_negative_u32_target(
+                hashrate.clone().as_unit(HashRateUnit::TeraHash).value,
+                "hashrate",
+            )?;
+            (algorithm, target)
+        }
+        TuningTarget::MiningMode(_) => {
+            anyhow::bail!("MiningMode tuning target is not supported on ePIC PowerPlay")
+        }
+    };
+
+    let Some(scaling) = scaling_config else {
+        anyhow::bail!("ScalingConfig is required for ePIC PowerPlay")
+    };
+
+    Ok(json!({
+        "param": {
+            "algo": algorithm,
+            "target": target,
+            "min_throttle": scaling.minimum,
+            "throttle_step": scaling.step,
+        }
+    }))
+}
+
 fn first_perpetual_tune_algorithm(summary: &Value) -> Option<(&str, &Value)> {
     summary
         .pointer("/PerpetualTune/Algorithm")
@@ -1115,8 +1273,30 @@ impl SetFaultLight for PowerPlayV1 {
 
 #[async_trait]
 impl SetPowerLimit for PowerPlayV1 {
+    /// Run the power-target perpetual tune algorithm at `limit`, after checking
+    /// it against the range the miner reports in `capabilities`.
+    async fn set_power_limit(&self, limit: Power) -> anyhow::Result<bool> {
+        let capabilities = self
+            .web
+            .send_command("capabilities", false, None, Method::GET)
+            .await?;
+        let (min, max) = power_tune_option(&capabilities).ok_or_else(|| {
+            anyhow::anyhow!("ePIC PowerPlay does not offer a power target perpetual tune")
+        })?;
+
+        let watts = limit.as_watts();
+        anyhow::ensure!(
+            (min..=max).contains(&watts),
+            "Power limit {watts} W is outside the supported range of {min}-{max} W"
+        );
+
+        let scaling = self.get_scaling_config().await?;
+        self.set_tuning_config(TuningConfig::new(TuningTarget::Power(limit)), Some(scaling))
+            .await
+    }
+
     fn supports_set_power_limit(&self) -> bool {
-        false
+        true
     }
 }
 
@@ -1332,60 +1512,7 @@ impl SupportsTuningConfig for PowerPlayV1 {
         config: TuningConfig,
         scaling_config: Option<ScalingConfig>,
     ) -> anyhow::Result<bool> {
-        let parse_algorithm = |algorithm_input: &str| -> anyhow::Result<&'static str> {
-            let normalized_algorithm = algorithm_input
-                .trim()
-                .to_ascii_lowercase()
-                .replace([' ', '_', '-'], "");
-
-            match normalized_algorithm.as_str() {
-                "chiptune" => Ok("ChipTune"),
-                "voltageoptimizer" => Ok("VoltageOptimizer"),
-                "power" | "powertune" => Ok("PowerTune"),
-                "boardtune" => Ok("BoardTune"),
-                _ => anyhow::bail!(
-                    "Unsupported perpetual tune algorithm '{algorithm_input}' for ePIC PowerPlay"
-                ),
-            }
-        };
-
-        let (algorithm, target) = match &config.target {
-            TuningTarget::Power(power) => (
-                "PowerTune",
-                to_non_negative_u32_target(power.as_watts(), "power")?,
-            ),
-            TuningTarget::HashRate(hashrate) => {
-                let algorithm_input = config.algorithm.as_deref().ok_or_else(|| {
-                    anyhow::anyhow!("TuningConfig.algorithm is required for hashrate tuning")
-                })?;
-                let algorithm = parse_algorithm(algorithm_input)?;
-                anyhow::ensure!(
-                    algorithm != "PowerTune",
-                    "Hashrate tuning target cannot be used with PowerTune algorithm"
-                );
-                let target = to_non_negative_u32_target(
-                    hashrate.clone().as_unit(HashRateUnit::TeraHash).value,
-                    "hashrate",
-                )?;
-                (algorithm, target)
-            }
-            TuningTarget::MiningMode(_) => {
-                anyhow::bail!("MiningMode tuning target is not supported on ePIC PowerPlay")
-            }
-        };
-
-        let Some(scaling) = scaling_config else {
-            anyhow::bail!("ScalingConfig is required for ePIC PowerPlay")
-        };
-
-        let payload = json!({
-            "param": {
-                "algo": algorithm,
-                "target": target,
-                "min_throttle": scaling.minimum,
-                "throttle_step": scaling.step,
-            }
-        });
+        let payload = perpetual_tune_payload(&config, scaling_config)?;
 
         self.web
             .send_command("perpetualtune/algo", false, Some(payload), Method::POST)
@@ -1436,13 +1563,20 @@ impl SupportsFanConfig for PowerPlayV1 {
     }
 
     async fn set_fan_config(&self, config: FanConfig) -> anyhow::Result<bool> {
+        config.ensure_no_min_fans()?;
         let payload = match config {
             FanConfig::Auto {
                 target_temp,
                 idle_speed,
+                ..
             } => {
                 let idle_speed = idle_speed.unwrap_or(20);
-                let target_temp = target_temp.round().max(0.0) as u64;
+                let target_temp = target_temp
+                    .ok_or_else(|| {
+                        anyhow::anyhow!("ePIC automatic fan control needs a target temperature")
+                    })?
+                    .round()
+                    .max(0.0) as u64;
 
                 json!({
                     "param": {
@@ -1453,7 +1587,7 @@ impl SupportsFanConfig for PowerPlayV1 {
                     }
                 })
             }
-            FanConfig::Manual { fan_speed } => json!({
+            FanConfig::Manual { fan_speed, .. } => json!({
                 "param": {
                     "Manual": fan_speed,
                 }
@@ -1544,9 +1678,24 @@ impl ReadLogs for PowerPlayV1 {
     }
 }
 
+impl SetSyslogTarget for PowerPlayV1 {}
+
+#[async_trait]
 impl FactoryReset for PowerPlayV1 {
+    async fn factory_reset(&self) -> anyhow::Result<bool> {
+        self.web
+            .send_command(
+                "defaultconfig",
+                false,
+                Some(json!({ "param": null })),
+                Method::POST,
+            )
+            .await
+            .map(|v| v.get("result").and_then(Value::as_bool).unwrap_or(false))
+    }
+
     fn supports_factory_reset(&self) -> bool {
-        false
+        true
     }
 }
 
@@ -1576,6 +1725,80 @@ impl HasAuth for PowerPlayV1 {
 impl SupportsTemperatureConfig for PowerPlayV1 {}
 impl GetTuningPercent for PowerPlayV1 {}
 impl SetTuningPercent for PowerPlayV1 {}
+impl GetApplianceConfig for PowerPlayV1 {}
+impl SetApplianceConfig for PowerPlayV1 {}
+
+#[async_trait]
+impl SupportsNetworkConfig for PowerPlayV1 {
+    async fn apply_network_config(&self, config: NetworkConfig) -> anyhow::Result<bool> {
+        if config.hostname.is_some() {
+            anyhow::bail!("ePIC network config does not include the hostname");
+        }
+
+        // PowerPlay takes a primary and an optional secondary DNS server.
+        let mut dns = config.dns.iter().flatten().map(ToString::to_string);
+        let param = if config.dhcp {
+            json!({ "dhcp": null })
+        } else {
+            json!({
+                "static": {
+                    "address": config.ip.map(|ip| ip.to_string()),
+                    "netmask": config.netmask.map(|mask| mask.to_string()),
+                    "gateway": config.gateway.map(|gateway| gateway.to_string()),
+                    "dns": dns.next(),
+                    "dns2": dns.next(),
+                }
+            })
+        };
+
+        self.web
+            .send_command(
+                "network",
+                false,
+                Some(json!({ "param": param })),
+                Method::POST,
+            )
+            .await
+            .map(|v| v.get("result").and_then(Value::as_bool).unwrap_or(false))
+    }
+
+    fn parse_network_config(
+        &self,
+        data: &HashMap<ConfigField, Value>,
+    ) -> anyhow::Result<NetworkConfig> {
+        let network = data
+            .get(&ConfigField::Network)
+            .and_then(Value::as_object)
+            .ok_or_else(|| anyhow::anyhow!("No network config data"))?;
+
+        // The interface settings are keyed by how the address is assigned.
+        let (dhcp, settings) = match (network.get("dhcp"), network.get("static")) {
+            (Some(settings), _) => (true, settings),
+            (None, Some(settings)) => (false, settings),
+            _ => anyhow::bail!("Unknown ePIC network mode"),
+        };
+        let addr = |key: &str| settings.get(key)?.as_str()?.parse().ok();
+
+        Ok(NetworkConfig {
+            dhcp,
+            ip: addr("address"),
+            netmask: addr("netmask"),
+            gateway: addr("gateway"),
+            dns: Some(
+                ["dns", "dns2"]
+                    .into_iter()
+                    .filter_map(addr)
+                    .collect::<Vec<_>>(),
+            )
+            .filter(|dns| !dns.is_empty()),
+            hostname: None,
+        })
+    }
+
+    fn supports_network_config(&self) -> bool {
+        true
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -1683,6 +1906,20 @@ mod tests {
 
         assert_eq!(miner_data.uptime, Some(Duration::from_secs(23170)));
         assert_eq!(miner_data.wattage, Some(Power::from_watts(2166.6174)));
+        assert_eq!(miner_data.psus.len(), 1);
+        assert_eq!(
+            miner_data
+                .control_board
+                .as_ref()
+                .and_then(|cb| cb.os_version.as_deref()),
+            Some("umcOS")
+        );
+        assert_eq!(miner_data.psus[0].firmware_version.as_deref(), Some("25"));
+        assert_eq!(miner_data.psus[0].input_voltage, None);
+        assert_eq!(
+            miner_data.psus[0].output_voltage,
+            Some(Voltage::from_volts(12.595))
+        );
         assert_eq!(miner_data.hashboards.len(), 3);
         assert_eq!(miner_data.hashboards[0].active, Some(false));
         assert_eq!(miner_data.hashboards[1].chips.len(), 110);
@@ -1706,6 +1943,57 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn power_tune_option_test() -> anyhow::Result<()> {
+        let capabilities = Value::from_str(CAPABILITIES)?;
+        assert_eq!(power_tune_option(&capabilities), None);
+
+        let capabilities = json!({
+            "PerpetualTune": [
+                { "algorithm": "VoltageOptimizer", "min": 60, "max": 300 },
+                { "algorithm": "Power", "min": 1500, "max": 3500 },
+            ]
+        });
+        assert_eq!(power_tune_option(&capabilities), Some((1500.0, 3500.0)));
+        Ok(())
+    }
+
+    #[test]
+    fn power_limit_runs_power_tune() -> anyhow::Result<()> {
+        let config = TuningConfig::new(TuningTarget::Power(Power::from_watts(3000.0)));
+        let payload = perpetual_tune_payload(&config, Some(ScalingConfig::new(5, 60)))?;
+        assert_eq!(
+            payload,
+            json!({
+                "param": {
+                    "algo": "PowerTune",
+                    "target": 3000,
+                    "min_throttle": 60,
+                    "throttle_step": 5,
+                }
+            })
+        );
+
+        assert!(perpetual_tune_payload(&config, None).is_err());
+
+        let overclock = config.with_voltage(Voltage::from_millivolts(1300.0));
+        assert!(perpetual_tune_payload(&overclock, Some(ScalingConfig::new(5, 60))).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn parse_network_config_test() -> anyhow::Result<()> {
+        let miner = PowerPlayV1::new(IpAddr::from([127, 0, 0, 1]), AntMinerModel::S19XP);
+        let data = HashMap::from([(ConfigField::Network, Value::from_str(NETWORK)?)]);
+
+        let config = miner.parse_network_config(&data)?;
+        assert!(config.dhcp);
+        assert_eq!(config.ip, Some(IpAddr::from([10, 0, 81, 19])));
+        assert_eq!(config.gateway, Some(IpAddr::from([10, 0, 81, 1])));
+        assert_eq!(config.dns, Some(vec![IpAddr::from([8, 8, 8, 8])]));
+        Ok(())
+    }
+
     #[test]
     fn parse_scaling_config_test() -> anyhow::Result<()> {
         let summary = Value::from_str(SUMMARY)?;
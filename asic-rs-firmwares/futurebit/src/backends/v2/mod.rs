@@ -2,8 +2,10 @@ use std::{collections::HashMap, net::IpAddr, str::FromStr, time::Duration};
 
 use asic_rs_core::{
     config::{
-        collector::{ConfigCollector, ConfigField, ConfigLocation},
-        pools::PoolGroupConfig,
+        collector::{ConfigCollector, ConfigExtractor, ConfigField, ConfigLocation},
+        pools::{PoolConfig, PoolGroupConfig},
+        scaling::ScalingConfig,
+        tuning::TuningConfig,
     },
     data::{
         board::{BoardData, MinerControlBoard},
@@ -12,6 +14,8 @@ use asic_rs_core::{
         device::{DeviceInfo, HashAlgorithm},
         fan::FanData,
         hashrate::{HashRate, HashRateUnit},
+        miner::{MiningMode, TuningTarget},
+        network::NetworkData,
         pool::{PoolData, PoolGroupData, PoolURL},
     },
     traits::{miner::*, model::MinerModel},
@@ -20,7 +24,7 @@ use asic_rs_makes_futurebit::hardware::FutureBitControlBoard;
 use async_trait::async_trait;
 use macaddr::MacAddr;
 use measurements::{AngularVelocity, Power, Temperature};
-use serde_json::Value;
+use serde_json::{Value, json};
 
 use crate::firmware::ApolloFirmware;
 
@@ -70,6 +74,25 @@ fn is_zero_mac(mac: &MacAddr) -> bool {
     }
 }
 
+/// Apollo `MinerMode` values for the generic mining modes. `custom` runs a
+/// user supplied frequency/voltage and has no generic equivalent.
+fn miner_mode_to_apollo(mode: MiningMode) -> &'static str {
+    match mode {
+        MiningMode::Low => "eco",
+        MiningMode::Normal => "balanced",
+        MiningMode::High => "turbo",
+    }
+}
+
+fn miner_mode_from_apollo(mode: &str) -> Option<MiningMode> {
+    match mode {
+        "eco" => Some(MiningMode::Low),
+        "balanced" => Some(MiningMode::Normal),
+        "turbo" => Some(MiningMode::High),
+        _ => None,
+    }
+}
+
 fn hash_rate_from_ghs(value: Option<f64>) -> Option<HashRate> {
     value.map(|f| {
         HashRate {
@@ -92,9 +115,60 @@ impl APIClient for ApolloV2 {
 }
 
 impl GetConfigsLocations for ApolloV2 {
-    #[allow(unused_variables)]
     fn get_configs_locations(&self, data_field: ConfigField) -> Vec<ConfigLocation> {
-        vec![]
+        const GQL_POOL_LIST: MinerCommand = MinerCommand::GraphQL {
+            command: r#"{
+                Pool {
+                    list {
+                        result {
+                            pools {
+                                enabled
+                                donation
+                                url
+                                username
+                                password
+                                index
+                            }
+                        }
+                        error { message }
+                    }
+                }
+            }"#,
+        };
+        const GQL_SETTINGS: MinerCommand = MinerCommand::GraphQL {
+            command: r#"{
+                Settings {
+                    read {
+                        result {
+                            settings {
+                                minerMode
+                            }
+                        }
+                        error { message }
+                    }
+                }
+            }"#,
+        };
+
+        match data_field {
+            ConfigField::Pools => vec![(
+                GQL_POOL_LIST,
+                ConfigExtractor {
+                    func: get_by_pointer,
+                    key: Some("/Pool/list/result/pools"),
+                    tag: None,
+                },
+            )],
+            ConfigField::Tuning => vec![(
+                GQL_SETTINGS,
+                ConfigExtractor {
+                    func: get_by_pointer,
+                    key: Some("/Settings/read/result/settings/minerMode"),
+                    tag: None,
+                },
+            )],
+            _ => vec![],
+        }
     }
 }
 
@@ -319,6 +393,14 @@ impl GetDataLocations for ApolloV2 {
                     tag: None,
                 },
             )],
+            DataField::Network => vec![(
+                GQL_MCU_STATS,
+                DataExtractor {
+                    func: get_by_pointer,
+                    key: Some("/Mcu/stats/result/stats/network"),
+                    tag: None,
+                },
+            )],
             DataField::Hostname => vec![(
                 GQL_MCU_STATS,
                 DataExtractor {
@@ -482,6 +564,36 @@ impl GetHostname for ApolloV2 {
     }
 }
 
+impl GetNetwork for ApolloV2 {
+    fn parse_network(&self, data: &HashMap<DataField, Value>) -> Option<NetworkData> {
+        // The MCU lists one entry per address; the physical interface is the
+        // one with a real MAC.
+        let networks = data.get(&DataField::Network)?.as_array()?;
+        let interface = networks
+            .iter()
+            .find(|network| {
+                network
+                    .get("mac")
+                    .and_then(Value::as_str)
+                    .and_then(|s| MacAddr::from_str(s).ok())
+                    .is_some_and(|mac| !is_zero_mac(&mac))
+            })?
+            .get("name")
+            .and_then(Value::as_str)?;
+
+        Some(NetworkData {
+            interface: Some(interface.to_string()),
+            ipv6_addresses: networks
+                .iter()
+                .filter(|network| network.get("name").and_then(Value::as_str) == Some(interface))
+                .filter_map(|network| network.get("address")?.as_str()?.parse().ok())
+                .filter(IpAddr::is_ipv6)
+                .collect(),
+            ..Default::default()
+        })
+    }
+}
+
 impl GetApiVersion for ApolloV2 {
     fn parse_api_version(&self, data: &HashMap<DataField, Value>) -> Option<String> {
         data.get(&DataField::ApiVersion)
@@ -513,6 +625,8 @@ impl GetControlBoardVersion for ApolloV2 {
     }
 }
 
+impl GetControlBoard for ApolloV2 {}
+
 impl GetHashboards for ApolloV2 {
     fn parse_hashboards(&self, data: &HashMap<DataField, Value>) -> Vec<BoardData> {
         let Some(stats) = data.get(&DataField::Hashboards) else {
@@ -589,6 +703,8 @@ impl GetFans for ApolloV2 {
 
 impl GetPsuFans for ApolloV2 {}
 
+impl GetPsus for ApolloV2 {}
+
 impl GetFluidTemperature for ApolloV2 {}
 
 impl GetWattage for ApolloV2 {
@@ -677,80 +793,151 @@ impl SetPowerLimit for ApolloV2 {
 
 #[async_trait]
 impl SupportsPoolsConfig for ApolloV2 {
-    async fn get_pools_config(&self) -> anyhow::Result<Vec<PoolGroupConfig>> {
-        Ok(self
-            .get_pools()
-            .await
+    fn parse_pools_config(
+        &self,
+        data: &HashMap<ConfigField, Value>,
+    ) -> anyhow::Result<Vec<PoolGroupConfig>> {
+        let pools = data
+            .get(&ConfigField::Pools)
+            .and_then(Value::as_array)
+            .ok_or_else(|| anyhow::anyhow!("No pool list in Apollo response"))?;
+
+        let mut pools: Vec<&Value> = pools
             .iter()
-            .map(|g| g.clone().into())
-            .collect())
+            .filter(|p| p.get("enabled").and_then(Value::as_bool).unwrap_or(true))
+            .filter(|p| as_u64(p.get("donation")).unwrap_or(0) == 0)
+            .collect();
+        pools.sort_by_key(|p| as_u64(p.get("index")).unwrap_or(u64::MAX));
+
+        let pools: Vec<PoolConfig> = pools
+            .into_iter()
+            .filter_map(|p| {
+                Some(PoolConfig {
+                    url: PoolURL::from(p.get("url")?.as_str()?.to_string()),
+                    username: p
+                        .get("username")
+                        .and_then(Value::as_str)
+                        .unwrap_or_default()
+                        .to_string(),
+                    password: p
+                        .get("password")
+                        .and_then(Value::as_str)
+                        .unwrap_or_default()
+                        .to_string(),
+                })
+            })
+            .collect();
+
+        if pools.is_empty() {
+            return Ok(vec![]);
+        }
+        Ok(vec![PoolGroupConfig {
+            name: String::from("default"),
+            quota: 1,
+            pools,
+        }])
+    }
+
+    async fn set_pools_config(&self, config: Vec<PoolGroupConfig>) -> anyhow::Result<bool> {
+        let [group] = config.as_slice() else {
+            anyhow::bail!(
+                "Apollo supports exactly one pool group, got {}",
+                config.len()
+            );
+        };
+        if group.pools.is_empty() {
+            anyhow::bail!("Apollo requires at least one pool");
+        }
+
+        let pools: Vec<Value> = group
+            .pools
+            .iter()
+            .enumerate()
+            .map(|(index, pool)| {
+                json!({
+                    "index": index,
+                    "enabled": true,
+                    "url": pool.url.to_string(),
+                    "username": pool.username,
+                    "password": pool.password,
+                })
+            })
+            .collect();
+
+        self.graphql
+            .send_action(
+                r#"query UpdatePools($input: PoolUpdateAllInput!) {
+                    Pool {
+                        updateAll(input: $input) {
+                            error { message }
+                        }
+                    }
+                }"#,
+                Some(json!({ "input": { "pools": pools } })),
+                "/Pool/updateAll",
+            )
+            .await?;
+
+        // The miner only reads its pool list on startup.
+        self.restart().await
     }
 
     fn supports_pools_config(&self) -> bool {
-        false
+        true
     }
 }
 
 #[async_trait]
 impl Restart for ApolloV2 {
     async fn restart(&self) -> anyhow::Result<bool> {
-        let data = self
-            .graphql
-            .send_graphql_command("mutation { Miner { restart { error { message } } } }", None)
+        self.graphql
+            .send_action(
+                "{ Miner { restart { error { message } } } }",
+                None,
+                "/Miner/restart",
+            )
             .await?;
-        if let Some(message) = data
-            .pointer("/Miner/restart/error/message")
-            .and_then(|v| v.as_str())
-        {
-            anyhow::bail!("Apollo restart failed: {message}");
-        }
         Ok(true)
     }
 
     fn supports_restart(&self) -> bool {
-        false
+        true
     }
 }
 
 #[async_trait]
 impl Pause for ApolloV2 {
     async fn pause(&self, _at_time: Option<Duration>) -> anyhow::Result<bool> {
-        let data = self
-            .graphql
-            .send_graphql_command("mutation { Miner { stop { error { message } } } }", None)
+        self.graphql
+            .send_action(
+                "{ Miner { stop { error { message } } } }",
+                None,
+                "/Miner/stop",
+            )
             .await?;
-        if let Some(message) = data
-            .pointer("/Miner/stop/error/message")
-            .and_then(|v| v.as_str())
-        {
-            anyhow::bail!("Apollo stop failed: {message}");
-        }
         Ok(true)
     }
 
     fn supports_pause(&self) -> bool {
-        false
+        true
     }
 }
 
 #[async_trait]
 impl Resume for ApolloV2 {
     async fn resume(&self, _at_time: Option<Duration>) -> anyhow::Result<bool> {
-        let data = self
-            .graphql
-            .send_graphql_command("mutation { Miner { start { error { message } } } }", None)
+        self.graphql
+            .send_action(
+                "{ Miner { start { error { message } } } }",
+                None,
+                "/Miner/start",
+            )
             .await?;
-        if let Some(message) = data
-            .pointer("/Miner/start/error/message")
-            .and_then(|v| v.as_str())
-        {
-            anyhow::bail!("Apollo start failed: {message}");
-        }
         Ok(true)
     }
 
     fn supports_resume(&self) -> bool {
-        false
+        true
     }
 }
 
@@ -760,12 +947,39 @@ impl ChangePassword for ApolloV2 {
     }
 }
 
+#[async_trait]
 impl ReadLogs for ApolloV2 {
+    async fn read_logs(&self) -> anyhow::Result<String> {
+        let output = self
+            .graphql
+            .send_action(
+                r#"query ReadLogs($input: LogReadInput!) {
+                    Logs {
+                        read(input: $input) {
+                            result { content }
+                            error { message }
+                        }
+                    }
+                }"#,
+                Some(json!({ "input": { "logType": "MINER", "lines": 1000 } })),
+                "/Logs/read",
+            )
+            .await?;
+
+        output
+            .pointer("/result/content")
+            .and_then(Value::as_str)
+            .map(ToString::to_string)
+            .ok_or_else(|| anyhow::anyhow!("Apollo returned no miner log content"))
+    }
+
     fn supports_read_logs(&self) -> bool {
-        false
+        true
     }
 }
 
+impl SetSyslogTarget for ApolloV2 {}
+
 impl FactoryReset for ApolloV2 {
     fn supports_factory_reset(&self) -> bool {
         false
@@ -780,7 +994,59 @@ impl SupportsScalingConfig for ApolloV2 {
 }
 
 #[async_trait]
-impl SupportsTuningConfig for ApolloV2 {}
+impl SupportsTuningConfig for ApolloV2 {
+    async fn set_tuning_config(
+        &self,
+        config: TuningConfig,
+        _scaling_config: Option<ScalingConfig>,
+    ) -> anyhow::Result<bool> {
+        config.ensure_target_only()?;
+        let mode = match config.target {
+            TuningTarget::MiningMode(mode) => miner_mode_to_apollo(mode),
+            TuningTarget::Power(_) => {
+                anyhow::bail!("Power tuning target is not supported on Apollo")
+            }
+            TuningTarget::HashRate(_) => {
+                anyhow::bail!("HashRate tuning target is not supported on Apollo")
+            }
+        };
+
+        self.graphql
+            .send_action(
+                r#"query UpdateSettings($input: SettingsUpdateInput!) {
+                    Settings {
+                        update(input: $input) {
+                            error { message }
+                        }
+                    }
+                }"#,
+                Some(json!({ "input": { "minerMode": mode } })),
+                "/Settings/update",
+            )
+            .await?;
+
+        // The power mode is applied when the miner process starts.
+        self.restart().await
+    }
+
+    fn parse_tuning_config(
+        &self,
+        data: &HashMap<ConfigField, Value>,
+    ) -> anyhow::Result<TuningConfig> {
+        let mode = data
+            .get(&ConfigField::Tuning)
+            .and_then(Value::as_str)
+            .ok_or_else(|| anyhow::anyhow!("No miner mode in Apollo settings"))?;
+
+        miner_mode_from_apollo(mode)
+            .map(|mode| TuningConfig::new(TuningTarget::MiningMode(mode)))
+            .ok_or_else(|| anyhow::anyhow!("Apollo miner mode '{mode}' has no generic equivalent"))
+    }
+
+    fn supports_tuning_config(&self) -> bool {
+        true
+    }
+}
 
 #[async_trait]
 impl SupportsFanConfig for ApolloV2 {}
@@ -807,6 +1073,9 @@ impl HasDefaultAuth for ApolloV2 {
 impl SupportsTemperatureConfig for ApolloV2 {}
 impl GetTuningPercent for ApolloV2 {}
 impl SetTuningPercent for ApolloV2 {}
+impl GetApplianceConfig for ApolloV2 {}
+impl SetApplianceConfig for ApolloV2 {}
+impl SupportsNetworkConfig for ApolloV2 {}
 
 #[cfg(test)]
 mod tests {
@@ -860,6 +1129,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_pools_config_skips_donation_and_disabled_pools() {
+        let miner = miner();
+        let mut data = HashMap::new();
+        data.insert(
+            ConfigField::Pools,
+            json!([
+                {"enabled": true, "donation": 0, "url": "stratum+tcp://backup.pool:3333", "username": "user.2", "password": "x", "index": 1},
+                {"enabled": true, "donation": 1, "url": "stratum+tcp://donate.pool:3333", "username": "dev", "password": "x", "index": 2},
+                {"enabled": false, "donation": 0, "url": "stratum+tcp://old.pool:3333", "username": "old", "password": "x", "index": 3},
+                {"enabled": true, "donation": null, "url": "stratum+tcp://main.pool:3333", "username": "user.1", "password": "x", "index": 0}
+            ]),
+        );
+
+        let groups = miner.parse_pools_config(&data).unwrap();
+
+        assert_eq!(groups.len(), 1);
+        let hosts: Vec<&str> = groups[0]
+            .pools
+            .iter()
+            .map(|p| p.url.host.as_str())
+            .collect();
+        assert_eq!(hosts, vec!["main.pool", "backup.pool"]);
+        assert_eq!(groups[0].pools[0].username, "user.1");
+    }
+
+    #[test]
+    fn parse_tuning_config_maps_miner_mode() {
+        let miner = miner();
+        let mut data = HashMap::new();
+
+        data.insert(ConfigField::Tuning, json!("turbo"));
+        let config = miner.parse_tuning_config(&data).unwrap();
+        assert_eq!(config.target_mode(), Some(MiningMode::High));
+
+        data.insert(ConfigField::Tuning, json!("custom"));
+        assert!(miner.parse_tuning_config(&data).is_err());
+    }
+
     #[test]
     fn parse_is_mining_accepts_boolean_status() {
         let miner = miner();
@@ -890,6 +1198,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn parse_network_uses_physical_interface() {
+        let miner = miner();
+        let mut data = HashMap::new();
+        data.insert(
+            DataField::Network,
+            json!([
+                {"name": "miner", "address": "10.10.10.1", "mac": "00:00:00:00:00:00"},
+                {"name": "eth0", "address": "192.168.1.20", "mac": "16:a1:04:59:2d:7d"},
+                {"name": "eth0", "address": "fe80::14a1:4ff:fe59:2d7d", "mac": "16:a1:04:59:2d:7d"}
+            ]),
+        );
+
+        let network = miner.parse_network(&data).unwrap();
+        assert_eq!(network.interface.as_deref(), Some("eth0"));
+        assert_eq!(
+            network.ipv6_addresses,
+            vec!["fe80::14a1:4ff:fe59:2d7d".parse::<IpAddr>().unwrap()]
+        );
+        assert_eq!(network.dhcp, None);
+    }
+
     #[test]
     fn parse_hostname_and_unknown_control_board() {
         let miner = miner();
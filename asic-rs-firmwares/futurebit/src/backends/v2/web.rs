@@ -161,6 +161,28 @@ impl ApolloGraphQLAPI {
             .ok_or_else(|| anyhow::anyhow!("Apollo GraphQL returned no data"))
     }
 
+    /// Run an Apollo action such as `Miner.restart` and return its output
+    /// object, failing if the action reports an error.
+    ///
+    /// `path` is the JSON pointer of the action output, e.g. `/Miner/restart`.
+    pub async fn send_action(
+        &self,
+        command: &str,
+        parameters: Option<Value>,
+        path: &str,
+    ) -> anyhow::Result<Value> {
+        let data = self.send_graphql_command(command, parameters).await?;
+        let output = data
+            .pointer(path)
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("Apollo GraphQL returned no output for {path}"))?;
+
+        if let Some(message) = output.pointer("/error/message").and_then(Value::as_str) {
+            anyhow::bail!("Apollo {path} failed: {message}");
+        }
+        Ok(output)
+    }
+
     pub async fn get_miner_stats(&self) -> anyhow::Result<Value> {
         let data = self
             .send_graphql_command(
@@ -4,20 +4,27 @@ use anyhow;
 use asic_rs_core::{
     config::{
         collector::{ConfigCollector, ConfigExtractor, ConfigField, ConfigLocation},
+        fan::FanConfig,
         pools::{PoolConfig, PoolGroupConfig},
+        scaling::ScalingConfig,
+        temperature::TemperatureConfig,
+        tuning::TuningConfig,
     },
     data::{
         board::{BoardData, ChipData, MinerControlBoard},
+        capabilities::{PresetTuningCapabilities, TuningCapabilities},
         collector::{
             DataCollector, DataExtensions, DataExtractor, DataField, DataLocation, get_by_pointer,
         },
         command::MinerCommand,
+        control_board::ControlBoardData,
         device::{DeviceInfo, HashAlgorithm},
         fan::FanData,
         hashrate::{HashRate, HashRateUnit},
         message::{MessageSeverity, MinerMessage},
         miner::TuningTarget,
         pool::{PoolData, PoolGroupData, PoolURL},
+        psu::PsuData,
     },
     traits::{miner::*, model::MinerModel},
 };
@@ -164,6 +171,90 @@ impl LuxMinerV1 {
         ordered_groups.sort_by_key(|(group_id, _)| *group_id);
         ordered_groups.into_iter().map(|(_, group)| group).collect()
     }
+
+    fn profile_name(profile: &Value) -> Option<&str> {
+        profile.get("Profile Name").and_then(Value::as_str)
+    }
+
+    fn profile_watts(profile: &Value) -> Option<f64> {
+        profile.get("Watts").and_then(Self::parse_f64)
+    }
+
+    /// Pick the profile with the highest power (or hashrate) that stays at or
+    /// below the target.
+    fn select_profile(profiles: &[Value], target: &TuningTarget) -> anyhow::Result<String> {
+        let (key, limit) = match target {
+            TuningTarget::Power(power) => ("Watts", power.as_watts()),
+            TuningTarget::HashRate(hashrate) => (
+                "Hashrate",
+                hashrate.clone().as_unit(HashRateUnit::TeraHash).value,
+            ),
+            TuningTarget::MiningMode(_) => {
+                anyhow::bail!("MiningMode tuning target is not supported on LuxOS")
+            }
+        };
+
+        profiles
+            .iter()
+            .filter_map(|profile| {
+                let value = profile.get(key).and_then(Self::parse_f64)?;
+                Some((Self::profile_name(profile)?, value))
+            })
+            .filter(|(_, value)| *value <= limit)
+            .max_by(|(_, a), (_, b)| a.total_cmp(b))
+            .map(|(name, _)| name.to_string())
+            .ok_or_else(|| anyhow::anyhow!("No LuxOS profile fits within {target:?}"))
+    }
+
+    /// Switch to a profile. With ATM enabled LuxOS rejects `profileset`, so the
+    /// profile becomes the ATM ceiling instead.
+    async fn apply_profile(&self, profile: &str) -> anyhow::Result<()> {
+        let atm_enabled = self
+            .rpc
+            .atm()
+            .await?
+            .pointer("/ATM/0/Enabled")
+            .and_then(Value::as_bool)
+            .unwrap_or(false);
+
+        if atm_enabled {
+            self.rpc
+                .atmset(None, None, None, None, None, Some(profile))
+                .await?;
+        } else {
+            self.rpc.profileset(profile).await?;
+        }
+        Ok(())
+    }
+
+    async fn apply_tuning_target(&self, target: &TuningTarget) -> anyhow::Result<()> {
+        let profiles = self.rpc.profiles().await?;
+        let profiles = profiles
+            .get("PROFILES")
+            .and_then(Value::as_array)
+            .ok_or_else(|| anyhow::anyhow!("No profiles in LuxOS response"))?;
+        let profile = Self::select_profile(profiles, target)?;
+        self.apply_profile(&profile).await
+    }
+
+    fn parse_tempctrl_thresholds(tempctrl: &Value) -> anyhow::Result<(f64, f64, f64)> {
+        let get = |key: &str| {
+            tempctrl
+                .get(key)
+                .and_then(Self::parse_f64)
+                .ok_or_else(|| anyhow::anyhow!("No {key} temperature in LuxOS tempctrl"))
+        };
+        Ok((get("Target")?, get("Hot")?, get("Panic")?))
+    }
+
+    async fn tempctrl(&self) -> anyhow::Result<Value> {
+        self.rpc
+            .tempctrl()
+            .await?
+            .pointer("/TEMPCTRL/0")
+            .cloned()
+            .ok_or_else(|| anyhow::anyhow!("No temperature control settings in LuxOS response"))
+    }
 }
 
 #[async_trait]
@@ -188,7 +279,71 @@ impl GetConfigsLocations for LuxMinerV1 {
             parameters: None,
         };
 
+        const RPC_CONFIG: MinerCommand = MinerCommand::RPC {
+            command: "config",
+            parameters: None,
+        };
+
+        const RPC_PROFILES: MinerCommand = MinerCommand::RPC {
+            command: "profiles",
+            parameters: None,
+        };
+
+        const RPC_TEMPCTRL: MinerCommand = MinerCommand::RPC {
+            command: "tempctrl",
+            parameters: None,
+        };
+
+        const RPC_FANS: MinerCommand = MinerCommand::RPC {
+            command: "fans",
+            parameters: None,
+        };
+
         match data_field {
+            ConfigField::Tuning => vec![
+                (
+                    RPC_CONFIG,
+                    ConfigExtractor {
+                        func: get_by_pointer,
+                        key: Some("/CONFIG/0/Profile"),
+                        tag: Some("Profile"),
+                    },
+                ),
+                (
+                    RPC_PROFILES,
+                    ConfigExtractor {
+                        func: get_by_pointer,
+                        key: Some("/PROFILES"),
+                        tag: Some("Profiles"),
+                    },
+                ),
+            ],
+            ConfigField::Fan => vec![
+                (
+                    RPC_TEMPCTRL,
+                    ConfigExtractor {
+                        func: get_by_pointer,
+                        key: Some("/TEMPCTRL/0"),
+                        tag: Some("tempctrl"),
+                    },
+                ),
+                (
+                    RPC_FANS,
+                    ConfigExtractor {
+                        func: get_by_pointer,
+                        key: Some("/FANS"),
+                        tag: Some("fans"),
+                    },
+                ),
+            ],
+            ConfigField::Temperature => vec![(
+                RPC_TEMPCTRL,
+                ConfigExtractor {
+                    func: get_by_pointer,
+                    key: Some("/TEMPCTRL/0"),
+                    tag: None,
+                },
+            )],
             ConfigField::Pools => vec![
                 (
                     RPC_GROUPS,
@@ -316,6 +471,24 @@ impl GetDataLocations for LuxMinerV1 {
                     tag: None,
                 },
             )],
+            DataField::ControlBoard => vec![
+                (
+                    RPC_CONFIG,
+                    DataExtractor {
+                        func: get_by_pointer,
+                        key: Some("/CONFIG/0/OS"),
+                        tag: Some("name"),
+                    },
+                ),
+                (
+                    RPC_VERSION,
+                    DataExtractor {
+                        func: get_by_pointer,
+                        key: Some("/VERSION/0/LUXminer"),
+                        tag: Some("version"),
+                    },
+                ),
+            ],
             DataField::Hashboards => vec![
                 (
                     RPC_STATS,
@@ -469,6 +642,14 @@ impl GetDataLocations for LuxMinerV1 {
                     tag: None,
                 },
             )],
+            DataField::TuningCapabilities => vec![(
+                RPC_PROFILES,
+                DataExtractor {
+                    func: get_by_pointer,
+                    key: Some("/PROFILES"),
+                    tag: None,
+                },
+            )],
             DataField::TuningTarget => vec![
                 (
                     RPC_CONFIG,
@@ -495,6 +676,14 @@ impl GetDataLocations for LuxMinerV1 {
                     tag: None,
                 },
             )],
+            DataField::Psus => vec![(
+                RPC_CONFIG,
+                DataExtractor {
+                    func: get_by_pointer,
+                    key: Some("/CONFIG/0/PSULabel"),
+                    tag: None,
+                },
+            )],
             DataField::Messages => vec![(
                 RPC_EVENTS,
                 DataExtractor {
@@ -571,6 +760,8 @@ impl GetHostname for LuxMinerV1 {
     }
 }
 
+impl GetNetwork for LuxMinerV1 {}
+
 impl GetApiVersion for LuxMinerV1 {
     fn parse_api_version(&self, data: &HashMap<DataField, Value>) -> Option<String> {
         data.extract::<String>(DataField::ApiVersion)
@@ -961,6 +1152,23 @@ impl GetControlBoardVersion for LuxMinerV1 {
     }
 }
 
+impl GetControlBoard for LuxMinerV1 {
+    /// LuxOS reports the OS name without a version; the OS is released
+    /// together with LUXminer, so its build identifies the OS version.
+    fn parse_control_board(&self, data: &HashMap<DataField, Value>) -> Option<ControlBoardData> {
+        let os = data.get(&DataField::ControlBoard)?;
+        let version = os.get("version").and_then(Value::as_str)?;
+        let os_version = match os.get("name").and_then(Value::as_str) {
+            Some(name) => format!("{name} {version}"),
+            None => version.to_string(),
+        };
+        Some(ControlBoardData {
+            os_version: Some(os_version),
+            ..Default::default()
+        })
+    }
+}
+
 impl GetWattage for LuxMinerV1 {
     fn parse_wattage(&self, data: &HashMap<DataField, Value>) -> Option<Power> {
         data.extract_map::<f64, _>(DataField::Wattage, Power::from_watts)
@@ -984,9 +1192,52 @@ impl GetTuningTarget for LuxMinerV1 {
 }
 
 impl GetScaledTuningTarget for LuxMinerV1 {}
-impl GetTuningCapabilities for LuxMinerV1 {}
 impl GetPsuFans for LuxMinerV1 {}
 
+impl GetPsus for LuxMinerV1 {
+    fn parse_psus(&self, data: &HashMap<DataField, Value>) -> Vec<PsuData> {
+        data.extract::<String>(DataField::Psus)
+            .filter(|label| !label.is_empty())
+            .map(|label| PsuData {
+                model: Some(label),
+                ..PsuData::new(0)
+            })
+            .into_iter()
+            .collect()
+    }
+}
+
+impl GetTuningCapabilities for LuxMinerV1 {
+    fn parse_tuning_capabilities(
+        &self,
+        data: &HashMap<DataField, Value>,
+    ) -> Option<TuningCapabilities> {
+        let profiles = data.get(&DataField::TuningCapabilities)?.as_array()?;
+
+        let mut presets: Vec<(f64, &Value)> = profiles
+            .iter()
+            .filter_map(|profile| Some((Self::profile_watts(profile)?, profile)))
+            .collect();
+        presets.sort_by(|(a, _), (b, _)| a.total_cmp(b));
+
+        let default = presets
+            .iter()
+            .find(|(_, profile)| Self::profile_name(profile) == Some("default"))
+            .map(|(watts, _)| TuningTarget::from_watts(*watts));
+
+        Some(TuningCapabilities {
+            presets: Some(PresetTuningCapabilities {
+                default,
+                presets: presets
+                    .into_iter()
+                    .map(|(watts, _)| TuningTarget::from_watts(watts))
+                    .collect(),
+            }),
+            ..Default::default()
+        })
+    }
+}
+
 impl GetMessages for LuxMinerV1 {
     fn parse_messages(&self, data: &HashMap<DataField, Value>) -> Vec<MinerMessage> {
         data.get(&DataField::Messages)
@@ -1054,8 +1305,13 @@ impl SetFaultLight for LuxMinerV1 {
 
 #[async_trait]
 impl SetPowerLimit for LuxMinerV1 {
+    async fn set_power_limit(&self, limit: Power) -> anyhow::Result<bool> {
+        self.apply_tuning_target(&TuningTarget::Power(limit))
+            .await?;
+        Ok(true)
+    }
     fn supports_set_power_limit(&self) -> bool {
-        false
+        true
     }
 }
 
@@ -1154,18 +1410,48 @@ impl Resume for LuxMinerV1 {
     }
 }
 
+#[async_trait]
 impl ChangePassword for LuxMinerV1 {
+    async fn change_password(&mut self, password: &str) -> anyhow::Result<bool> {
+        self.rpc.passwordset(password).await?;
+        Ok(true)
+    }
+
     fn supports_change_password(&self) -> bool {
-        false
+        true
     }
 }
 
+#[async_trait]
 impl ReadLogs for LuxMinerV1 {
+    async fn read_logs(&self) -> anyhow::Result<String> {
+        let logs = self.rpc.logs().await?;
+        let lines = logs
+            .get("LOGS")
+            .and_then(Value::as_array)
+            .ok_or_else(|| anyhow::anyhow!("No logs in LuxOS response"))?;
+
+        let mut text = String::new();
+        for line in lines {
+            let line = line
+                .as_str()
+                .or_else(|| line.get("Line").and_then(Value::as_str))
+                .or_else(|| line.get("Log").and_then(Value::as_str));
+            if let Some(line) = line {
+                text.push_str(line);
+                text.push('\n');
+            }
+        }
+        Ok(text)
+    }
+
     fn supports_read_logs(&self) -> bool {
-        false
+        true
     }
 }
 
+impl SetSyslogTarget for LuxMinerV1 {}
+
 impl FactoryReset for LuxMinerV1 {
     fn supports_factory_reset(&self) -> bool {
         false
@@ -1191,21 +1477,143 @@ impl HasDefaultAuth for LuxMinerV1 {}
 
 #[async_trait]
 impl SupportsTuningConfig for LuxMinerV1 {
+    /// A config algorithm names the LuxOS profile to run; otherwise the
+    /// closest profile at or below the target is used.
+    async fn set_tuning_config(
+        &self,
+        config: TuningConfig,
+        _scaling_config: Option<ScalingConfig>,
+    ) -> anyhow::Result<bool> {
+        config.ensure_target_only()?;
+        match config.algorithm() {
+            Some(profile) => self.apply_profile(profile).await?,
+            None => self.apply_tuning_target(&config.target).await?,
+        }
+        Ok(true)
+    }
+
+    fn parse_tuning_config(
+        &self,
+        data: &HashMap<ConfigField, Value>,
+    ) -> anyhow::Result<TuningConfig> {
+        let tuning = data
+            .get(&ConfigField::Tuning)
+            .ok_or_else(|| anyhow::anyhow!("No profile settings in LuxOS config"))?;
+        let name = tuning
+            .get("Profile")
+            .and_then(Value::as_str)
+            .ok_or_else(|| anyhow::anyhow!("No active profile in LuxOS config"))?;
+        let watts = tuning
+            .get("Profiles")
+            .and_then(Value::as_array)
+            .and_then(|profiles| {
+                profiles
+                    .iter()
+                    .find(|profile| Self::profile_name(profile) == Some(name))
+            })
+            .and_then(Self::profile_watts)
+            .ok_or_else(|| anyhow::anyhow!("Unknown LuxOS profile {name}"))?;
+
+        Ok(TuningConfig::new(TuningTarget::from_watts(watts)).with_algorithm(name))
+    }
+
     fn supports_tuning_config(&self) -> bool {
-        false
+        true
     }
 }
 
 #[async_trait]
 impl SupportsFanConfig for LuxMinerV1 {
+    /// LuxOS fans follow `tempctrl` unless a fixed speed is set; a speed of
+    /// `-1` hands control back to it. The auto idle speed is not configurable.
+    async fn set_fan_config(&self, config: FanConfig) -> anyhow::Result<bool> {
+        config.ensure_no_min_fans()?;
+        match config {
+            FanConfig::Auto { target_temp, .. } => {
+                let (current, hot, panic) =
+                    Self::parse_tempctrl_thresholds(&self.tempctrl().await?)?;
+                self.rpc
+                    .tempctrlset(target_temp.unwrap_or(current), hot, panic)
+                    .await?;
+                self.rpc.fanset(Some(-1), None).await?;
+            }
+            FanConfig::Manual { fan_speed, .. } => {
+                if fan_speed > 100 {
+                    anyhow::bail!("Fan speed must be a percentage, got {fan_speed}");
+                }
+                self.rpc.fanset(Some(fan_speed as i32), None).await?;
+            }
+        }
+        Ok(true)
+    }
+
+    fn parse_fan_config(&self, data: &HashMap<ConfigField, Value>) -> anyhow::Result<FanConfig> {
+        let fan = data
+            .get(&ConfigField::Fan)
+            .ok_or_else(|| anyhow::anyhow!("No fan settings in LuxOS response"))?;
+        let tempctrl = fan
+            .get("tempctrl")
+            .ok_or_else(|| anyhow::anyhow!("No temperature control settings in LuxOS response"))?;
+
+        match tempctrl.get("Mode").and_then(Value::as_str) {
+            Some("Manual") => fan
+                .pointer("/fans/0/Speed")
+                .and_then(Value::as_u64)
+                .map(FanConfig::manual)
+                .ok_or_else(|| anyhow::anyhow!("No fan speed in LuxOS response")),
+            _ => {
+                let (target, _, _) = Self::parse_tempctrl_thresholds(tempctrl)?;
+                Ok(FanConfig::auto(target, None))
+            }
+        }
+    }
+
     fn supports_fan_config(&self) -> bool {
-        false
+        true
     }
 }
 
-impl SupportsTemperatureConfig for LuxMinerV1 {}
+#[async_trait]
+impl SupportsTemperatureConfig for LuxMinerV1 {
+    async fn set_temperature_config(&self, config: TemperatureConfig) -> anyhow::Result<bool> {
+        if config.minimum.is_some() {
+            anyhow::bail!("LuxOS does not support a minimum temperature");
+        }
+        let (target, hot, panic) = Self::parse_tempctrl_thresholds(&self.tempctrl().await?)?;
+        self.rpc
+            .tempctrlset(
+                target,
+                config.hot.unwrap_or(hot),
+                config.danger.unwrap_or(panic),
+            )
+            .await?;
+        Ok(true)
+    }
+
+    fn parse_temperature_config(
+        &self,
+        data: &HashMap<ConfigField, Value>,
+    ) -> anyhow::Result<TemperatureConfig> {
+        let tempctrl = data
+            .get(&ConfigField::Temperature)
+            .ok_or_else(|| anyhow::anyhow!("No temperature control settings in LuxOS response"))?;
+        let (_, hot, panic) = Self::parse_tempctrl_thresholds(tempctrl)?;
+        Ok(TemperatureConfig {
+            hot: Some(hot),
+            danger: Some(panic),
+            minimum: None,
+        })
+    }
+
+    fn supports_temperature_config(&self) -> bool {
+        true
+    }
+}
 impl GetTuningPercent for LuxMinerV1 {}
 impl SetTuningPercent for LuxMinerV1 {}
+impl GetApplianceConfig for LuxMinerV1 {}
+impl SetApplianceConfig for LuxMinerV1 {}
+impl SupportsNetworkConfig for LuxMinerV1 {}
 #[cfg(test)]
 mod tests {
     use std::sync::{Arc, Mutex};
@@ -1216,7 +1624,7 @@ mod tests {
         net::TcpListener,
     };
 
-    use asic_rs_core::test::api::MockAPIClient;
+    use asic_rs_core::test::{api::MockAPIClient, rpc::mock_rpc};
     use asic_rs_makes_antminer::models::AntMinerModel;
 
     use super::*;
@@ -1400,6 +1808,15 @@ mod tests {
             Some(TuningTarget::Power(Power::from_watts(1188f64)))
         );
         assert_eq!(miner_data.fans.len(), 4);
+        assert_eq!(miner_data.psus.len(), 1);
+        assert_eq!(
+            miner_data
+                .control_board
+                .as_ref()
+                .and_then(|cb| cb.os_version.as_deref()),
+            Some("LuxOS 2025.4.8.220305-57b389c7")
+        );
+        assert_eq!(miner_data.psus[0].model.as_deref(), Some("APW121215e"));
         assert_eq!(miner_data.hashboards[0].chips.len(), 77);
         assert_eq!(miner_data.pools.len(), 2);
         assert_eq!(miner_data.pools[0].len(), 2);
@@ -1592,4 +2009,143 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_luxminer_v1_profiles() -> anyhow::Result<()> {
+        let miner = LuxMinerV1::new(IpAddr::from([127, 0, 0, 1]), AntMinerModel::S19KPro);
+        let profiles = Value::from_str(PROFILES)?["PROFILES"].clone();
+
+        let capabilities = miner
+            .parse_tuning_capabilities(&HashMap::from([(
+                DataField::TuningCapabilities,
+                profiles.clone(),
+            )]))
+            .and_then(|c| c.presets)
+            .unwrap();
+        assert_eq!(
+            capabilities.presets.first(),
+            Some(&TuningTarget::from_watts(784.0))
+        );
+        assert!(capabilities.default.is_some());
+
+        let tuning = miner.parse_tuning_config(&HashMap::from([(
+            ConfigField::Tuning,
+            json!({ "Profile": "290MHz", "Profiles": profiles.clone() }),
+        )]))?;
+        assert_eq!(tuning.algorithm(), Some("290MHz"));
+
+        let profiles = profiles.as_array().unwrap();
+        assert_eq!(
+            LuxMinerV1::select_profile(profiles, &TuningTarget::from_watts(1000.0))?,
+            "240MHz"
+        );
+        assert!(LuxMinerV1::select_profile(profiles, &TuningTarget::from_watts(100.0)).is_err());
+
+        Ok(())
+    }
+
+    fn session_response() -> Value {
+        json!({
+            "SESSION": [{ "SessionID": "abc123" }],
+            "STATUS": [{ "STATUS": "S", "Msg": "ok" }]
+        })
+    }
+
+    #[tokio::test]
+    async fn test_change_password_sends_session_and_password() -> anyhow::Result<()> {
+        let (port, requests, server) = mock_rpc(2, |command| {
+            Some(match command {
+                "session" => session_response(),
+                _ => json!({ "STATUS": [{ "STATUS": "S", "Msg": "Password changed" }] }),
+            })
+        })
+        .await?;
+
+        let mut miner = LuxMinerV1::new(IpAddr::from([127, 0, 0, 1]), AntMinerModel::S19KPro);
+        miner.rpc = LUXMinerRPCAPI::new(IpAddr::from([127, 0, 0, 1])).with_port(port);
+        assert!(miner.change_password("hunter2").await?);
+        server.await??;
+
+        let requests = requests.lock().unwrap();
+        assert_eq!(requests[1]["command"], "passwordset");
+        assert_eq!(requests[1]["parameter"], "abc123,hunter2");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_change_password_propagates_rejection() -> anyhow::Result<()> {
+        let (port, _, server) = mock_rpc(2, |command| {
+            Some(match command {
+                "session" => session_response(),
+                _ => json!({ "STATUS": [{ "STATUS": "E", "Msg": "Password too short" }] }),
+            })
+        })
+        .await?;
+
+        let mut miner = LuxMinerV1::new(IpAddr::from([127, 0, 0, 1]), AntMinerModel::S19KPro);
+        miner.rpc = LUXMinerRPCAPI::new(IpAddr::from([127, 0, 0, 1])).with_port(port);
+        let err = miner.change_password("x").await.unwrap_err();
+        server.await??;
+
+        assert!(err.to_string().contains("Password too short"));
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_tuning_rejects_chip_set_points() -> anyhow::Result<()> {
+        let (port, requests, _server) = mock_rpc(1, |_| None).await?;
+
+        let mut miner = LuxMinerV1::new(IpAddr::from([127, 0, 0, 1]), AntMinerModel::S19KPro);
+        miner.rpc = LUXMinerRPCAPI::new(IpAddr::from([127, 0, 0, 1])).with_port(port);
+        let config = TuningConfig::new(TuningTarget::Power(Power::from_watts(3000.0)))
+            .with_voltage(Voltage::from_millivolts(1300.0));
+        assert!(miner.set_tuning_config(config, None).await.is_err());
+
+        assert!(requests.lock().unwrap().is_empty());
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn test_read_logs_joins_lines() -> anyhow::Result<()> {
+        let (port, _, server) = mock_rpc(2, |command| {
+            Some(match command {
+                "session" => session_response(),
+                _ => json!({
+                    "LOGS": ["first line", { "Line": "second line" }],
+                    "STATUS": [{ "STATUS": "S", "Msg": "ok" }]
+                }),
+            })
+        })
+        .await?;
+
+        let mut miner = LuxMinerV1::new(IpAddr::from([127, 0, 0, 1]), AntMinerModel::S19KPro);
+        miner.rpc = LUXMinerRPCAPI::new(IpAddr::from([127, 0, 0, 1])).with_port(port);
+        assert_eq!(miner.read_logs().await?, "first line\nsecond line\n");
+        server.await??;
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_fan_and_temperature_config() -> anyhow::Result<()> {
+        let miner = LuxMinerV1::new(IpAddr::from([127, 0, 0, 1]), AntMinerModel::S19KPro);
+        let tempctrl = json!({ "Mode": "Auto", "Target": 70.0, "Hot": 80.0, "Panic": 90.0 });
+
+        let fan = miner.parse_fan_config(&HashMap::from([(
+            ConfigField::Fan,
+            json!({ "tempctrl": tempctrl.clone(), "fans": [{ "Speed": 60 }] }),
+        )]))?;
+        assert_eq!(fan, FanConfig::auto(70.0, None));
+
+        let manual = miner.parse_fan_config(&HashMap::from([(
+            ConfigField::Fan,
+            json!({ "tempctrl": { "Mode": "Manual" }, "fans": [{ "Speed": 60 }] }),
+        )]))?;
+        assert_eq!(manual, FanConfig::manual(60));
+
+        let temperature = miner
+            .parse_temperature_config(&HashMap::from([(ConfigField::Temperature, tempctrl)]))?;
+        assert_eq!(temperature.hot, Some(80.0));
+        assert_eq!(temperature.danger, Some(90.0));
+        Ok(())
+    }
 }
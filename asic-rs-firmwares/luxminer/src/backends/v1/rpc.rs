@@ -27,6 +27,11 @@ impl LUXMinerRPCAPI {
         }
     }
 
+    pub fn with_port(mut self, port: u16) -> Self {
+        self.port = port;
+        self
+    }
+
     fn parse_rpc_result(&self, response: &str) -> anyhow::Result<Value> {
         let status = RPCCommandStatus::from_luxminer(response)?;
         match status.into_result() {
@@ -190,6 +195,28 @@ impl LUXMinerRPCAPI {
             .await
     }
 
+    pub async fn tempctrlset(&self, target: f64, hot: f64, panic: f64) -> anyhow::Result<Value> {
+        self.send_command(
+            "tempctrlset",
+            true,
+            Some(Value::String(format!("{target},{hot},{panic}"))),
+        )
+        .await
+    }
+
+    pub async fn passwordset(&self, password: &str) -> anyhow::Result<Value> {
+        self.send_command(
+            "passwordset",
+            true,
+            Some(Value::String(password.to_string())),
+        )
+        .await
+    }
+
+    pub async fn logs(&self) -> anyhow::Result<Value> {
+        self.send_command("logs", true, None).await
+    }
+
     // ATM (Advanced Thermal Management) commands
     pub async fn atm(&self) -> anyhow::Result<Value> {
         self.send_command("atm", false, None).await
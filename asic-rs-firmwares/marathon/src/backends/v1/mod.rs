@@ -4,7 +4,11 @@ use anyhow;
 use asic_rs_core::{
     config::{
         collector::{ConfigCollector, ConfigExtractor, ConfigField, ConfigLocation},
+        fan::FanConfig,
+        network::NetworkConfig,
         pools::{PoolConfig, PoolGroupConfig},
+        scaling::ScalingConfig,
+        tuning::TuningConfig,
     },
     data::{
         board::{BoardData, ChipData, MinerControlBoard},
@@ -16,7 +20,8 @@ use asic_rs_core::{
         fan::FanData,
         hashrate::{HashRate, HashRateUnit},
         message::{MessageSeverity, MinerMessage},
-        miner::TuningTarget,
+        miner::{MiningMode, TuningTarget},
+        network::NetworkData,
         pool::{PoolData, PoolGroupData, PoolURL},
     },
     traits::{miner::*, model::MinerModel},
@@ -34,6 +39,10 @@ use crate::firmware::MarathonFirmware;
 
 mod web;
 
+/// `advance-config` key holding the chip temperature the automatic fan curve
+/// regulates towards, as read and written by pyasic's MaraFW support.
+const CHIP_TARGET_TEMP: &str = "chip-target-temp";
+
 #[derive(Debug)]
 pub struct MaraV1 {
     ip: IpAddr,
@@ -241,6 +250,145 @@ impl MaraV1 {
         Ok((pool_groups, pools))
     }
 
+    /// Point the `mode` section of a miner config at a tuning target. Power and
+    /// hashrate targets run the Concorde autotuner (`Auto`), the normal mining
+    /// mode runs stock settings. A paused (`Sleep`) miner is rejected, since
+    /// switching its work mode would resume mining.
+    fn apply_tuning_target(config: &mut Value, target: &TuningTarget) -> anyhow::Result<()> {
+        let mode = &mut config["mode"];
+        if mode.get("work-mode-selector").and_then(Value::as_str)
+            == Some(&MaraWorkMode::Sleep.to_string())
+        {
+            anyhow::bail!("MaraFW miner is paused, resume it before changing the tuning target");
+        }
+        match target {
+            TuningTarget::Power(power) => {
+                mode["work-mode-selector"] = json!(MaraWorkMode::Auto.to_string());
+                mode["concorde"]["mode-select"] = json!("PowerTarget");
+                mode["concorde"]["power-target"] = json!(power.as_watts().round() as u64);
+            }
+            TuningTarget::HashRate(hashrate) => {
+                mode["work-mode-selector"] = json!(MaraWorkMode::Auto.to_string());
+                mode["concorde"]["mode-select"] = json!("Hashrate");
+                mode["concorde"]["hash-target"] =
+                    json!(hashrate.clone().as_unit(HashRateUnit::TeraHash).value);
+            }
+            TuningTarget::MiningMode(MiningMode::Normal) => {
+                mode["work-mode-selector"] = json!(MaraWorkMode::Stock.to_string());
+            }
+            TuningTarget::MiningMode(other) => {
+                anyhow::bail!("MaraFW does not support the {other} mining mode")
+            }
+        }
+        Ok(())
+    }
+
+    fn parse_tuning_mode(mode: &Value) -> anyhow::Result<TuningConfig> {
+        let work_mode = mode
+            .get("work-mode-selector")
+            .and_then(Value::as_str)
+            .ok_or_else(|| anyhow::anyhow!("MaraFW miner_config missing work-mode-selector"))?
+            .parse::<MaraWorkMode>()?;
+        let concorde = mode.get("concorde");
+
+        let target = match work_mode {
+            MaraWorkMode::Stock => TuningTarget::MiningMode(MiningMode::Normal),
+            _ if concorde
+                .and_then(|c| c.get("mode-select"))
+                .and_then(Value::as_str)
+                == Some("Hashrate") =>
+            {
+                let hashrate = concorde
+                    .and_then(|c| c.get("hash-target"))
+                    .and_then(Value::as_f64)
+                    .ok_or_else(|| anyhow::anyhow!("MaraFW miner_config missing hash-target"))?;
+                TuningTarget::HashRate(HashRate {
+                    value: hashrate,
+                    unit: HashRateUnit::TeraHash,
+                    algo: String::from("SHA256"),
+                })
+            }
+            _ => concorde
+                .and_then(|c| c.get("power-target"))
+                .and_then(Value::as_f64)
+                .map(TuningTarget::from_watts)
+                .ok_or_else(|| anyhow::anyhow!("MaraFW miner_config missing power-target"))?,
+        };
+
+        Ok(TuningConfig::new(target).with_algorithm(work_mode.to_string()))
+    }
+
+    fn apply_fan_config(config: &mut Value, fan: &FanConfig) -> anyhow::Result<()> {
+        let advanced = &mut config["advance-config"];
+        match fan {
+            FanConfig::Auto { target_temp, .. } => {
+                advanced["override-fan-control"] = json!(false);
+                if let Some(target_temp) = target_temp {
+                    advanced[CHIP_TARGET_TEMP] = json!(target_temp.round().max(0.0) as u64);
+                }
+            }
+            FanConfig::Manual { fan_speed, .. } => {
+                if *fan_speed > 100 {
+                    anyhow::bail!("Fan speed must be a percentage, got {fan_speed}");
+                }
+                advanced["override-fan-control"] = json!(true);
+                advanced["fan-fixed-percent"] = json!(fan_speed);
+            }
+        }
+        Ok(())
+    }
+
+    fn parse_network_config_value(network: &Value) -> anyhow::Result<NetworkConfig> {
+        let dhcp = network
+            .get("dhcp")
+            .and_then(Value::as_bool)
+            .ok_or_else(|| anyhow::anyhow!("MaraFW network_config missing dhcp"))?;
+        let addr = |key: &str| network.get(key)?.as_str()?.trim().parse().ok();
+
+        Ok(NetworkConfig {
+            dhcp,
+            ip: addr("ipaddress"),
+            netmask: addr("netmask"),
+            gateway: addr("gateway"),
+            dns: network
+                .get("dns")
+                .and_then(Value::as_str)
+                .map(|dns| {
+                    dns.split([',', ' '])
+                        .filter_map(|server| server.trim().parse().ok())
+                        .collect::<Vec<_>>()
+                })
+                .filter(|dns| !dns.is_empty()),
+            hostname: network
+                .get("hostname")
+                .and_then(Value::as_str)
+                .map(String::from),
+        })
+    }
+
+    fn write_network_config(network: &mut Value, config: &NetworkConfig) {
+        let addr = |addr: Option<IpAddr>| addr.map(|a| a.to_string()).unwrap_or_default();
+
+        network["dhcp"] = json!(config.dhcp);
+        if !config.dhcp {
+            network["ipaddress"] = json!(addr(config.ip));
+            network["netmask"] = json!(addr(config.netmask));
+            network["gateway"] = json!(addr(config.gateway));
+            network["dns"] = json!(
+                config
+                    .dns
+                    .iter()
+                    .flatten()
+                    .map(ToString::to_string)
+                    .collect::<Vec<_>>()
+                    .join(",")
+            );
+        }
+        if let Some(hostname) = &config.hostname {
+            network["hostname"] = json!(hostname);
+        }
+    }
+
     async fn get_miner_config(&self) -> anyhow::Result<Value> {
         self.web
             .send_command("miner_config", true, None, Method::GET)
@@ -383,17 +531,33 @@ impl GetConfigsLocations for MaraV1 {
             parameters: None,
         };
 
-        match data_field {
-            ConfigField::Pools => vec![(
-                WEB_MINER_CONFIG,
-                ConfigExtractor {
-                    func: get_by_pointer,
-                    key: Some(""),
-                    tag: None,
-                },
-            )],
-            _ => vec![],
-        }
+        let key = match data_field {
+            ConfigField::Network => {
+                return vec![(
+                    MinerCommand::WebAPI {
+                        command: "network_config",
+                        parameters: None,
+                    },
+                    ConfigExtractor {
+                        func: get_by_pointer,
+                        key: Some(""),
+                        tag: None,
+                    },
+                )];
+            }
+            ConfigField::Pools => "",
+            ConfigField::Tuning => "/mode",
+            ConfigField::Fan => "/advance-config",
+            _ => return vec![],
+        };
+        vec![(
+            WEB_MINER_CONFIG,
+            ConfigExtractor {
+                func: get_by_pointer,
+                key: Some(key),
+                tag: None,
+            },
+        )]
     }
 }
 
@@ -479,6 +643,14 @@ impl GetDataLocations for MaraV1 {
                     tag: None,
                 },
             )],
+            DataField::Network => vec![(
+                WEB_NETWORK_CONFIG,
+                DataExtractor {
+                    func: get_by_pointer,
+                    key: Some(""),
+                    tag: None,
+                },
+            )],
             DataField::Hashrate => vec![(
                 WEB_BRIEF,
                 DataExtractor {
@@ -623,6 +795,14 @@ impl GetHostname for MaraV1 {
     }
 }
 
+impl GetNetwork for MaraV1 {
+    fn parse_network(&self, data: &HashMap<DataField, Value>) -> Option<NetworkData> {
+        data.get(&DataField::Network)
+            .and_then(|network| Self::parse_network_config_value(network).ok())
+            .map(NetworkData::from)
+    }
+}
+
 impl GetApiVersion for MaraV1 {}
 
 impl GetFirmwareVersion for MaraV1 {
@@ -650,6 +830,8 @@ impl GetControlBoardVersion for MaraV1 {
     }
 }
 
+impl GetControlBoard for MaraV1 {}
+
 impl MaraV1 {
     fn parse_chip_data(asic_infos: &Value) -> Vec<ChipData> {
         asic_infos
@@ -867,6 +1049,8 @@ impl GetFans for MaraV1 {
 
 impl GetPsuFans for MaraV1 {}
 
+impl GetPsus for MaraV1 {}
+
 impl GetFluidTemperature for MaraV1 {}
 
 impl GetWattage for MaraV1 {
@@ -1015,15 +1199,31 @@ impl GetPools for MaraV1 {
 
 #[async_trait]
 impl SetFaultLight for MaraV1 {
+    async fn set_fault_light(&self, fault: bool) -> anyhow::Result<bool> {
+        self.web
+            .send_command(
+                "locate_miner",
+                true,
+                Some(json!({ "blinking": fault })),
+                Method::POST,
+            )
+            .await?;
+        Ok(true)
+    }
     fn supports_set_fault_light(&self) -> bool {
-        false
+        true
     }
 }
 
 #[async_trait]
 impl SetPowerLimit for MaraV1 {
+    async fn set_power_limit(&self, limit: Power) -> anyhow::Result<bool> {
+        let mut config = self.get_miner_config().await?;
+        Self::apply_tuning_target(&mut config, &TuningTarget::Power(limit))?;
+        self.set_miner_config(config).await
+    }
     fn supports_set_power_limit(&self) -> bool {
-        false
+        true
     }
 }
 
@@ -1061,8 +1261,19 @@ impl SupportsPoolsConfig for MaraV1 {
 
 #[async_trait]
 impl Restart for MaraV1 {
+    async fn restart(&self) -> anyhow::Result<bool> {
+        self.web
+            .send_command(
+                "maintenance",
+                true,
+                Some(json!({ "type": "reboot" })),
+                Method::POST,
+            )
+            .await?;
+        Ok(true)
+    }
     fn supports_restart(&self) -> bool {
-        false
+        true
     }
 }
 
@@ -1113,6 +1324,8 @@ impl ReadLogs for MaraV1 {
     }
 }
 
+impl SetSyslogTarget for MaraV1 {}
+
 impl FactoryReset for MaraV1 {
     fn supports_factory_reset(&self) -> bool {
         false
@@ -1147,24 +1360,126 @@ impl HasAuth for MaraV1 {
 
 #[async_trait]
 impl SupportsTuningConfig for MaraV1 {
+    /// The config algorithm selects the work mode (`Auto`, `Fixed`, `Stock`);
+    /// without one the mode follows from the target.
+    async fn set_tuning_config(
+        &self,
+        config: TuningConfig,
+        _scaling_config: Option<ScalingConfig>,
+    ) -> anyhow::Result<bool> {
+        config.ensure_target_only()?;
+        let work_mode = config
+            .algorithm()
+            .map(str::parse::<MaraWorkMode>)
+            .transpose()?;
+
+        let mut miner_config = self.get_miner_config().await?;
+        Self::apply_tuning_target(&mut miner_config, &config.target)?;
+        if let Some(work_mode) = work_mode {
+            miner_config["mode"]["work-mode-selector"] = json!(work_mode.to_string());
+        }
+        self.set_miner_config(miner_config).await
+    }
+
+    fn parse_tuning_config(
+        &self,
+        data: &HashMap<ConfigField, Value>,
+    ) -> anyhow::Result<TuningConfig> {
+        data.get(&ConfigField::Tuning)
+            .ok_or_else(|| anyhow::anyhow!("MaraFW miner_config missing mode"))
+            .and_then(Self::parse_tuning_mode)
+    }
+
     fn supports_tuning_config(&self) -> bool {
-        false
+        true
     }
 }
 
 #[async_trait]
 impl SupportsFanConfig for MaraV1 {
+    async fn set_fan_config(&self, config: FanConfig) -> anyhow::Result<bool> {
+        config.ensure_no_min_fans()?;
+        let mut miner_config = self.get_miner_config().await?;
+        Self::apply_fan_config(&mut miner_config, &config)?;
+        self.set_miner_config(miner_config).await
+    }
+
+    fn parse_fan_config(&self, data: &HashMap<ConfigField, Value>) -> anyhow::Result<FanConfig> {
+        let advanced = data
+            .get(&ConfigField::Fan)
+            .ok_or_else(|| anyhow::anyhow!("MaraFW miner_config missing advance-config"))?;
+
+        if advanced
+            .get("override-fan-control")
+            .and_then(Value::as_bool)
+            == Some(true)
+        {
+            advanced
+                .get("fan-fixed-percent")
+                .and_then(Value::as_u64)
+                .map(FanConfig::manual)
+                .ok_or_else(|| anyhow::anyhow!("MaraFW miner_config missing fan-fixed-percent"))
+        } else {
+            advanced
+                .get(CHIP_TARGET_TEMP)
+                .and_then(Value::as_f64)
+                .map(|target| FanConfig::auto(target, None))
+                .ok_or_else(|| anyhow::anyhow!("MaraFW miner_config missing {CHIP_TARGET_TEMP}"))
+        }
+    }
+
     fn supports_fan_config(&self) -> bool {
-        false
+        true
     }
 }
 
 impl SupportsTemperatureConfig for MaraV1 {}
 impl GetTuningPercent for MaraV1 {}
 impl SetTuningPercent for MaraV1 {}
+impl GetApplianceConfig for MaraV1 {}
+impl SetApplianceConfig for MaraV1 {}
+
+#[async_trait]
+impl SupportsNetworkConfig for MaraV1 {
+    async fn apply_network_config(&self, config: NetworkConfig) -> anyhow::Result<bool> {
+        let mut network = self
+            .web
+            .send_command("network_config", true, None, Method::GET)
+            .await?;
+        Self::write_network_config(&mut network, &config);
+
+        let resp = self
+            .web
+            .send_command("network_config", true, Some(network), Method::POST)
+            .await?;
+        if resp.get("error").and_then(Value::as_bool) == Some(true) {
+            let msg = resp
+                .get("msg")
+                .and_then(Value::as_str)
+                .unwrap_or("unknown error");
+            anyhow::bail!("MaraFW network_config POST failed: {msg}");
+        }
+        Ok(true)
+    }
+
+    fn parse_network_config(
+        &self,
+        data: &HashMap<ConfigField, Value>,
+    ) -> anyhow::Result<NetworkConfig> {
+        data.get(&ConfigField::Network)
+            .ok_or_else(|| anyhow::anyhow!("MaraFW network_config missing"))
+            .and_then(Self::parse_network_config_value)
+    }
+
+    fn supports_network_config(&self) -> bool {
+        true
+    }
+}
 
 #[cfg(test)]
 mod tests {
+    use asic_rs_makes_antminer::models::AntMinerModel;
+
     use super::*;
 
     #[test]
@@ -1256,4 +1571,138 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_tuning_target_round_trip() -> anyhow::Result<()> {
+        let mut config = json!({
+            "mode": {
+                "work-mode-selector": "Stock",
+                "concorde": { "mode-select": "PowerTarget", "power-target": 3000 },
+            }
+        });
+
+        MaraV1::apply_tuning_target(&mut config, &TuningTarget::from_watts(3500.0))?;
+        let tuning = MaraV1::parse_tuning_mode(&config["mode"])?;
+
+        assert_eq!(tuning.target, TuningTarget::from_watts(3500.0));
+        assert_eq!(tuning.algorithm(), Some("Auto"));
+
+        MaraV1::apply_tuning_target(&mut config, &TuningTarget::MiningMode(MiningMode::Normal))?;
+        assert_eq!(config["mode"]["work-mode-selector"], "Stock");
+        assert!(
+            MaraV1::apply_tuning_target(&mut config, &TuningTarget::MiningMode(MiningMode::High))
+                .is_err()
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_tuning_target_keeps_paused_miner_asleep() {
+        let mut config = json!({
+            "mode": {
+                "work-mode-selector": "Sleep",
+                "concorde": { "mode-select": "PowerTarget", "power-target": 3000 },
+            }
+        });
+
+        assert!(
+            MaraV1::apply_tuning_target(&mut config, &TuningTarget::from_watts(3500.0)).is_err()
+        );
+        assert_eq!(config["mode"]["work-mode-selector"], "Sleep");
+        assert_eq!(config["mode"]["concorde"]["power-target"], 3000);
+    }
+
+    #[test]
+    fn test_apply_fan_config() -> anyhow::Result<()> {
+        let mut config = json!({});
+
+        MaraV1::apply_fan_config(&mut config, &FanConfig::manual(60))?;
+        assert_eq!(config["advance-config"]["override-fan-control"], true);
+        assert_eq!(config["advance-config"]["fan-fixed-percent"], 60);
+
+        MaraV1::apply_fan_config(&mut config, &FanConfig::auto(65.0, None))?;
+        assert_eq!(config["advance-config"]["override-fan-control"], false);
+        assert_eq!(config["advance-config"]["chip-target-temp"], 65);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_fan_config_reads_target() -> anyhow::Result<()> {
+        let miner = MaraV1::new(IpAddr::from([127, 0, 0, 1]), AntMinerModel::S19KPro);
+        let parse = |advanced: Value| {
+            miner.parse_fan_config(&HashMap::from([(ConfigField::Fan, advanced)]))
+        };
+
+        assert_eq!(
+            parse(json!({"override-fan-control": false, "chip-target-temp": 70}))?,
+            FanConfig::auto(70.0, None)
+        );
+        assert_eq!(
+            parse(json!({"override-fan-control": true, "fan-fixed-percent": 40}))?,
+            FanConfig::manual(40)
+        );
+        assert!(parse(json!({"override-fan-control": false})).is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_network_config_round_trip() -> anyhow::Result<()> {
+        let mut network = json!({
+            "dhcp": true,
+            "hostname": "mara-miner",
+            "ipaddress": "192.168.1.50",
+            "netmask": "255.255.255.0",
+            "gateway": "192.168.1.1",
+            "dns": "192.168.1.1",
+        });
+        let config = MaraV1::parse_network_config_value(&network)?;
+        assert!(config.dhcp);
+        assert_eq!(config.hostname.as_deref(), Some("mara-miner"));
+
+        let static_config = NetworkConfig::static_ip(
+            IpAddr::from([192, 168, 1, 60]),
+            IpAddr::from([255, 255, 255, 0]),
+            IpAddr::from([192, 168, 1, 1]),
+            vec![IpAddr::from([1, 1, 1, 1]), IpAddr::from([8, 8, 8, 8])],
+        );
+        MaraV1::write_network_config(&mut network, &static_config);
+        assert_eq!(network["dhcp"], false);
+        assert_eq!(network["ipaddress"], "192.168.1.60");
+        assert_eq!(network["dns"], "1.1.1.1,8.8.8.8");
+        assert_eq!(network["hostname"], "mara-miner");
+        assert_eq!(
+            MaraV1::parse_network_config_value(&network)?,
+            static_config.with_hostname("mara-miner")
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_parse_network() {
+        let miner = MaraV1::new(IpAddr::from([127, 0, 0, 1]), AntMinerModel::S19KPro);
+        let mut data = HashMap::new();
+        data.insert(
+            DataField::Network,
+            json!({
+                "dhcp": false,
+                "hostname": "mara-miner",
+                "ipaddress": "192.168.1.50",
+                "netmask": "255.255.255.0",
+                "gateway": "192.168.1.1",
+                "dns": "1.1.1.1, 8.8.8.8",
+            }),
+        );
+
+        let network = miner.parse_network(&data).unwrap();
+        assert_eq!(network.dhcp, Some(false));
+        assert_eq!(network.gateway, Some(IpAddr::from([192, 168, 1, 1])));
+        assert_eq!(
+            network.dns,
+            vec![IpAddr::from([1, 1, 1, 1]), IpAddr::from([8, 8, 8, 8])]
+        );
+    }
 }
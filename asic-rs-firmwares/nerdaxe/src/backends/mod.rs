@@ -6,6 +6,7 @@ use asic_rs_core::traits::{
 };
 use v1::NerdAxeV1;
 
+pub(crate) mod settings;
 pub mod v1;
 
 pub struct NerdAxe;
@@ -0,0 +1,151 @@
+//! NerdAxe keeps the AxeOS settings for pools, tuning and OTA; only the fan
+//! controller differs.
+
+use anyhow::{anyhow, bail};
+use asic_rs_core::config::fan::FanConfig;
+use asic_rs_firmwares_bitaxe::axeos::settings::DEFAULT_FAN_TARGET_TEMP;
+pub(crate) use asic_rs_firmwares_bitaxe::axeos::settings::{
+    ota_endpoint, parse_pools_config, parse_tuning_config, pools_config_payload,
+    tuning_config_payload,
+};
+use serde_json::{Value, json};
+
+/// NerdQAxe builds run the auto fan as a PID loop selected with
+/// `autofanspeed: 2`; older NerdAxe builds use the AxeOS `1`.
+const PID_AUTO_FAN_MODE: u64 = 2;
+const AXEOS_AUTO_FAN_MODE: u64 = 1;
+
+/// Whether the firmware runs the NerdQAxe PID fan controller.
+fn has_pid_fan(info: &Value) -> bool {
+    info.get("pidTargetTemp").is_some()
+}
+
+pub(crate) fn parse_fan_config(info: &Value) -> anyhow::Result<FanConfig> {
+    let mode = info
+        .get("autofanspeed")
+        .and_then(|v| v.as_u64().or_else(|| v.as_bool().map(u64::from)))
+        .ok_or_else(|| anyhow!("No autofanspeed setting in system info"))?;
+
+    if mode != 0 {
+        let target_temp = info
+            .get("pidTargetTemp")
+            .or_else(|| info.get("temptarget"))
+            .and_then(Value::as_f64)
+            .unwrap_or(DEFAULT_FAN_TARGET_TEMP);
+        Ok(FanConfig::auto(target_temp, None))
+    } else {
+        // `fanspeed` is the live duty cycle on NerdQAxe, the setting lives in
+        // `manualFanSpeed`.
+        info.get("manualFanSpeed")
+            .or_else(|| info.get("fanspeed"))
+            .and_then(Value::as_u64)
+            .map(FanConfig::manual)
+            .ok_or_else(|| anyhow!("No fan speed setting in system info"))
+    }
+}
+
+/// Build the `PATCH system` payload for a fan config, using the field names of
+/// the fan controller reported in `system/info`.
+pub(crate) fn fan_config_payload(config: &FanConfig, info: &Value) -> anyhow::Result<Value> {
+    let pid = has_pid_fan(info);
+    match config {
+        FanConfig::Auto { target_temp, .. } => {
+            let (mode, target_key) = if pid {
+                (PID_AUTO_FAN_MODE, "pidTargetTemp")
+            } else {
+                (AXEOS_AUTO_FAN_MODE, "temptarget")
+            };
+            let mut payload = json!({ "autofanspeed": mode });
+            if let Some(target_temp) = target_temp {
+                payload[target_key] = json!(target_temp.round().max(0.0) as u64);
+            }
+            Ok(payload)
+        }
+        FanConfig::Manual { fan_speed, .. } => {
+            if *fan_speed > 100 {
+                bail!("Fan speed must be a percentage, got {fan_speed}");
+            }
+            let key = if info.get("manualFanSpeed").is_some() {
+                "manualFanSpeed"
+            } else {
+                "fanspeed"
+            };
+            Ok(json!({
+                "autofanspeed": 0,
+                key: fan_speed,
+            }))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn nerdqaxe_info() -> Value {
+        json!({
+            "autofanspeed": 2,
+            "pidTargetTemp": 55,
+            "fanspeed": 43,
+            "manualFanSpeed": 70,
+        })
+    }
+
+    fn nerdaxe_info() -> Value {
+        json!({
+            "autofanspeed": 1,
+            "temptarget": 62,
+            "fanspeed": 80,
+        })
+    }
+
+    #[test]
+    fn pid_fan_target_is_parsed() {
+        assert_eq!(
+            parse_fan_config(&nerdqaxe_info()).unwrap(),
+            FanConfig::auto(55.0, None)
+        );
+        assert_eq!(
+            parse_fan_config(&nerdaxe_info()).unwrap(),
+            FanConfig::auto(62.0, None)
+        );
+    }
+
+    #[test]
+    fn manual_fan_speed_setting_is_preferred_over_live_duty() {
+        let mut info = nerdqaxe_info();
+        info["autofanspeed"] = json!(0);
+        assert_eq!(parse_fan_config(&info).unwrap(), FanConfig::manual(70));
+
+        let mut info = nerdaxe_info();
+        info["autofanspeed"] = json!(false);
+        assert_eq!(parse_fan_config(&info).unwrap(), FanConfig::manual(80));
+    }
+
+    #[test]
+    fn auto_fan_payload_follows_fan_controller() {
+        let config = FanConfig::auto(58.0, None);
+        assert_eq!(
+            fan_config_payload(&config, &nerdqaxe_info()).unwrap(),
+            json!({"autofanspeed": 2, "pidTargetTemp": 58})
+        );
+        assert_eq!(
+            fan_config_payload(&config, &nerdaxe_info()).unwrap(),
+            json!({"autofanspeed": 1, "temptarget": 58})
+        );
+    }
+
+    #[test]
+    fn manual_fan_payload_follows_fan_controller() {
+        let config = FanConfig::manual(65);
+        assert_eq!(
+            fan_config_payload(&config, &nerdqaxe_info()).unwrap(),
+            json!({"autofanspeed": 0, "manualFanSpeed": 65})
+        );
+        assert_eq!(
+            fan_config_payload(&config, &nerdaxe_info()).unwrap(),
+            json!({"autofanspeed": 0, "fanspeed": 65})
+        );
+        assert!(fan_config_payload(&FanConfig::manual(101), &nerdaxe_info()).is_err());
+    }
+}
@@ -2,7 +2,11 @@ use std::{net::IpAddr, time::Duration};
 
 use once_cell::sync::OnceCell;
 
-use asic_rs_core::{data::command::MinerCommand, traits::miner::*};
+use asic_rs_core::{
+    data::{command::MinerCommand, firmware::FirmwareImage},
+    traits::miner::*,
+};
+use asic_rs_firmwares_bitaxe::axeos::ota;
 use async_trait::async_trait;
 use reqwest::{Client, Method, Response};
 use serde_json::Value;
@@ -52,8 +56,24 @@ impl WebAPIClient for NerdAxeWebAPI {
             match result {
                 Ok(response) => {
                     if response.status().is_success() {
-                        match response.json().await {
-                            Ok(json_data) => return Ok(json_data),
+                        match response.text().await {
+                            Ok(text) => match serde_json::from_str(&text) {
+                                Ok(json_data) => return Ok(json_data),
+                                // Settings and restart requests are answered with an
+                                // empty or plain text body.
+                                Err(_) if method != Method::GET => {
+                                    return Ok(if text.is_empty() {
+                                        Value::Null
+                                    } else {
+                                        Value::String(text)
+                                    });
+                                }
+                                Err(e) => {
+                                    if attempt == self.retries {
+                                        return Err(NerdAxeError::ParseError(e.to_string()))?;
+                                    }
+                                }
+                            },
                             Err(e) => {
                                 if attempt == self.retries {
                                     return Err(NerdAxeError::ParseError(e.to_string()))?;
@@ -98,6 +118,26 @@ impl NerdAxeWebAPI {
         self.client.get_or_try_init(Self::build_client)
     }
 
+    pub async fn system_info(&self) -> anyhow::Result<Value> {
+        self.send_command("system/info", false, None, Method::GET)
+            .await
+    }
+
+    pub async fn restart(&self) -> anyhow::Result<Value> {
+        self.send_command("system/restart", false, None, Method::POST)
+            .await
+    }
+
+    pub async fn update_settings(&self, settings: Value) -> anyhow::Result<Value> {
+        self.send_command("system", false, Some(settings), Method::PATCH)
+            .await
+    }
+
+    /// Upload an OTA image to `endpoint` (`system/OTA` or `system/OTAWWW`).
+    pub async fn upload_ota(&self, endpoint: &str, image: FirmwareImage) -> anyhow::Result<()> {
+        ota::upload_ota(self.client()?, self.ip, self.port, endpoint, image).await
+    }
+
     async fn execute_request(
         &self,
         url: &str,
@@ -1,3 +1,4 @@
 pub use v1::ProtoV1;
 
+pub(crate) mod upgrade;
 pub mod v1;
@@ -0,0 +1,86 @@
+use std::time::Duration;
+
+use serde_json::Value;
+
+pub(crate) const UPDATE_POLL_INTERVAL: Duration = Duration::from_secs(5);
+pub(crate) const INSTALL_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+pub(crate) const REBOOT_TIMEOUT: Duration = Duration::from_secs(10 * 60);
+
+/// State of a software update, from `/system-info/sw_update_status`.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum UpdateStatus {
+    /// No update is pending.
+    Current,
+    /// The uploaded image is being verified or installed.
+    Installing {
+        progress: Option<u64>,
+    },
+    /// The image is installed and takes effect after a reboot.
+    Installed {
+        version: Option<String>,
+    },
+    Failed(String),
+}
+
+/// Parse `sw_update_status` from a `/api/v1/system` response.
+pub(crate) fn parse_update_status(system: &Value) -> Option<UpdateStatus> {
+    let status = system.pointer("/system-info/sw_update_status")?;
+    let version = ["new_version", "pending_version"]
+        .iter()
+        .find_map(|key| status.get(*key)?.as_str())
+        .map(String::from);
+
+    Some(match status.get("status")?.as_str()? {
+        "current" => UpdateStatus::Current,
+        "installed" | "pending_reboot" | "ready" => UpdateStatus::Installed { version },
+        "error" | "failed" => UpdateStatus::Failed(
+            ["error", "message"]
+                .iter()
+                .find_map(|key| status.get(*key)?.as_str())
+                .unwrap_or("unknown error")
+                .to_string(),
+        ),
+        _ => UpdateStatus::Installing {
+            progress: status.get("progress").and_then(Value::as_u64),
+        },
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::test::json::v1::SYSTEM;
+
+    fn system(status: Value) -> Value {
+        json!({ "system-info": { "sw_update_status": status } })
+    }
+
+    #[test]
+    fn parse_update_status_test() -> anyhow::Result<()> {
+        let current: Value = serde_json::from_str(SYSTEM)?;
+        assert_eq!(parse_update_status(&current), Some(UpdateStatus::Current));
+
+        assert_eq!(
+            parse_update_status(&system(json!({ "status": "installing", "progress": 40 }))),
+            Some(UpdateStatus::Installing { progress: Some(40) })
+        );
+        assert_eq!(
+            parse_update_status(&system(
+                json!({ "status": "installed", "current_version": "1.8.0", "new_version": "1.9.0" })
+            )),
+            Some(UpdateStatus::Installed {
+                version: Some("1.9.0".to_string())
+            })
+        );
+        assert_eq!(
+            parse_update_status(&system(
+                json!({ "status": "error", "error": "signature check failed" })
+            )),
+            Some(UpdateStatus::Failed("signature check failed".to_string()))
+        );
+        assert_eq!(parse_update_status(&json!({})), None);
+        Ok(())
+    }
+}
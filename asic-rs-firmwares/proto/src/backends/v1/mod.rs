@@ -8,6 +8,7 @@ use asic_rs_core::{
             get_by_pointer as get_config_by_pointer,
         },
         fan::FanConfig,
+        network::NetworkConfig,
         pools::{PoolConfig, PoolGroupConfig},
         scaling::ScalingConfig,
         tuning::TuningConfig,
@@ -18,12 +19,16 @@ use asic_rs_core::{
             DataCollector, DataExtensions, DataExtractor, DataField, DataLocation, get_by_pointer,
         },
         command::MinerCommand,
+        control_board::ControlBoardData,
         device::{DeviceInfo, HashAlgorithm, MinerHardware},
         fan::FanData,
+        firmware::{self, FirmwareImage},
         hashrate::{HashRate, HashRateUnit},
         message::{MessageSeverity, MinerComponent, MinerMessage},
         miner::TuningTarget,
+        network::NetworkData,
         pool::{PoolData, PoolGroupData, PoolURL},
+        psu::PsuData,
     },
     traits::{
         auth::{HasAuth, HasDefaultAuth, MinerAuth},
@@ -34,11 +39,11 @@ use asic_rs_core::{
 use asic_rs_makes_proto::hardware::ProtoControlBoard;
 use async_trait::async_trait;
 use macaddr::MacAddr;
-use measurements::{AngularVelocity, Power, Temperature};
+use measurements::{AngularVelocity, Current, Power, Temperature, Voltage};
 use serde_json::{Value, json};
 
 use super::v1::web::ProtoWebAPI;
-use crate::firmware::ProtoFirmware;
+use crate::{backends::upgrade::REBOOT_TIMEOUT, firmware::ProtoFirmware};
 
 pub mod web;
 
@@ -122,6 +127,31 @@ impl ProtoV1 {
             fans: fans.map(|f| f.len() as u8),
         }
     }
+
+    /// Parse the `network-info` object of a `/api/v1/network` response.
+    fn network_config_from_info(info: &Value) -> NetworkConfig {
+        let addr = |key: &str| info.get(key)?.as_str()?.parse().ok();
+
+        NetworkConfig {
+            dhcp: info.get("dhcp").and_then(Value::as_bool).unwrap_or(false),
+            ip: addr("ip_address"),
+            netmask: addr("netmask"),
+            gateway: addr("gateway"),
+            dns: info
+                .get("dns_servers")
+                .and_then(Value::as_array)
+                .map(|servers| {
+                    servers
+                        .iter()
+                        .filter_map(|server| server.as_str()?.parse().ok())
+                        .collect()
+                }),
+            hostname: info
+                .get("hostname")
+                .and_then(Value::as_str)
+                .map(String::from),
+        }
+    }
 }
 
 #[async_trait]
@@ -166,6 +196,17 @@ impl GetConfigsLocations for ProtoV1 {
                     tag: None,
                 },
             )],
+            ConfigField::Network => vec![(
+                MinerCommand::WebAPI {
+                    command: "/api/v1/network",
+                    parameters: None,
+                },
+                ConfigExtractor {
+                    func: get_config_by_pointer,
+                    key: Some("/network-info"),
+                    tag: None,
+                },
+            )],
             ConfigField::Tuning => vec![(
                 WEB_MINING_TARGET,
                 ConfigExtractor {
@@ -246,6 +287,22 @@ impl GetDataLocations for ProtoV1 {
                     tag: None,
                 },
             )],
+            DataField::Network => vec![(
+                WEB_NETWORK,
+                DataExtractor {
+                    func: get_by_pointer,
+                    key: Some("/network-info"),
+                    tag: None,
+                },
+            )],
+            DataField::ControlBoard => vec![(
+                WEB_SYSTEM,
+                DataExtractor {
+                    func: get_by_pointer,
+                    key: Some("/system-info/os"),
+                    tag: None,
+                },
+            )],
             DataField::ApiVersion => vec![(
                 WEB_SYSTEM,
                 DataExtractor {
@@ -296,6 +353,24 @@ impl GetDataLocations for ProtoV1 {
                     },
                 ),
             ],
+            DataField::Psus => vec![
+                (
+                    WEB_HARDWARE,
+                    DataExtractor {
+                        func: get_by_pointer,
+                        key: Some("/hardware-info/psus-info"),
+                        tag: Some("hardware"),
+                    },
+                ),
+                (
+                    web_telemetry_full.clone(),
+                    DataExtractor {
+                        func: get_by_pointer,
+                        key: Some("/psus"),
+                        tag: Some("telemetry"),
+                    },
+                ),
+            ],
             DataField::Hashrate => vec![(
                 web_telemetry_full.clone(),
                 DataExtractor {
@@ -415,6 +490,14 @@ impl GetHostname for ProtoV1 {
     }
 }
 
+impl GetNetwork for ProtoV1 {
+    fn parse_network(&self, data: &HashMap<DataField, Value>) -> Option<NetworkData> {
+        data.get(&DataField::Network)
+            .filter(|info| info.is_object())
+            .map(|info| NetworkData::from(Self::network_config_from_info(info)))
+    }
+}
+
 impl GetApiVersion for ProtoV1 {
     fn parse_api_version(&self, data: &HashMap<DataField, Value>) -> Option<String> {
         data.extract::<String>(DataField::ApiVersion)
@@ -437,6 +520,23 @@ impl GetControlBoardVersion for ProtoV1 {
     }
 }
 
+impl GetControlBoard for ProtoV1 {
+    fn parse_control_board(&self, data: &HashMap<DataField, Value>) -> Option<ControlBoardData> {
+        let os = data.get(&DataField::ControlBoard)?;
+        let field = |key: &str| os.get(key).and_then(Value::as_str);
+        let os_version = match (field("name"), field("version")) {
+            (Some(name), Some(version)) => format!("{name} {version}"),
+            (Some(name), None) => name.to_string(),
+            (None, Some(version)) => version.to_string(),
+            (None, None) => return None,
+        };
+        Some(ControlBoardData {
+            os_version: Some(os_version),
+            ..Default::default()
+        })
+    }
+}
+
 #[async_trait]
 impl GetHashboards for ProtoV1 {
     fn parse_hashboards(&self, data: &HashMap<DataField, Value>) -> Vec<BoardData> {
@@ -607,6 +707,80 @@ impl GetFans for ProtoV1 {
 }
 
 impl GetPsuFans for ProtoV1 {}
+
+impl GetPsus for ProtoV1 {
+    fn parse_psus(&self, data: &HashMap<DataField, Value>) -> Vec<PsuData> {
+        let Some(psus_payload) = data.get(&DataField::Psus) else {
+            return Vec::new();
+        };
+        let empty = Vec::new();
+        let hardware_psus = psus_payload
+            .get("hardware")
+            .and_then(Value::as_array)
+            .unwrap_or(&empty);
+        let telemetry_psus = psus_payload
+            .get("telemetry")
+            .and_then(Value::as_array)
+            .unwrap_or(&empty);
+
+        // Hardware info lists installed PSUs by 1-based slot; telemetry
+        // reports them by 0-based index.
+        let mut psus: Vec<PsuData> = hardware_psus
+            .iter()
+            .enumerate()
+            .map(|(idx, psu)| {
+                let slot = psu
+                    .get("slot")
+                    .and_then(Value::as_u64)
+                    .unwrap_or((idx + 1) as u64);
+                let mut psu_data = PsuData::new(slot.saturating_sub(1) as u8);
+                psu_data.model = psu
+                    .get("model")
+                    .and_then(Value::as_str)
+                    .map(ToOwned::to_owned);
+                psu_data.serial_number = psu
+                    .get("psu_sn")
+                    .and_then(Value::as_str)
+                    .map(ToOwned::to_owned);
+                psu_data.firmware_version = psu
+                    .pointer("/firmware/app_version")
+                    .and_then(Value::as_str)
+                    .map(ToOwned::to_owned);
+                psu_data
+            })
+            .collect();
+
+        for telemetry in telemetry_psus {
+            let serial = telemetry.get("serial_number").and_then(Value::as_str);
+            let position = telemetry
+                .get("index")
+                .and_then(Value::as_u64)
+                .map(|idx| idx as u8);
+            let idx = psus
+                .iter()
+                .position(|psu| serial.is_some() && psu.serial_number.as_deref() == serial)
+                .or_else(|| psus.iter().position(|psu| Some(psu.position) == position))
+                .unwrap_or_else(|| {
+                    let mut psu_data = PsuData::new(position.unwrap_or(psus.len() as u8));
+                    psu_data.serial_number = serial.map(ToOwned::to_owned);
+                    psus.push(psu_data);
+                    psus.len() - 1
+                });
+            let psu = &mut psus[idx];
+
+            let reading = |pointer: &str| telemetry.pointer(pointer).and_then(Value::as_f64);
+            psu.input_voltage = reading("/voltage/input").map(Voltage::from_volts);
+            psu.input_current = reading("/current/input").map(Current::from_amperes);
+            psu.output_voltage = reading("/voltage/output").map(Voltage::from_volts);
+            psu.output_power = reading("/power/output").map(Power::from_watts);
+            psu.temperature = reading("/temperature/average").map(Temperature::from_celsius);
+        }
+
+        psus.sort_by_key(|psu| psu.position);
+        psus
+    }
+}
+
 impl GetFluidTemperature for ProtoV1 {}
 
 impl GetWattage for ProtoV1 {
@@ -813,7 +987,31 @@ impl Resume for ProtoV1 {
 }
 
 #[async_trait]
-impl UpgradeFirmware for ProtoV1 {}
+impl UpgradeFirmware for ProtoV1 {
+    async fn upgrade_firmware(&self, image: FirmwareImage) -> anyhow::Result<bool> {
+        if image.bytes.is_empty() {
+            anyhow::bail!("Firmware image {} is empty", image.filename);
+        }
+        let previous = firmware::version_before_upgrade(self).await?;
+        self.web.upload_update(image).await?;
+        let expected = self.web.wait_for_update().await?;
+        self.web.reboot().await?;
+
+        let version = firmware::wait_for_upgraded_version(
+            self,
+            &previous,
+            expected.as_deref(),
+            REBOOT_TIMEOUT,
+        )
+        .await?;
+        tracing::info!(miner_ip = %self.ip, version = %version, "Proto firmware upgrade complete");
+        Ok(true)
+    }
+
+    fn supports_upgrade_firmware(&self) -> bool {
+        true
+    }
+}
 
 #[async_trait]
 impl ChangePassword for ProtoV1 {
@@ -831,9 +1029,15 @@ impl ChangePassword for ProtoV1 {
     }
 }
 
+#[async_trait]
 impl FactoryReset for ProtoV1 {
+    async fn factory_reset(&self) -> anyhow::Result<bool> {
+        self.web.factory_reset().await?;
+        Ok(true)
+    }
+
     fn supports_factory_reset(&self) -> bool {
-        false
+        true
     }
 }
 
@@ -848,6 +1052,8 @@ impl ReadLogs for ProtoV1 {
     }
 }
 
+impl SetSyslogTarget for ProtoV1 {}
+
 #[async_trait]
 impl SupportsPoolsConfig for ProtoV1 {
     async fn set_pools_config(&self, config: Vec<PoolGroupConfig>) -> anyhow::Result<bool> {
@@ -919,6 +1125,7 @@ impl SupportsTuningConfig for ProtoV1 {
         config: TuningConfig,
         _scaling_config: Option<ScalingConfig>,
     ) -> anyhow::Result<bool> {
+        config.ensure_target_only()?;
         if let TuningTarget::Power(power) = config.target {
             return self.set_power_limit(power).await;
         }
@@ -948,12 +1155,15 @@ impl SupportsTuningConfig for ProtoV1 {
 #[async_trait]
 impl SupportsFanConfig for ProtoV1 {
     async fn set_fan_config(&self, config: FanConfig) -> anyhow::Result<bool> {
+        config.ensure_no_min_fans()?;
         let payload = match config {
             FanConfig::Auto { target_temp, .. } => json!({
                 "mode": "Auto",
-                "target_temperature_c": target_temp,
+                "target_temperature_c": target_temp.ok_or_else(|| {
+                    anyhow!("Proto automatic fan control needs a target temperature")
+                })?,
             }),
-            FanConfig::Manual { fan_speed } => json!({
+            FanConfig::Manual { fan_speed, .. } => json!({
                 "mode": "Manual",
                 "speed_percentage": fan_speed,
             }),
@@ -1009,6 +1219,41 @@ impl HasAuth for ProtoV1 {
 impl SupportsTemperatureConfig for ProtoV1 {}
 impl GetTuningPercent for ProtoV1 {}
 impl SetTuningPercent for ProtoV1 {}
+impl GetApplianceConfig for ProtoV1 {}
+impl SetApplianceConfig for ProtoV1 {}
+
+#[async_trait]
+impl SupportsNetworkConfig for ProtoV1 {
+    async fn apply_network_config(&self, config: NetworkConfig) -> anyhow::Result<bool> {
+        let mut payload = json!({ "dhcp": config.dhcp });
+        if !config.dhcp {
+            payload["ip_address"] = json!(config.ip);
+            payload["netmask"] = json!(config.netmask);
+            payload["gateway"] = json!(config.gateway);
+            payload["dns_servers"] = json!(config.dns);
+        }
+        if let Some(hostname) = &config.hostname {
+            payload["hostname"] = json!(hostname);
+        }
+
+        self.web.set_network(payload).await?;
+        Ok(true)
+    }
+
+    fn parse_network_config(
+        &self,
+        data: &HashMap<ConfigField, Value>,
+    ) -> anyhow::Result<NetworkConfig> {
+        let info = data
+            .get(&ConfigField::Network)
+            .ok_or_else(|| anyhow!("missing network info"))?;
+        Ok(Self::network_config_from_info(info))
+    }
+
+    fn supports_network_config(&self) -> bool {
+        true
+    }
+}
 
 #[cfg(test)]
 mod tests {
@@ -1198,6 +1443,38 @@ mod tests {
                 > 10.0
         );
         assert_eq!(parsed.fans.len(), 4);
+        assert_eq!(
+            parsed
+                .control_board
+                .as_ref()
+                .and_then(|cb| cb.os_version.as_deref()),
+            Some("ProtoOS 1.8.0")
+        );
+        let network = parsed.network.as_ref().expect("network info");
+        assert_eq!(network.dhcp, Some(true));
+        assert_eq!(network.gateway, Some(IpAddr::from([192, 168, 2, 1])));
+        assert_eq!(parsed.psus.len(), 2);
+        assert_eq!(parsed.psus[1].position, 1);
+        assert_eq!(parsed.psus[1].model.as_deref(), Some("PSU-3600W"));
+        assert_eq!(
+            parsed.psus[1].serial_number.as_deref(),
+            Some("PSU-PROTO-SIM-b6bdaf86-1")
+        );
+        assert_eq!(parsed.psus[1].firmware_version.as_deref(), Some("1.2.0"));
+        assert!(
+            parsed.psus[1]
+                .input_voltage
+                .expect("PSU input voltage")
+                .as_volts()
+                > 200.0
+        );
+        assert!(
+            parsed.psus[1]
+                .output_power
+                .expect("PSU output power")
+                .as_watts()
+                > 1000.0
+        );
         assert!(parsed.is_mining);
         assert!(parsed.uptime.expect("uptime") > Duration::from_secs(0));
         assert_eq!(parsed.pools.len(), 1);
@@ -1371,4 +1648,26 @@ mod tests {
         assert_eq!(messages[1].message, "PSU_FAULT: PSU voltage out of range");
         assert_eq!(messages[1].component, Some(MinerComponent::power_supply(0)));
     }
+
+    #[test]
+    fn parse_network_config_from_network_info() -> Result<()> {
+        let miner = ProtoV1::new(
+            IpAddr::from([127, 0, 0, 1]),
+            asic_rs_makes_proto::models::ProtoModel::Rig,
+            None,
+            MinerHardware::default(),
+        );
+        let data = HashMap::from([(
+            ConfigField::Network,
+            parse_fixture(NETWORK)["network-info"].clone(),
+        )]);
+
+        let config = miner.parse_network_config(&data)?;
+        assert!(config.dhcp);
+        assert_eq!(config.ip, Some(IpAddr::from([192, 168, 8, 163])));
+        assert_eq!(config.netmask, Some(IpAddr::from([255, 255, 255, 0])));
+        assert_eq!(config.dns, None);
+        assert_eq!(config.hostname.as_deref(), Some("proto-miner-af86"));
+        Ok(())
+    }
 }
@@ -2,7 +2,10 @@ use std::{net::IpAddr, time::Duration};
 
 use anyhow::{Context, Result, anyhow, bail};
 use asic_rs_core::{
-    data::command::MinerCommand,
+    data::{
+        command::MinerCommand,
+        firmware::{FirmwareImage, upload_body},
+    },
     traits::{
         auth::MinerAuth,
         miner::{APIClient, WebAPIClient},
@@ -10,9 +13,16 @@ use asic_rs_core::{
 };
 use async_trait::async_trait;
 use once_cell::sync::OnceCell;
-use reqwest::{Client, Method, Response, StatusCode};
+use reqwest::{Client, Method, Response, StatusCode, multipart};
 use serde_json::{Value, json};
-use tokio::sync::RwLock;
+use tokio::{
+    sync::RwLock,
+    time::{Instant, sleep},
+};
+
+use crate::backends::upgrade::{
+    INSTALL_TIMEOUT, UPDATE_POLL_INTERVAL, UpdateStatus, parse_update_status,
+};
 
 #[derive(Debug)]
 pub struct ProtoWebAPI {
@@ -191,12 +201,95 @@ impl ProtoWebAPI {
             .await
     }
 
+    /// Update the network configuration. The interface is reconfigured as soon
+    /// as the request is accepted.
+    pub async fn set_network(&self, payload: Value) -> Result<Value> {
+        self.send_command("/api/v1/network", false, Some(payload), Method::PUT)
+            .await
+    }
+
     /// Replace the configured pools. Accepts an array of pool configs.
     pub async fn set_pools(&self, payload: Value) -> Result<Value> {
         self.send_command("/api/v1/pools", false, Some(payload), Method::POST)
             .await
     }
 
+    /// Reset the rig to factory defaults. Pools, network and password settings
+    /// are erased and the rig reboots.
+    pub async fn factory_reset(&self) -> Result<Value> {
+        self.send_command("/api/v1/system/factory-reset", false, None, Method::POST)
+            .await
+    }
+
+    /// Upload a software update image to `/api/v1/system/update`, logging
+    /// progress as it is sent. The rig verifies and installs the image in the
+    /// background; see [`Self::wait_for_update`].
+    pub async fn upload_update(&self, image: FirmwareImage) -> Result<()> {
+        self.ensure_authenticated().await?;
+
+        let FirmwareImage { filename, bytes } = image;
+        let size = bytes.len() as u64;
+        let form = multipart::Form::new().part(
+            "file",
+            multipart::Part::stream_with_length(upload_body(self.ip, bytes), size)
+                .file_name(filename)
+                .mime_str("application/octet-stream")
+                .context("failed to set firmware part mime type")?,
+        );
+
+        let mut request = self
+            .client()?
+            .post(self.endpoint_url("/api/v1/system/update"))
+            .multipart(form)
+            .timeout(Duration::from_secs(600));
+        if let Some(token) = self.token.read().await.clone() {
+            request = request.bearer_auth(token);
+        }
+
+        let response = request
+            .send()
+            .await
+            .context("firmware upload HTTP request failed")?;
+        let status = response.status();
+        match status {
+            status if status.is_success() => Ok(()),
+            StatusCode::CONFLICT => bail!("A software update is already in progress on the rig"),
+            _ => {
+                let body = response.text().await.unwrap_or_default();
+                bail!("Firmware upload failed with status code {status}: {body}")
+            }
+        }
+    }
+
+    /// Poll `sw_update_status` until the uploaded image is installed, returning
+    /// the version it will boot into when the rig reports one.
+    pub async fn wait_for_update(&self) -> Result<Option<String>> {
+        let deadline = Instant::now() + INSTALL_TIMEOUT;
+        let mut last_progress = None;
+        while Instant::now() < deadline {
+            sleep(UPDATE_POLL_INTERVAL).await;
+            let Ok(system) = self
+                .send_command("/api/v1/system", false, None, Method::GET)
+                .await
+            else {
+                continue;
+            };
+            match parse_update_status(&system) {
+                Some(UpdateStatus::Installed { version }) => return Ok(version),
+                Some(UpdateStatus::Failed(error)) => bail!("Software update failed: {error}"),
+                Some(UpdateStatus::Installing { progress }) if progress != last_progress => {
+                    last_progress = progress;
+                    tracing::info!(miner_ip = %self.ip, progress, "Proto software update progress");
+                }
+                _ => {}
+            }
+        }
+        bail!(
+            "Software update was not installed within {} seconds",
+            INSTALL_TIMEOUT.as_secs()
+        )
+    }
+
     /// Flash the control-board locate LED to help find the miner. The LED
     /// turns itself off after the API's default duration; there is no way to
     /// turn it off early, and requests made while it is already lit are ignored.
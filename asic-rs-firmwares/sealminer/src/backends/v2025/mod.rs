@@ -7,6 +7,7 @@ use asic_rs_core::{
             ConfigCollector, ConfigExtractor, ConfigField, ConfigLocation,
             get_by_pointer as cfg_by_pointer,
         },
+        fan::FanConfig,
         pools::PoolGroupConfig,
         tuning::TuningConfig,
     },
@@ -19,18 +20,20 @@ use asic_rs_core::{
         command::MinerCommand,
         device::{DeviceInfo, HashAlgorithm},
         fan::FanData,
+        firmware::{self, FirmwareImage},
         hashrate::{HashRate, HashRateUnit},
         message::{MessageSeverity, MinerComponent, MinerMessage},
         miner::TuningTarget,
         pool::{PoolData, PoolGroupData, PoolURL},
+        psu::PsuData,
     },
     traits::{miner::*, model::MinerModel},
 };
 use async_trait::async_trait;
 use macaddr::MacAddr;
-use measurements::{AngularVelocity, Frequency, Power, Temperature};
+use measurements::{AngularVelocity, Frequency, Power, Temperature, Voltage};
 use rpc::SealMinerRPCAPI;
-use serde_json::Value;
+use serde_json::{Value, json};
 use std::time::{SystemTime, UNIX_EPOCH};
 use web::SealMinerWebAPI;
 
@@ -39,6 +42,10 @@ use crate::firmware::SealMinerStockFirmware;
 pub(crate) mod rpc;
 pub(crate) mod web;
 
+/// How long the miner may take to flash an upgrade, reboot and report the new
+/// firmware version.
+const UPGRADE_TIMEOUT: Duration = Duration::from_secs(15 * 60);
+
 #[derive(Debug)]
 pub struct SealMinerV2025 {
     pub ip: IpAddr,
@@ -83,6 +90,10 @@ impl GetConfigsLocations for SealMinerV2025 {
             command: "pools",
             parameters: None,
         };
+        const CGI_GET_MINER_CONF: MinerCommand = MinerCommand::WebAPI {
+            command: "get_miner_conf",
+            parameters: None,
+        };
         match data_field {
             ConfigField::Tuning => vec![(
                 RPC_STATS,
@@ -100,6 +111,14 @@ impl GetConfigsLocations for SealMinerV2025 {
                     tag: None,
                 },
             )],
+            ConfigField::Fan => vec![(
+                CGI_GET_MINER_CONF,
+                ConfigExtractor {
+                    func: cfg_by_pointer,
+                    key: Some(""),
+                    tag: None,
+                },
+            )],
             _ => vec![],
         }
     }
@@ -251,6 +270,14 @@ impl GetDataLocations for SealMinerV2025 {
                     tag: None,
                 },
             )],
+            DataField::Psus => vec![(
+                RPC_STATS,
+                DataExtractor {
+                    func: get_by_pointer,
+                    key: Some("/STATS/0"),
+                    tag: None,
+                },
+            )],
             DataField::AverageTemperature => vec![(
                 RPC_STATS,
                 DataExtractor {
@@ -355,6 +382,8 @@ impl GetSerialNumber for SealMinerV2025 {
 
 impl GetHostname for SealMinerV2025 {}
 
+impl GetNetwork for SealMinerV2025 {}
+
 impl GetApiVersion for SealMinerV2025 {
     fn parse_api_version(&self, data: &HashMap<DataField, Value>) -> Option<String> {
         data.extract::<String>(DataField::ApiVersion)
@@ -380,6 +409,8 @@ impl GetControlBoardVersion for SealMinerV2025 {
     }
 }
 
+impl GetControlBoard for SealMinerV2025 {}
+
 impl GetHashboards for SealMinerV2025 {
     fn parse_hashboards(&self, data: &HashMap<DataField, Value>) -> Vec<BoardData> {
         let stats = match data.get(&DataField::Hashboards) {
@@ -536,6 +567,32 @@ impl GetPsuFans for SealMinerV2025 {
     }
 }
 
+impl GetPsus for SealMinerV2025 {
+    fn parse_psus(&self, data: &HashMap<DataField, Value>) -> Vec<PsuData> {
+        let Some(stats) = data.get(&DataField::Psus) else {
+            return vec![];
+        };
+        let text = |key: &str| stats.get(key).and_then(Value::as_str).map(String::from);
+        let number = |key: &str| stats.get(key).and_then(Value::as_f64);
+
+        let psu = PsuData {
+            model: text("PSU Model"),
+            serial_number: text("PSU SN"),
+            firmware_version: text("PSU Firmware"),
+            input_voltage: number("PSU Input Voltage").map(Voltage::from_volts),
+            output_voltage: number("PSU Output Voltage").map(Voltage::from_volts),
+            output_power: number("PSU Output Power").map(Power::from_watts),
+            temperature: number("PSU Temp AMB").map(Temperature::from_celsius),
+            fan_rpm: number("PSU Fan Speed").map(AngularVelocity::from_rpm),
+            ..PsuData::new(0)
+        };
+        if psu == PsuData::new(0) {
+            return vec![];
+        }
+        vec![psu]
+    }
+}
+
 impl GetFluidTemperature for SealMinerV2025 {}
 
 impl GetWattage for SealMinerV2025 {
@@ -720,18 +777,43 @@ impl Resume for SealMinerV2025 {
     }
 }
 
+#[async_trait]
 impl ChangePassword for SealMinerV2025 {
+    async fn change_password(&mut self, password: &str) -> anyhow::Result<bool> {
+        let original_auth = self.web.auth();
+        if !self.web.change_password(password).await? {
+            return Ok(false);
+        }
+
+        // The miner already took the new password, so keep it even if the
+        // check below fails.
+        self.set_auth(MinerAuth::new(original_auth.username(), password));
+        self.web.get_miner_conf().await.map_err(|e| {
+            anyhow::anyhow!(
+                "SealMiner accepted the new password but logging in with it failed: {e}"
+            )
+        })?;
+        Ok(true)
+    }
+
     fn supports_change_password(&self) -> bool {
-        false
+        true
     }
 }
 
+#[async_trait]
 impl ReadLogs for SealMinerV2025 {
+    async fn read_logs(&self) -> anyhow::Result<String> {
+        self.web.read_logs().await
+    }
+
     fn supports_read_logs(&self) -> bool {
-        false
+        true
     }
 }
 
+impl SetSyslogTarget for SealMinerV2025 {}
+
 impl FactoryReset for SealMinerV2025 {
     fn supports_factory_reset(&self) -> bool {
         false
@@ -799,6 +881,7 @@ impl SupportsTuningConfig for SealMinerV2025 {
         config: TuningConfig,
         _scaling_config: Option<ScalingConfig>,
     ) -> anyhow::Result<bool> {
+        config.ensure_target_only()?;
         match config.target {
             TuningTarget::Power(power) => self.set_power_limit(power).await,
             TuningTarget::HashRate(_) => {
@@ -817,15 +900,66 @@ impl SupportsTuningConfig for SealMinerV2025 {
 
 #[async_trait]
 impl SupportsFanConfig for SealMinerV2025 {
+    async fn set_fan_config(&self, config: FanConfig) -> anyhow::Result<bool> {
+        config.ensure_no_min_fans()?;
+        let payload = match config {
+            FanConfig::Auto {
+                target_temp: None,
+                idle_speed: None,
+                ..
+            } => json!({"fan-ctrl": false}),
+            FanConfig::Auto { .. } => anyhow::bail!(
+                "SealMiner automatic fan control has no target temperature or idle speed"
+            ),
+            FanConfig::Manual { fan_speed, .. } if fan_speed > 100 => {
+                anyhow::bail!("Fan speed must be a percentage, got {fan_speed}")
+            }
+            FanConfig::Manual { fan_speed, .. } => json!({
+                "fan-ctrl": true,
+                "fan-pwm": fan_speed.to_string(),
+            }),
+        };
+
+        self.web.set_miner_conf(payload).await?;
+        Ok(true)
+    }
+
+    fn parse_fan_config(&self, data: &HashMap<ConfigField, Value>) -> anyhow::Result<FanConfig> {
+        let conf = data
+            .get(&ConfigField::Fan)
+            .ok_or_else(|| anyhow::anyhow!("No fan config data"))?;
+
+        let manual = conf
+            .get("fan-ctrl")
+            .and_then(|v| v.as_bool().or_else(|| v.as_str().map(|s| s == "true")))
+            .unwrap_or(false);
+        if !manual {
+            // The firmware does not report its target temperature.
+            return Ok(FanConfig::auto(None, None));
+        }
+        let fan_speed = conf
+            .get("fan-pwm")
+            .and_then(|v| v.as_u64().or_else(|| v.as_str()?.parse().ok()))
+            .unwrap_or(100);
+        Ok(FanConfig::manual(fan_speed))
+    }
+
     fn supports_fan_config(&self) -> bool {
-        false
+        true
     }
 }
 
 #[async_trait]
 impl UpgradeFirmware for SealMinerV2025 {
+    async fn upgrade_firmware(&self, image: FirmwareImage) -> anyhow::Result<bool> {
+        let previous = firmware::version_before_upgrade(self).await?;
+        self.web.upgrade_firmware(image).await?;
+        firmware::wait_for_upgraded_version(self, &previous, None, UPGRADE_TIMEOUT).await?;
+        Ok(true)
+    }
+
     fn supports_upgrade_firmware(&self) -> bool {
-        false
+        true
     }
 }
 
@@ -844,6 +978,9 @@ impl HasDefaultAuth for SealMinerV2025 {
 impl SupportsTemperatureConfig for SealMinerV2025 {}
 impl GetTuningPercent for SealMinerV2025 {}
 impl SetTuningPercent for SealMinerV2025 {}
+impl GetApplianceConfig for SealMinerV2025 {}
+impl SetApplianceConfig for SealMinerV2025 {}
+impl SupportsNetworkConfig for SealMinerV2025 {}
 
 #[cfg(test)]
 mod tests {
@@ -962,6 +1099,19 @@ mod tests {
         );
         assert_eq!(miner_data.fans.len(), 4);
         assert_eq!(miner_data.wattage, Some(Power::from_watts(3504.0)));
+        assert_eq!(miner_data.psus.len(), 1);
+        assert_eq!(
+            miner_data.psus[0].model.as_deref(),
+            Some("BTPSU04KSA-03.00")
+        );
+        assert_eq!(
+            miner_data.psus[0].output_voltage,
+            Some(Voltage::from_volts(15.671))
+        );
+        assert_eq!(
+            miner_data.psus[0].fan_rpm,
+            Some(AngularVelocity::from_rpm(4408.0))
+        );
         assert_eq!(
             miner_data.tuning_target,
             Some(TuningTarget::Power(Power::from_watts(3500.0)))
@@ -970,4 +1120,39 @@ mod tests {
         assert_eq!(miner_data.pools.len(), 1);
         assert_eq!(miner_data.pools[0].len(), 1);
     }
+
+    #[tokio::test]
+    async fn test_sealminer_v2025_fan_config() -> anyhow::Result<()> {
+        let miner = SealMinerV2025::new(IpAddr::from([10, 0, 13, 179]), SealMinerModel::A2);
+
+        let mut results = HashMap::new();
+        results.insert(
+            MinerCommand::WebAPI {
+                command: "get_miner_conf",
+                parameters: None,
+            },
+            json!({"fan-ctrl": true, "fan-pwm": "80"}),
+        );
+
+        let mock_api = MockAPIClient::new(results);
+        let mut collector = ConfigCollector::new_with_client(&miner, &mock_api);
+        let data = collector.collect(&[ConfigField::Fan]).await;
+
+        assert_eq!(miner.parse_fan_config(&data)?, FanConfig::manual(80));
+
+        let data = HashMap::from([(
+            ConfigField::Fan,
+            json!({"fan-ctrl": false, "fan-pwm": "80"}),
+        )]);
+        assert_eq!(miner.parse_fan_config(&data)?, FanConfig::auto(None, None));
+
+        assert!(miner.set_fan_config(FanConfig::manual(150)).await.is_err());
+        assert!(
+            miner
+                .set_fan_config(FanConfig::auto(70.0, None))
+                .await
+                .is_err()
+        );
+        Ok(())
+    }
 }
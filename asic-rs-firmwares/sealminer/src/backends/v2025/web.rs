@@ -1,13 +1,14 @@
 use std::{net::IpAddr, time::Duration};
 
+use anyhow::Context;
 use asic_rs_core::{
     config::pools::{PoolConfig, PoolGroupConfig},
-    data::{command::MinerCommand, pool::PoolURL},
+    data::{command::MinerCommand, firmware::FirmwareImage, pool::PoolURL},
     traits::miner::{APIClient, MinerAuth, WebAPIClient},
 };
 use async_trait::async_trait;
 use once_cell::sync::OnceCell;
-use reqwest::{Client, Method};
+use reqwest::{Client, Method, multipart};
 use serde_json::{Value, json};
 use tokio::sync::Mutex;
 
@@ -34,6 +35,10 @@ impl SealMinerWebAPI {
         self.session_cookie = Mutex::new(None);
     }
 
+    pub fn auth(&self) -> MinerAuth {
+        self.auth.clone()
+    }
+
     fn build_client() -> anyhow::Result<Client> {
         Client::builder()
             .timeout(Duration::from_secs(10))
@@ -179,6 +184,97 @@ impl SealMinerWebAPI {
         })
     }
 
+    pub async fn get_miner_conf(&self) -> anyhow::Result<Value> {
+        self.send_command("get_miner_conf", false, None, Method::GET)
+            .await
+    }
+
+    pub async fn set_miner_conf(&self, conf: Value) -> anyhow::Result<Value> {
+        self.send_command("set_miner_conf", false, Some(conf), Method::POST)
+            .await
+    }
+
+    pub async fn change_password(&self, password: &str) -> anyhow::Result<bool> {
+        let payload = json!({
+            "curPwd": self.auth.password(),
+            "newPwd": password,
+            "confirmPwd": password,
+        });
+        let response = self
+            .send_command("passwd", true, Some(payload), Method::POST)
+            .await?;
+        Ok(response.get("stats").and_then(Value::as_str) == Some("success"))
+    }
+
+    pub async fn read_logs(&self) -> anyhow::Result<String> {
+        let cookie = self.session_cookie().await?;
+        let response = self
+            .client()?
+            .get(format!("http://{}/cgi-bin/log.php", self.ip))
+            .header("Cookie", cookie)
+            .send()
+            .await?;
+
+        let status = response.status();
+        if !status.is_success() {
+            anyhow::bail!("Log request failed with status code {status}");
+        }
+        response
+            .text()
+            .await
+            .map_err(|e| anyhow::anyhow!("failed to read log response body: {e}"))
+    }
+
+    /// Upload a firmware image to `upgrade.php`. The miner flashes it and reboots
+    /// on its own; settings are kept.
+    pub async fn upgrade_firmware(&self, image: FirmwareImage) -> anyhow::Result<()> {
+        let cookie = self.session_cookie().await?;
+        let FirmwareImage { filename, bytes } = image;
+        let form = multipart::Form::new().part(
+            "firmware",
+            multipart::Part::bytes(bytes)
+                .file_name(filename)
+                .mime_str("application/octet-stream")
+                .context("failed to set firmware part mime type")?,
+        );
+
+        let response = self
+            .client()?
+            .post(format!("http://{}/cgi-bin/upgrade.php", self.ip))
+            .header("Cookie", cookie)
+            .multipart(form)
+            .timeout(Duration::from_secs(300))
+            .send()
+            .await
+            .context("firmware upload HTTP request failed")?;
+
+        let status = response.status();
+        let body = response
+            .text()
+            .await
+            .context("failed to read firmware upload response body")?;
+        if !status.is_success() {
+            anyhow::bail!("Firmware upload failed with status code {status}: {body}");
+        }
+
+        let result: Option<Value> = serde_json::from_str(&body).ok();
+        match result
+            .as_ref()
+            .and_then(|v| v.get("stats"))
+            .and_then(Value::as_str)
+        {
+            Some("error") => anyhow::bail!(
+                "Firmware image was rejected by the miner: {}",
+                result
+                    .as_ref()
+                    .and_then(|v| v.get("msg"))
+                    .and_then(Value::as_str)
+                    .unwrap_or(&body)
+            ),
+            _ => Ok(()),
+        }
+    }
+
     pub async fn set_mining_mode(&self, mode: u32) -> anyhow::Result<Value> {
         self.send_command(
             "set_mining_mode",
@@ -8,6 +8,8 @@ use semver::Version;
 pub use v1_2_0::VnishV120;
 pub use v1_3_0::VnishV130;
 
+pub(crate) mod settings;
+pub(crate) mod upgrade;
 pub mod v1_2_0;
 pub mod v1_3_0;
 
@@ -0,0 +1,357 @@
+use anyhow::{anyhow, bail};
+use asic_rs_core::{
+    config::{fan::FanConfig, network::NetworkConfig, tuning::TuningConfig},
+    data::{
+        capabilities::{PresetTuningCapabilities, TuningCapabilities},
+        miner::TuningTarget,
+    },
+};
+use measurements::{Frequency, Voltage};
+use serde_json::{Value, json};
+
+/// `overclock.preset` value when the chips run at a manual frequency and voltage.
+const PRESET_DISABLED: &str = "disabled";
+
+/// Cooling mode names in `miner.cooling.mode.name`.
+const COOLING_AUTO: &str = "auto";
+const COOLING_MANUAL: &str = "manual";
+const COOLING_IMMERSION: &str = "immersion";
+
+/// `autotune/presets` answers with either a bare array or `{"presets": [...]}`.
+fn preset_list(presets: &Value) -> Option<&Vec<Value>> {
+    presets
+        .as_array()
+        .or_else(|| presets.get("presets").and_then(Value::as_array))
+}
+
+/// Preset names are the preset's power in watts, e.g. `"3495"`.
+fn preset_watts(name: &Value) -> Option<u64> {
+    name.as_u64()
+        .or_else(|| name.as_str().and_then(|s| s.trim().parse().ok()))
+}
+
+/// List the autotune presets as power targets, lowest first.
+pub(crate) fn parse_tuning_capabilities(presets: &Value) -> Option<TuningCapabilities> {
+    let mut watts: Vec<u64> = preset_list(presets)?
+        .iter()
+        .filter_map(|preset| preset_watts(preset.get("name")?))
+        .collect();
+    watts.sort_unstable();
+    watts.dedup();
+
+    (!watts.is_empty()).then(|| TuningCapabilities {
+        presets: Some(PresetTuningCapabilities {
+            default: None,
+            presets: watts
+                .into_iter()
+                .map(|w| TuningTarget::from_watts(w as f64))
+                .collect(),
+        }),
+        ..Default::default()
+    })
+}
+
+/// Express `miner.overclock` as a tuning config.
+///
+/// A preset maps to its power target. A manual overclock carries the global
+/// chip frequency and voltage, with the current power draw as target.
+pub(crate) fn parse_tuning_config(
+    overclock: &Value,
+    power: Option<f64>,
+) -> anyhow::Result<TuningConfig> {
+    let preset = overclock
+        .get("preset")
+        .ok_or_else(|| anyhow!("No preset in VNish overclock settings"))?;
+    if let Some(watts) = preset_watts(preset) {
+        return Ok(TuningConfig::new(TuningTarget::from_watts(watts as f64)));
+    }
+
+    let freq = overclock.pointer("/globals/freq").and_then(Value::as_f64);
+    let volt = overclock.pointer("/globals/volt").and_then(Value::as_f64);
+    match (freq, volt, power) {
+        (Some(freq), Some(volt), Some(power)) => {
+            Ok(TuningConfig::new(TuningTarget::from_watts(power))
+                .with_frequency(Frequency::from_megahertz(freq))
+                .with_voltage(Voltage::from_millivolts(volt)))
+        }
+        _ => bail!("No preset or manual overclock in VNish overclock settings"),
+    }
+}
+
+/// Apply a tuning config to a copy of `miner.overclock`.
+///
+/// A frequency or voltage disables presets and sets the global chip
+/// frequency and voltage; the power target is then ignored. Otherwise the
+/// power target must match one of the autotune presets exactly.
+pub(crate) fn tuning_config_overclock(
+    config: &TuningConfig,
+    overclock: &Value,
+    presets: &Value,
+) -> anyhow::Result<Value> {
+    let mut overclock = overclock.clone();
+
+    if config.frequency.is_some() || config.voltage.is_some() {
+        if let Some(frequency) = config.frequency {
+            let mhz = frequency.as_megahertz().round();
+            if mhz <= 0.0 {
+                bail!("Chip frequency must be positive");
+            }
+            overclock["globals"]["freq"] = json!(mhz as u64);
+        }
+        if let Some(voltage) = config.voltage {
+            let millivolts = voltage.as_millivolts().round();
+            if millivolts <= 0.0 {
+                bail!("Chip voltage must be positive");
+            }
+            overclock["globals"]["volt"] = json!(millivolts as u64);
+        }
+        overclock["preset"] = json!(PRESET_DISABLED);
+        return Ok(overclock);
+    }
+
+    let watts = match &config.target {
+        TuningTarget::Power(power) => power.as_watts().round() as u64,
+        TuningTarget::HashRate(_) => bail!("HashRate tuning target is not supported on VNish"),
+        TuningTarget::MiningMode(_) => bail!("MiningMode tuning target is not supported on VNish"),
+    };
+    let available: Vec<u64> = preset_list(presets)
+        .ok_or_else(|| anyhow!("Unexpected autotune/presets response"))?
+        .iter()
+        .filter_map(|preset| preset_watts(preset.get("name")?))
+        .collect();
+    if !available.contains(&watts) {
+        bail!("No VNish autotune preset for {watts} W, available presets: {available:?}");
+    }
+
+    overclock["preset"] = json!(watts.to_string());
+    Ok(overclock)
+}
+
+pub(crate) fn parse_fan_config(cooling: &Value) -> anyhow::Result<FanConfig> {
+    let mode = cooling
+        .pointer("/mode/name")
+        .and_then(Value::as_str)
+        .ok_or_else(|| anyhow!("No cooling mode in VNish settings"))?;
+    let param = cooling.pointer("/mode/param").and_then(Value::as_f64);
+
+    match mode {
+        COOLING_AUTO => Ok(FanConfig::auto(
+            param.ok_or_else(|| anyhow!("No target temperature in VNish auto cooling mode"))?,
+            cooling.get("fan_min_duty").and_then(Value::as_u64),
+        )),
+        COOLING_MANUAL => param
+            .map(|speed| FanConfig::manual(speed as u64))
+            .ok_or_else(|| anyhow!("No fan speed in VNish manual cooling mode")),
+        COOLING_IMMERSION => Ok(FanConfig::manual(0)),
+        mode => bail!("Unknown VNish cooling mode '{mode}'"),
+    }
+}
+
+/// Apply a fan config to a copy of `miner.cooling`.
+///
+/// A manual speed of 0 selects immersion mode, which stops the fans and skips
+/// the fan checks. The idle speed of an automatic config is the minimum duty;
+/// the maximum duty is left as configured.
+pub(crate) fn fan_config_cooling(config: &FanConfig, cooling: &Value) -> anyhow::Result<Value> {
+    let mut cooling = cooling.clone();
+    match config {
+        FanConfig::Auto {
+            target_temp,
+            idle_speed,
+            ..
+        } => {
+            let target_temp = target_temp
+                .ok_or_else(|| anyhow!("VNish automatic fan control needs a target temperature"))?;
+            cooling["mode"] = json!({ "name": COOLING_AUTO, "param": target_temp.round() as u64 });
+            if let Some(idle_speed) = idle_speed {
+                if *idle_speed > 100 {
+                    bail!("Fan speed must be a percentage, got {idle_speed}");
+                }
+                cooling["fan_min_duty"] = json!(idle_speed);
+            }
+        }
+        FanConfig::Manual { fan_speed: 0, .. } => {
+            cooling["mode"] = json!({ "name": COOLING_IMMERSION });
+        }
+        FanConfig::Manual { fan_speed, .. } => {
+            if *fan_speed > 100 {
+                bail!("Fan speed must be a percentage, got {fan_speed}");
+            }
+            cooling["mode"] = json!({ "name": COOLING_MANUAL, "param": fan_speed });
+        }
+    }
+    Ok(cooling)
+}
+
+pub(crate) fn parse_network_config(network: &Value) -> anyhow::Result<NetworkConfig> {
+    let dhcp = network
+        .get("dhcp")
+        .and_then(Value::as_bool)
+        .ok_or_else(|| anyhow!("No dhcp flag in VNish network settings"))?;
+    let addr = |key: &str| network.get(key)?.as_str()?.trim().parse().ok();
+
+    Ok(NetworkConfig {
+        dhcp,
+        ip: addr("ipaddress"),
+        netmask: addr("netmask"),
+        gateway: addr("gateway"),
+        dns: network
+            .get("dnsservers")
+            .and_then(Value::as_array)
+            .map(|servers| {
+                servers
+                    .iter()
+                    .filter_map(|s| s.as_str()?.trim().parse().ok())
+                    .collect::<Vec<_>>()
+            })
+            .filter(|dns| !dns.is_empty()),
+        hostname: network
+            .get("hostname")
+            .and_then(Value::as_str)
+            .map(String::from),
+    })
+}
+
+/// Apply a network config to a copy of the `network` settings.
+///
+/// With DHCP the static fields are left as configured, since VNish requires
+/// them to be present.
+pub(crate) fn network_config_settings(config: &NetworkConfig, network: &Value) -> Value {
+    let mut network = network.clone();
+    network["dhcp"] = json!(config.dhcp);
+    if !config.dhcp {
+        let addr = |addr: Option<std::net::IpAddr>| addr.map(|a| a.to_string()).unwrap_or_default();
+        network["ipaddress"] = json!(addr(config.ip));
+        network["netmask"] = json!(addr(config.netmask));
+        network["gateway"] = json!(addr(config.gateway));
+        network["dnsservers"] = json!(
+            config
+                .dns
+                .iter()
+                .flatten()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+        );
+    }
+    if let Some(hostname) = &config.hostname {
+        network["hostname"] = json!(hostname);
+    }
+    network
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn presets() -> Value {
+        json!([
+            { "name": "disabled", "pretty": "Disabled" },
+            { "name": "3495", "pretty": "3495 watt ~ 132 TH", "status": "tuned" },
+            { "name": "3100", "pretty": "3100 watt ~ 120 TH", "status": "untuned" },
+        ])
+    }
+
+    #[test]
+    fn presets_are_listed_as_power_targets() {
+        let capabilities = parse_tuning_capabilities(&presets())
+            .and_then(|c| c.presets)
+            .expect("presets");
+        assert_eq!(
+            capabilities.presets,
+            vec![
+                TuningTarget::from_watts(3100.0),
+                TuningTarget::from_watts(3495.0)
+            ]
+        );
+        assert!(parse_tuning_capabilities(&json!({ "presets": [] })).is_none());
+    }
+
+    #[test]
+    fn tuning_round_trip() -> anyhow::Result<()> {
+        let overclock = json!({ "preset": "3495", "globals": { "freq": 525, "volt": 1300 } });
+        assert_eq!(
+            parse_tuning_config(&overclock, None)?.target,
+            TuningTarget::from_watts(3495.0)
+        );
+
+        let preset = TuningConfig::new(TuningTarget::from_watts(3100.0));
+        let updated = tuning_config_overclock(&preset, &overclock, &presets())?;
+        assert_eq!(updated["preset"], "3100");
+        assert_eq!(updated["globals"]["freq"], 525);
+
+        let missing = TuningConfig::new(TuningTarget::from_watts(3000.0));
+        assert!(tuning_config_overclock(&missing, &overclock, &presets()).is_err());
+
+        let manual = TuningConfig::new(TuningTarget::from_watts(3300.0))
+            .with_frequency(Frequency::from_megahertz(650.0))
+            .with_voltage(Voltage::from_millivolts(1350.0));
+        let updated = tuning_config_overclock(&manual, &overclock, &presets())?;
+        assert_eq!(updated["preset"], PRESET_DISABLED);
+        assert_eq!(updated["globals"], json!({ "freq": 650, "volt": 1350 }));
+        let parsed = parse_tuning_config(&updated, Some(3300.0))?;
+        assert_eq!(parsed.target, manual.target);
+        assert_eq!(parsed.frequency, manual.frequency);
+        assert_eq!(parsed.voltage, manual.voltage);
+        assert_eq!(parsed.algorithm(), None);
+
+        let frequency_only = TuningConfig::new(TuningTarget::from_watts(3300.0))
+            .with_frequency(Frequency::from_megahertz(600.0));
+        let updated = tuning_config_overclock(&frequency_only, &overclock, &presets())?;
+        assert_eq!(updated["globals"], json!({ "freq": 600, "volt": 1300 }));
+        Ok(())
+    }
+
+    #[test]
+    fn fan_round_trip() -> anyhow::Result<()> {
+        let cooling = json!({
+            "fan_min_count": 4,
+            "fan_min_duty": 10,
+            "fan_max_duty": 100,
+            "mode": { "name": "auto", "param": 70 },
+        });
+        assert_eq!(parse_fan_config(&cooling)?, FanConfig::auto(70.0, Some(10)));
+
+        let immersion = fan_config_cooling(&FanConfig::manual(0), &cooling)?;
+        assert_eq!(immersion["mode"]["name"], COOLING_IMMERSION);
+        assert_eq!(parse_fan_config(&immersion)?, FanConfig::manual(0));
+
+        let manual = fan_config_cooling(&FanConfig::manual(60), &cooling)?;
+        assert_eq!(parse_fan_config(&manual)?, FanConfig::manual(60));
+        assert_eq!(manual["fan_max_duty"], 100);
+
+        assert!(fan_config_cooling(&FanConfig::manual(120), &cooling).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn network_round_trip() -> anyhow::Result<()> {
+        let network = json!({
+            "hostname": "vnish",
+            "dhcp": true,
+            "ipaddress": "10.0.0.20",
+            "netmask": "255.255.255.0",
+            "gateway": "10.0.0.1",
+            "dnsservers": ["10.0.0.1"],
+        });
+        let config = parse_network_config(&network)?;
+        assert!(config.dhcp);
+        assert_eq!(config.dns, Some(vec!["10.0.0.1".parse()?]));
+
+        let static_config = NetworkConfig::static_ip(
+            "10.0.0.20".parse()?,
+            "255.255.0.0".parse()?,
+            "10.0.0.254".parse()?,
+            vec!["1.1.1.1".parse()?, "8.8.8.8".parse()?],
+        );
+        let updated = network_config_settings(&static_config, &network);
+        assert_eq!(updated["dhcp"], false);
+        assert_eq!(updated["gateway"], "10.0.0.254");
+        assert_eq!(updated["dnsservers"], json!(["1.1.1.1", "8.8.8.8"]));
+        assert_eq!(updated["hostname"], "vnish");
+        assert_eq!(
+            parse_network_config(&updated)?,
+            static_config.with_hostname("vnish")
+        );
+        Ok(())
+    }
+}
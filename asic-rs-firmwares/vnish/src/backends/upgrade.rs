@@ -0,0 +1,170 @@
+use std::{future::Future, time::Duration};
+
+use anyhow::{Context, bail};
+use asic_rs_core::data::firmware::FirmwareImage;
+use reqwest::{StatusCode, multipart};
+use serde_json::Value;
+use tokio::time::{Instant, sleep};
+
+/// Seconds VNish waits before rebooting when the upload response omits `after`.
+const DEFAULT_REBOOT_AFTER: u64 = 3;
+
+/// Build the `firmware/update` form, keeping the current settings.
+pub(crate) fn upgrade_form(image: FirmwareImage) -> anyhow::Result<multipart::Form> {
+    let FirmwareImage { filename, bytes } = image;
+    Ok(multipart::Form::new().text("keep_settings", "true").part(
+        "file",
+        multipart::Part::bytes(bytes)
+            .file_name(filename)
+            .mime_str("application/octet-stream")
+            .context("failed to set firmware part mime type")?,
+    ))
+}
+
+/// Parse the `firmware/update` response into the number of seconds after
+/// which the miner reboots into the new image.
+pub(crate) fn parse_upgrade_response(status: StatusCode, body: &str) -> anyhow::Result<u64> {
+    let payload: Option<Value> = serde_json::from_str(body).ok();
+
+    if !status.is_success() {
+        let reason = payload
+            .as_ref()
+            .and_then(|v| v.get("err"))
+            .and_then(Value::as_str)
+            .unwrap_or(body);
+        bail!(
+            "Firmware upload failed with status code {}: {}",
+            status,
+            reason
+        );
+    }
+
+    Ok(payload
+        .as_ref()
+        .and_then(|v| v.get("after"))
+        .and_then(Value::as_u64)
+        .unwrap_or(DEFAULT_REBOOT_AFTER))
+}
+
+/// Polling schedule used while a miner reboots into a new image.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct RebootWait {
+    /// How often to check whether the miner has gone down.
+    pub down_poll: Duration,
+    /// How long the miner may keep answering after the scheduled reboot.
+    pub down_timeout: Duration,
+    /// How often to check whether the miner is back.
+    pub up_poll: Duration,
+    /// How long the miner may stay unreachable once it has gone down.
+    pub up_timeout: Duration,
+}
+
+impl RebootWait {
+    pub(crate) const DEFAULT: Self = Self {
+        down_poll: Duration::from_secs(2),
+        down_timeout: Duration::from_secs(2 * 60),
+        up_poll: Duration::from_secs(15),
+        up_timeout: Duration::from_secs(10 * 60),
+    };
+
+    /// Wait for a reboot scheduled `after` seconds from now: first until
+    /// `reachable` reports the miner gone, then until it reports it back.
+    pub(crate) async fn wait<P, F>(&self, after: u64, mut reachable: P) -> anyhow::Result<()>
+    where
+        P: FnMut() -> F,
+        F: Future<Output = bool>,
+    {
+        sleep(Duration::from_secs(after)).await;
+
+        let deadline = Instant::now() + self.down_timeout;
+        loop {
+            if !reachable().await {
+                break;
+            }
+            if Instant::now() >= deadline {
+                bail!(
+                    "Miner did not reboot within {} seconds of the firmware upgrade",
+                    after + self.down_timeout.as_secs()
+                );
+            }
+            sleep(self.down_poll).await;
+        }
+
+        let deadline = Instant::now() + self.up_timeout;
+        while Instant::now() < deadline {
+            sleep(self.up_poll).await;
+            if reachable().await {
+                return Ok(());
+            }
+        }
+
+        bail!(
+            "Miner did not come back online within {} seconds of the firmware upgrade",
+            self.up_timeout.as_secs()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+
+    const FAST: RebootWait = RebootWait {
+        down_poll: Duration::from_millis(1),
+        down_timeout: Duration::from_millis(50),
+        up_poll: Duration::from_millis(1),
+        up_timeout: Duration::from_millis(50),
+    };
+
+    #[test]
+    fn upgrade_response_reports_reboot_delay() {
+        assert_eq!(
+            parse_upgrade_response(StatusCode::OK, r#"{"after": 10}"#).unwrap(),
+            10
+        );
+        assert_eq!(
+            parse_upgrade_response(StatusCode::OK, "").unwrap(),
+            DEFAULT_REBOOT_AFTER
+        );
+    }
+
+    #[test]
+    fn upgrade_response_error_uses_firmware_reason() {
+        let err =
+            parse_upgrade_response(StatusCode::BAD_REQUEST, r#"{"err": "bad image"}"#).unwrap_err();
+        assert!(err.to_string().contains("bad image"));
+    }
+
+    #[tokio::test]
+    async fn reboot_waits_for_miner_to_go_down_and_come_back() {
+        // up, up, down, down, up
+        let replies = [true, true, false, false, true];
+        let calls = Cell::new(0);
+        let result = FAST
+            .wait(0, || {
+                let i = calls.get();
+                calls.set(i + 1);
+                async move { replies[i.min(replies.len() - 1)] }
+            })
+            .await;
+
+        assert!(result.is_ok());
+        assert_eq!(calls.get(), replies.len());
+    }
+
+    #[tokio::test]
+    async fn reboot_that_never_happens_is_an_error() {
+        let result = FAST.wait(0, || async { true }).await;
+
+        assert!(result.unwrap_err().to_string().contains("did not reboot"));
+    }
+
+    #[tokio::test]
+    async fn miner_that_stays_down_is_an_error() {
+        let result = FAST.wait(0, || async { false }).await;
+
+        assert!(result.unwrap_err().to_string().contains("come back online"));
+    }
+}
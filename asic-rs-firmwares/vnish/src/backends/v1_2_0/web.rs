@@ -2,13 +2,18 @@ use std::{net::IpAddr, time::Duration};
 
 use once_cell::sync::OnceCell;
 
-use anyhow;
-use asic_rs_core::{data::command::MinerCommand, traits::miner::*};
+use anyhow::{self, Context};
+use asic_rs_core::{
+    data::{command::MinerCommand, firmware::FirmwareImage},
+    traits::miner::*,
+};
 use async_trait::async_trait;
 use reqwest::{Client, Method, Response};
 use serde_json::{Value, json};
 use tokio::sync::RwLock;
 
+use crate::backends::upgrade::{RebootWait, parse_upgrade_response, upgrade_form};
+
 /// VNish WebAPI client
 #[derive(Debug)]
 pub struct VnishWebAPI {
@@ -128,6 +133,8 @@ impl VnishWebAPI {
             MinerAuth::UserAndPass(creds) => {
                 self.authenticate(creds.password.expose_secret()).await?
             }
+            // Private keys are only accepted over SSH.
+            MinerAuth::KeyAuth(_) => return Err(VnishError::AuthenticationFailed),
         };
         *self.bearer_token.write().await = Some(token);
         Ok(())
@@ -276,6 +283,52 @@ impl VnishWebAPI {
             .map(|_| true)
     }
 
+    /// Upload a firmware image to `firmware/update`, keeping the current settings.
+    ///
+    /// Returns the number of seconds after which the miner reboots into the new image.
+    pub async fn upgrade_firmware(&self, image: FirmwareImage) -> anyhow::Result<u64> {
+        self.ensure_authenticated().await?;
+
+        let url = format!("http://{}:{}/api/v1/firmware/update", self.ip, self.port);
+        let mut request = self
+            .client()?
+            .post(url)
+            .multipart(upgrade_form(image)?)
+            .timeout(self.timeout.max(Duration::from_secs(300)));
+        if let Some(ref token) = *self.bearer_token.read().await {
+            request = request.header("Authorization", format!("Bearer {token}"));
+        }
+
+        let response = request
+            .send()
+            .await
+            .context("firmware upload HTTP request failed")?;
+        let status = response.status();
+        let body = response
+            .text()
+            .await
+            .context("failed to read firmware upload response body")?;
+
+        parse_upgrade_response(status, &body)
+    }
+
+    /// Wait for the miner to go down for a reboot scheduled `after` seconds
+    /// from now and come back, then log back in with the configured credentials.
+    pub async fn wait_for_reboot(&self, after: u64) -> anyhow::Result<()> {
+        *self.bearer_token.write().await = None;
+        RebootWait::DEFAULT
+            .wait(after, || async {
+                self.send_command("info", false, None, Method::GET)
+                    .await
+                    .is_ok()
+            })
+            .await?;
+
+        *self.bearer_token.write().await = None;
+        self.ensure_authenticated().await?;
+        Ok(())
+    }
+
     async fn read_log(&self, log_type: &str) -> anyhow::Result<String> {
         self.ensure_authenticated().await?;
 
@@ -4,21 +4,31 @@ use anyhow;
 use asic_rs_core::{
     config::{
         collector::{ConfigCollector, ConfigExtractor, ConfigField, ConfigLocation},
+        fan::FanConfig,
+        network::NetworkConfig,
         pools::PoolGroupConfig,
+        scaling::ScalingConfig,
         temperature::TemperatureConfig,
+        tuning::TuningConfig,
     },
     data::{
         board::{BoardData, ChipData, MinerControlBoard},
+        capabilities::TuningCapabilities,
         collector::{
             DataCollector, DataExtensions, DataExtractor, DataField, DataLocation, get_by_pointer,
         },
         command::MinerCommand,
+        control_board::ControlBoardData,
         device::{DeviceInfo, HashAlgorithm},
         fan::FanData,
+        firmware::FirmwareImage,
         hashrate::{HashRate, HashRateUnit},
         message::{MessageSeverity, MinerMessage},
+        network::{NetworkData, SYSFS_LINK_STATS_COMMAND},
         pool::{PoolData, PoolGroupData, PoolURL},
+        psu::PsuData,
     },
+    ssh::SSHClient,
     traits::{miner::*, model::MinerModel},
 };
 use asic_rs_makes_antminer::hardware::AntMinerControlBoard;
@@ -28,7 +38,7 @@ use measurements::{AngularVelocity, Frequency, Power, Temperature, Voltage};
 use serde_json::{Value, json};
 use web::VnishWebAPI;
 
-use crate::firmware::VnishFirmware;
+use crate::{backends::settings, firmware::VnishFirmware};
 
 mod web;
 
@@ -36,7 +46,9 @@ mod web;
 pub struct VnishV130 {
     ip: IpAddr,
     web: VnishWebAPI,
+    ssh: SSHClient,
     device_info: DeviceInfo,
+    ssh_auth_set: bool,
 }
 
 impl VnishV130 {
@@ -45,7 +57,10 @@ impl VnishV130 {
         VnishV130 {
             ip,
             web: VnishWebAPI::new(ip, 80, auth),
+            // SSH logs in as the system root user, not the web UI account.
+            ssh: SSHClient::new(ip, MinerAuth::new("root", "admin")),
             device_info: DeviceInfo::new(model, VnishFirmware::default(), HashAlgorithm::SHA256),
+            ssh_auth_set: false,
         }
     }
 }
@@ -55,6 +70,7 @@ impl APIClient for VnishV130 {
     async fn get_api_result(&self, command: &MinerCommand) -> anyhow::Result<Value> {
         match command {
             MinerCommand::WebAPI { .. } => self.web.get_api_result(command).await,
+            MinerCommand::SSH { .. } => self.ssh.get_api_result(command).await,
             _ => Err(anyhow::anyhow!("Unsupported command type for Vnish API")),
         }
     }
@@ -66,6 +82,10 @@ impl GetConfigsLocations for VnishV130 {
             command: "summary",
             parameters: None,
         };
+        const WEB_SETTINGS: MinerCommand = MinerCommand::WebAPI {
+            command: "settings",
+            parameters: None,
+        };
         match data_field {
             ConfigField::Temperature => vec![(
                 WEB_SUMMARY,
@@ -75,6 +95,40 @@ impl GetConfigsLocations for VnishV130 {
                     tag: None,
                 },
             )],
+            ConfigField::Tuning => vec![
+                (
+                    WEB_SETTINGS,
+                    ConfigExtractor {
+                        func: get_by_pointer,
+                        key: Some("/miner/overclock"),
+                        tag: Some("overclock"),
+                    },
+                ),
+                (
+                    WEB_SUMMARY,
+                    ConfigExtractor {
+                        func: get_by_pointer,
+                        key: Some("/miner/power_consumption"),
+                        tag: Some("power"),
+                    },
+                ),
+            ],
+            ConfigField::Fan => vec![(
+                WEB_SETTINGS,
+                ConfigExtractor {
+                    func: get_by_pointer,
+                    key: Some("/miner/cooling"),
+                    tag: None,
+                },
+            )],
+            ConfigField::Network => vec![(
+                WEB_SETTINGS,
+                ConfigExtractor {
+                    func: get_by_pointer,
+                    key: Some("/network"),
+                    tag: None,
+                },
+            )],
             _ => vec![],
         }
     }
@@ -108,6 +162,9 @@ impl GetDataLocations for VnishV130 {
             command: "chains/factory-info",
             parameters: None,
         };
+        const SSH_LINK_STATS: MinerCommand = MinerCommand::SSH {
+            command: SYSFS_LINK_STATS_COMMAND,
+        };
 
         match data_field {
             DataField::Mac => vec![(
@@ -136,6 +193,25 @@ impl GetDataLocations for VnishV130 {
                     },
                 ),
             ],
+            DataField::Psus => vec![(
+                WEB_FACTORY_INFO,
+                DataExtractor {
+                    func: get_by_pointer,
+                    key: Some("/psu_serial"),
+                    tag: None,
+                },
+            )],
+            // Link stats are only read over SSH, so they are opt-in: a
+            // filtered port 22 would otherwise stall every poll.
+            DataField::Network if !self.ssh_auth_set => vec![],
+            DataField::Network => vec![(
+                SSH_LINK_STATS,
+                DataExtractor {
+                    func: get_by_pointer,
+                    key: Some(""),
+                    tag: None,
+                },
+            )],
             DataField::Hostname => vec![(
                 WEB_INFO,
                 DataExtractor {
@@ -176,6 +252,14 @@ impl GetDataLocations for VnishV130 {
                     tag: None,
                 },
             )],
+            DataField::ControlBoard => vec![(
+                WEB_INFO,
+                DataExtractor {
+                    func: get_by_pointer,
+                    key: Some("/system"),
+                    tag: None,
+                },
+            )],
             DataField::Hashrate => vec![(
                 WEB_SUMMARY,
                 DataExtractor {
@@ -260,6 +344,17 @@ impl GetDataLocations for VnishV130 {
                     tag: None,
                 },
             )],
+            DataField::TuningCapabilities => vec![(
+                MinerCommand::WebAPI {
+                    command: "autotune/presets",
+                    parameters: None,
+                },
+                DataExtractor {
+                    func: get_by_pointer,
+                    key: Some(""),
+                    tag: None,
+                },
+            )],
             DataField::LightFlashing => vec![(
                 WEB_STATUS,
                 DataExtractor {
@@ -342,6 +437,13 @@ impl GetHostname for VnishV130 {
     }
 }
 
+impl GetNetwork for VnishV130 {
+    fn parse_network(&self, data: &HashMap<DataField, Value>) -> Option<NetworkData> {
+        data.extract::<String>(DataField::Network)
+            .map(|link_stats| NetworkData::default().with_sysfs_link_stats(&link_stats))
+    }
+}
+
 impl GetApiVersion for VnishV130 {
     fn parse_api_version(&self, data: &HashMap<DataField, Value>) -> Option<String> {
         data.extract::<String>(DataField::ApiVersion)
@@ -364,6 +466,24 @@ impl GetControlBoardVersion for VnishV130 {
     }
 }
 
+impl GetControlBoard for VnishV130 {
+    fn parse_control_board(&self, data: &HashMap<DataField, Value>) -> Option<ControlBoardData> {
+        let system = data.get(&DataField::ControlBoard)?;
+        // Memory is reported in kB.
+        let memory = |key: &str| system.get(key).and_then(Value::as_u64).map(|kb| kb * 1024);
+        let memory_total = memory("mem_total");
+        let control_board = ControlBoardData {
+            memory_total,
+            memory_used: memory_total
+                .zip(memory("mem_free"))
+                .map(|(total, free)| total.saturating_sub(free)),
+            os_version: system.get("os").and_then(Value::as_str).map(String::from),
+            ..Default::default()
+        };
+        (!control_board.is_empty()).then_some(control_board)
+    }
+}
+
 impl GetHashboards for VnishV130 {
     fn parse_hashboards(&self, data: &HashMap<DataField, Value>) -> Vec<BoardData> {
         let Some(all_chains) = data.get(&DataField::Hashboards).and_then(|v| v.as_array()) else {
@@ -574,6 +694,20 @@ impl GetFans for VnishV130 {
 
 impl GetPsuFans for VnishV130 {}
 
+impl GetPsus for VnishV130 {
+    fn parse_psus(&self, data: &HashMap<DataField, Value>) -> Vec<PsuData> {
+        // Factory info only carries the PSU serial number.
+        data.extract::<String>(DataField::Psus)
+            .filter(|serial| !serial.is_empty())
+            .map(|serial| PsuData {
+                serial_number: Some(serial),
+                ..PsuData::new(0)
+            })
+            .into_iter()
+            .collect()
+    }
+}
+
 impl GetFluidTemperature for VnishV130 {
     fn parse_fluid_temperature(&self, data: &HashMap<DataField, Value>) -> Option<Temperature> {
         // Fluid temperature mirrors other firmwares' "environment temperature":
@@ -621,7 +755,15 @@ impl GetTuningPercent for VnishV130 {
 impl GetTuningTarget for VnishV130 {}
 
 impl GetScaledTuningTarget for VnishV130 {}
-impl GetTuningCapabilities for VnishV130 {}
+impl GetTuningCapabilities for VnishV130 {
+    fn parse_tuning_capabilities(
+        &self,
+        data: &HashMap<DataField, Value>,
+    ) -> Option<TuningCapabilities> {
+        data.get(&DataField::TuningCapabilities)
+            .and_then(settings::parse_tuning_capabilities)
+    }
+}
 impl GetLightFlashing for VnishV130 {
     fn parse_light_flashing(&self, data: &HashMap<DataField, Value>) -> Option<bool> {
         data.extract::<bool>(DataField::LightFlashing)
@@ -873,6 +1015,35 @@ impl SetTuningPercent for VnishV130 {
     }
 }
 
+impl GetApplianceConfig for VnishV130 {}
+impl SetApplianceConfig for VnishV130 {}
+#[async_trait]
+impl SupportsNetworkConfig for VnishV130 {
+    async fn apply_network_config(&self, config: NetworkConfig) -> anyhow::Result<bool> {
+        let current = self.web.settings().await?;
+        let network = current
+            .get("network")
+            .ok_or_else(|| anyhow::anyhow!("settings missing network"))?;
+        let network = settings::network_config_settings(&config, network);
+
+        self.web.set_settings(json!({ "network": network })).await?;
+        Ok(true)
+    }
+
+    fn parse_network_config(
+        &self,
+        data: &HashMap<ConfigField, Value>,
+    ) -> anyhow::Result<NetworkConfig> {
+        data.get(&ConfigField::Network)
+            .ok_or_else(|| anyhow::anyhow!("No network settings returned by miner"))
+            .and_then(settings::parse_network_config)
+    }
+
+    fn supports_network_config(&self) -> bool {
+        true
+    }
+}
+
 #[async_trait]
 impl SupportsPoolsConfig for VnishV130 {
     async fn get_pools_config(&self) -> anyhow::Result<Vec<PoolGroupConfig>> {
@@ -974,6 +1145,8 @@ impl ReadLogs for VnishV130 {
     }
 }
 
+impl SetSyslogTarget for VnishV130 {}
+
 #[async_trait]
 impl FactoryReset for VnishV130 {
     async fn factory_reset(&self) -> anyhow::Result<bool> {
@@ -1021,8 +1194,14 @@ impl SupportsTemperatureConfig for VnishV130 {
 
 #[async_trait]
 impl UpgradeFirmware for VnishV130 {
+    async fn upgrade_firmware(&self, image: FirmwareImage) -> anyhow::Result<bool> {
+        let reboot_after = self.web.upgrade_firmware(image).await?;
+        self.web.wait_for_reboot(reboot_after).await?;
+        Ok(true)
+    }
+
     fn supports_upgrade_firmware(&self) -> bool {
-        false
+        true
     }
 }
 
@@ -1036,18 +1215,81 @@ impl HasAuth for VnishV130 {
     fn set_auth(&mut self, auth: MinerAuth) {
         self.web.set_auth(auth);
     }
+
+    /// Also enables reading the link stats in [`DataField::Network`], which
+    /// are only available over SSH.
+    fn set_ssh_auth(&mut self, auth: MinerAuth) {
+        self.ssh.set_auth(auth);
+        self.ssh_auth_set = true;
+    }
 }
 
 #[async_trait]
 impl SupportsTuningConfig for VnishV130 {
     fn supports_tuning_config(&self) -> bool {
-        false
+        true
+    }
+
+    /// Select an autotune preset by its power, or run a manual overclock given
+    /// as an algorithm such as `"650MHz@1300mV"`.
+    async fn set_tuning_config(
+        &self,
+        config: TuningConfig,
+        _scaling_config: Option<ScalingConfig>,
+    ) -> anyhow::Result<bool> {
+        let current = self.web.settings().await?;
+        let overclock = current
+            .pointer("/miner/overclock")
+            .ok_or_else(|| anyhow::anyhow!("settings missing miner.overclock"))?;
+        let presets = match config.algorithm() {
+            Some(_) => Value::Null,
+            None => self.web.autotune_presets().await?,
+        };
+        let overclock = settings::tuning_config_overclock(&config, overclock, &presets)?;
+
+        self.web
+            .set_settings(json!({ "miner": { "overclock": overclock } }))
+            .await?;
+        Ok(true)
+    }
+
+    fn parse_tuning_config(
+        &self,
+        data: &HashMap<ConfigField, Value>,
+    ) -> anyhow::Result<TuningConfig> {
+        let tuning = data
+            .get(&ConfigField::Tuning)
+            .ok_or_else(|| anyhow::anyhow!("No overclock settings returned by miner"))?;
+        let overclock = tuning
+            .get("overclock")
+            .ok_or_else(|| anyhow::anyhow!("settings missing miner.overclock"))?;
+        settings::parse_tuning_config(overclock, tuning.get("power").and_then(Value::as_f64))
     }
 }
 
 #[async_trait]
 impl SupportsFanConfig for VnishV130 {
     fn supports_fan_config(&self) -> bool {
-        false
+        true
+    }
+
+    async fn set_fan_config(&self, config: FanConfig) -> anyhow::Result<bool> {
+        config.ensure_no_min_fans()?;
+        let current = self.web.settings().await?;
+        let cooling = current
+            .pointer("/miner/cooling")
+            .ok_or_else(|| anyhow::anyhow!("settings missing miner.cooling"))?;
+        let cooling = settings::fan_config_cooling(&config, cooling)?;
+
+        self.web
+            .set_settings(json!({ "miner": { "cooling": cooling } }))
+            .await?;
+        Ok(true)
+    }
+
+    fn parse_fan_config(&self, data: &HashMap<ConfigField, Value>) -> anyhow::Result<FanConfig> {
+        data.get(&ConfigField::Fan)
+            .ok_or_else(|| anyhow::anyhow!("No cooling settings returned by miner"))
+            .and_then(settings::parse_fan_config)
     }
 }
@@ -0,0 +1,119 @@
+use aes::{
+    Aes256,
+    cipher::{BlockDecryptMut, BlockEncryptMut, KeyInit},
+};
+use base64::prelude::*;
+use ecb::cipher::block_padding::ZeroPadding;
+use md5crypt::md5crypt;
+use sha2::{Digest, Sha256};
+
+/// Client name and magic used by `open_write_api` to enable write commands.
+pub(crate) const UNLOCK_CLIENT: &str = "heatcore";
+const UNLOCK_MAGIC: &str = "3804fe31981418ce711a31d94bc69651";
+
+type Aes256EcbDec = ecb::Decryptor<Aes256>;
+type Aes256EcbEnc = ecb::Encryptor<Aes256>;
+
+/// Keys derived from a `get_token` response, used to sign and encrypt
+/// privileged commands on the btminer 1.x/2.x API.
+pub(crate) struct TokenData {
+    pub host_password_md5: String,
+    pub host_sign: String,
+}
+
+impl TokenData {
+    pub fn derive(password: &str, salt: &str, new_salt: &str, time: &str) -> anyhow::Result<Self> {
+        let host_password_md5 = md5crypt_hash(password, salt)?;
+        let host_sign = md5crypt_hash(&format!("{host_password_md5}{time}"), new_salt)?;
+
+        Ok(Self {
+            host_password_md5,
+            host_sign,
+        })
+    }
+}
+
+/// Hash `password` with md5crypt and return only the hash segment of the
+/// `$1$salt$hash` output.
+pub(crate) fn md5crypt_hash(password: &str, salt: &str) -> anyhow::Result<String> {
+    let crypted = md5crypt(password.as_bytes(), salt.as_bytes());
+    String::from_utf8_lossy(&crypted)
+        .split('$')
+        .nth(3)
+        .map(ToOwned::to_owned)
+        .ok_or_else(|| anyhow::anyhow!("Failed to extract md5crypt hash"))
+}
+
+/// Token answering an `open_write_api` challenge.
+pub(crate) fn write_api_unlock_token(
+    password: &str,
+    salt: &str,
+    new_salt: &str,
+    time: &str,
+) -> anyhow::Result<String> {
+    let pwd_md5 = md5crypt_hash(password, salt)?;
+    let token_data = format!("{time}{new_salt}{UNLOCK_MAGIC}{pwd_md5}");
+    Ok(format!("{:x}", md5::compute(token_data.as_bytes())))
+}
+
+fn sha256_key(key: &str) -> Vec<u8> {
+    Sha256::digest(key.as_bytes()).to_vec()
+}
+
+/// AES-256-ECB encrypt `data` with a raw key, zero padded and base64 encoded.
+pub(crate) fn aes_ecb_encrypt(aes_key: &[u8], data: &str) -> anyhow::Result<String> {
+    let original_len = data.len();
+    let padded_len = (original_len + 15) & !15;
+    let mut buffer = data.as_bytes().to_vec();
+    buffer.resize(padded_len, 0);
+
+    let enc = Aes256EcbEnc::new_from_slice(aes_key)
+        .map_err(|e| anyhow::anyhow!("invalid AES-256 key length: {e:?}"))?
+        .encrypt_padded_mut::<ZeroPadding>(&mut buffer, original_len)
+        .map_err(|e| anyhow::anyhow!("AES encryption failed: {e:?}"))?;
+
+    Ok(BASE64_STANDARD.encode(enc).replace('\n', ""))
+}
+
+/// AES-256-ECB encrypt `data` with the SHA-256 of `key`.
+pub(crate) fn aes_ecb_enc(key: &str, data: &str) -> anyhow::Result<String> {
+    aes_ecb_encrypt(&sha256_key(key), data)
+}
+
+/// Reverse of [`aes_ecb_enc`].
+pub(crate) fn aes_ecb_dec(key: &str, data: &str) -> anyhow::Result<String> {
+    let aes_key = sha256_key(key);
+    let b64_dec = &mut BASE64_STANDARD.decode(data)?[..];
+
+    let dec = Aes256EcbDec::new_from_slice(&aes_key)
+        .map_err(|e| anyhow::anyhow!("invalid AES-256 key length: {e:?}"))?
+        .decrypt_padded_mut::<ZeroPadding>(b64_dec)
+        .map_err(|e| anyhow::anyhow!("AES decryption failed: {e:?}"))?;
+
+    Ok(String::from_utf8_lossy(dec).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aes_round_trip() -> anyhow::Result<()> {
+        let message = r#"{"command":"reboot","token":"abcdef"}"#;
+        let encrypted = aes_ecb_enc("key", message)?;
+
+        assert_ne!(encrypted, message);
+        assert_eq!(aes_ecb_dec("key", &encrypted)?, message);
+        Ok(())
+    }
+
+    #[test]
+    fn md5crypt_hash_strips_salt() -> anyhow::Result<()> {
+        let hash = md5crypt_hash("admin", "BQ5hoXV9")?;
+
+        assert!(!hash.is_empty());
+        assert!(!hash.contains('$'));
+        assert!(!hash.contains("BQ5hoXV9"));
+        Ok(())
+    }
+}
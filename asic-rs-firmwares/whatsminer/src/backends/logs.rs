@@ -0,0 +1,178 @@
+use std::{fmt::Write as _, io::Read, time::Duration};
+
+use flate2::read::GzDecoder;
+use serde_json::Value;
+
+/// Log archives can be several megabytes, well beyond what fits in the
+/// regular RPC timeout.
+pub(crate) const LOG_DOWNLOAD_TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Largest log archive accepted, well above the few megabytes BTMiner sends,
+/// so a bogus `logfilelen` cannot make us allocate arbitrary amounts.
+pub(crate) const MAX_LOG_FILE_LEN: usize = 64 * 1024 * 1024;
+
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+const TAR_BLOCK: usize = 512;
+
+/// Split a `download_logs` response into its JSON status and the offset at
+/// which the file data starts. Returns `None` until the status is complete.
+pub(crate) fn split_log_header(buf: &[u8]) -> Option<(Value, usize)> {
+    let mut stream = serde_json::Deserializer::from_slice(buf).into_iter::<Value>();
+    let header = stream.next()?.ok()?;
+    let mut offset = stream.byte_offset();
+    while matches!(buf.get(offset), Some(b'\n' | b'\0')) {
+        offset += 1;
+    }
+    Some((header, offset))
+}
+
+/// Read `logfilelen` from a log download status, which firmware reports
+/// either as a string or a number, rejecting lengths above
+/// [`MAX_LOG_FILE_LEN`].
+pub(crate) fn log_file_len(msg: &Value) -> anyhow::Result<usize> {
+    let len = msg
+        .get("logfilelen")
+        .ok_or_else(|| anyhow::anyhow!("Missing logfilelen in log download response"))?;
+    let len = len
+        .as_u64()
+        .or_else(|| len.as_str().and_then(|s| s.trim().parse().ok()))
+        .ok_or_else(|| anyhow::anyhow!("Invalid logfilelen in log download response: {len}"))?;
+    usize::try_from(len)
+        .ok()
+        .filter(|len| *len <= MAX_LOG_FILE_LEN)
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "Log file of {len} bytes exceeds the {MAX_LOG_FILE_LEN} byte download limit"
+            )
+        })
+}
+
+/// Take the log file out of a `download_logs` buffer, failing if the
+/// connection closed before all of it arrived.
+pub(crate) fn log_file_data(buf: &[u8], offset: usize, len: usize) -> anyhow::Result<Vec<u8>> {
+    buf.get(offset..offset + len)
+        .map(<[u8]>::to_vec)
+        .ok_or_else(|| {
+            anyhow::anyhow!(
+                "Log download ended after {} of {len} bytes",
+                buf.len().saturating_sub(offset)
+            )
+        })
+}
+
+fn tar_field(field: &[u8]) -> &str {
+    let end = field.iter().position(|b| *b == 0).unwrap_or(field.len());
+    std::str::from_utf8(&field[..end]).unwrap_or_default()
+}
+
+/// Convert a downloaded log file to text.
+///
+/// BTMiner sends its log directory as a gzipped tarball; every regular file
+/// in it is written out under a `==> name <==` header. Anything that is not
+/// a tarball is returned as-is.
+pub(crate) fn log_archive_to_text(data: &[u8]) -> anyhow::Result<String> {
+    let data = if data.starts_with(&GZIP_MAGIC) {
+        let mut decoded = Vec::new();
+        GzDecoder::new(data).read_to_end(&mut decoded)?;
+        decoded
+    } else {
+        data.to_vec()
+    };
+
+    if data.get(257..262) != Some(b"ustar".as_slice()) {
+        return Ok(String::from_utf8_lossy(&data).into_owned());
+    }
+
+    let mut text = String::new();
+    let mut offset = 0;
+    while let Some(header) = data.get(offset..offset + TAR_BLOCK) {
+        if header.iter().all(|b| *b == 0) {
+            break;
+        }
+        let name = tar_field(&header[0..100]);
+        let size = usize::from_str_radix(tar_field(&header[124..136]).trim(), 8)
+            .map_err(|_| anyhow::anyhow!("Invalid size for {name} in log archive"))?;
+        let is_file = matches!(header[156], b'0' | 0);
+
+        offset += TAR_BLOCK;
+        if is_file {
+            let end = (offset + size).min(data.len());
+            writeln!(text, "==> {name} <==")?;
+            text.push_str(&String::from_utf8_lossy(&data[offset..end]));
+            if !text.ends_with('\n') {
+                text.push('\n');
+            }
+        }
+        offset += size.div_ceil(TAR_BLOCK) * TAR_BLOCK;
+    }
+
+    Ok(text)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    fn tar_entry(name: &str, content: &str) -> Vec<u8> {
+        let mut header = vec![0u8; TAR_BLOCK];
+        header[..name.len()].copy_from_slice(name.as_bytes());
+        let size = format!("{:011o}", content.len());
+        header[124..135].copy_from_slice(size.as_bytes());
+        header[156] = b'0';
+        header[257..262].copy_from_slice(b"ustar");
+
+        let mut entry = header;
+        entry.extend_from_slice(content.as_bytes());
+        entry.resize(TAR_BLOCK + content.len().div_ceil(TAR_BLOCK) * TAR_BLOCK, 0);
+        entry
+    }
+
+    #[test]
+    fn log_archive_to_text_reads_tar_entries() -> anyhow::Result<()> {
+        let mut archive = tar_entry("miner.log", "hashing\n");
+        archive.extend(tar_entry("power.log", "psu ok"));
+        archive.extend(vec![0u8; TAR_BLOCK * 2]);
+
+        let text = log_archive_to_text(&archive)?;
+
+        assert_eq!(
+            text,
+            "==> miner.log <==\nhashing\n==> power.log <==\npsu ok\n"
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn split_log_header_waits_for_complete_status() {
+        let response = br#"{"STATUS":"S","Msg":{"logfilelen":"4"}}"#;
+
+        assert!(split_log_header(&response[..10]).is_none());
+
+        let mut buf = response.to_vec();
+        buf.extend_from_slice(b"\nDATA");
+        let (header, offset) = split_log_header(&buf).unwrap();
+
+        assert_eq!(header["Msg"], json!({"logfilelen": "4"}));
+        assert_eq!(&buf[offset..], b"DATA");
+        assert_eq!(log_file_len(&header["Msg"]).unwrap(), 4);
+        assert_eq!(log_file_data(&buf, offset, 4).unwrap(), b"DATA");
+    }
+
+    #[test]
+    fn oversized_log_file_is_rejected() {
+        let len = MAX_LOG_FILE_LEN + 1;
+
+        assert!(log_file_len(&json!({"logfilelen": len})).is_err());
+        assert!(log_file_len(&json!({"logfilelen": len.to_string()})).is_err());
+        assert!(log_file_len(&json!({"logfilelen": MAX_LOG_FILE_LEN})).is_ok());
+    }
+
+    #[test]
+    fn truncated_log_file_is_an_error() {
+        let err = log_file_data(b"{}DAT", 2, 4).unwrap_err();
+
+        assert!(err.to_string().contains("3 of 4 bytes"));
+    }
+}
@@ -9,6 +9,9 @@ pub use v1::WhatsMinerV1;
 pub use v2::WhatsMinerV2;
 pub use v3::WhatsMinerV3;
 
+pub(crate) mod crypto;
+pub(crate) mod logs;
+pub(crate) mod psu;
 pub mod v1;
 pub mod v2;
 pub mod v3;
@@ -0,0 +1,85 @@
+use asic_rs_core::data::psu::PsuData;
+use measurements::{AngularVelocity, Current, Temperature, Voltage};
+use serde_json::Value;
+
+/// Read a field that firmware reports either as a string or a number.
+fn number(psu: &Value, keys: &[&str]) -> Option<f64> {
+    keys.iter().find_map(|key| {
+        let value = psu.get(*key)?;
+        value
+            .as_f64()
+            .or_else(|| value.as_str().and_then(|s| s.trim().parse().ok()))
+    })
+}
+
+fn text(psu: &Value, keys: &[&str]) -> Option<String> {
+    keys.iter()
+        .find_map(|key| psu.get(*key)?.as_str())
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(String::from)
+}
+
+/// Parse the PSU object from `get_psu` (`Msg`) or `get.device.info`
+/// (`msg/power`). V1/V2 use snake_case keys, V3 drops the underscores.
+///
+/// Input voltage, input current and output voltage are reported in
+/// hundredths of a volt or ampere.
+pub(crate) fn parse_psu(psu: &Value) -> Option<PsuData> {
+    psu.as_object()?;
+
+    let mut psu_data = PsuData::new(0);
+    psu_data.model = text(psu, &["model", "name", "type"]);
+    psu_data.serial_number = text(psu, &["serial_no", "sn"]);
+    psu_data.firmware_version = text(psu, &["sw_version", "swversion"]);
+    psu_data.input_voltage = number(psu, &["vin"]).map(|v| Voltage::from_volts(v / 100.0));
+    psu_data.input_current = number(psu, &["iin"]).map(|i| Current::from_amperes(i / 100.0));
+    psu_data.output_voltage = number(psu, &["vout"]).map(|v| Voltage::from_volts(v / 100.0));
+    psu_data.temperature = number(psu, &["temp0", "temp"]).map(Temperature::from_celsius);
+    psu_data.fan_rpm = number(psu, &["fan_speed", "fanspeed"]).map(AngularVelocity::from_rpm);
+    Some(psu_data)
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn parse_psu_test() {
+        let v2 = json!({
+            "name": "P221B",
+            "model": "P221B",
+            "sw_version": "230224.220429",
+            "hw_version": "",
+            "serial_no": "1436B2429603097",
+            "vin": "22300",
+            "iin": "860",
+            "fan_speed": "6000"
+        });
+        let psu = parse_psu(&v2).unwrap();
+        assert_eq!(psu.model.as_deref(), Some("P221B"));
+        assert_eq!(psu.serial_number.as_deref(), Some("1436B2429603097"));
+        assert_eq!(psu.firmware_version.as_deref(), Some("230224.220429"));
+        assert_eq!(psu.input_voltage, Some(Voltage::from_volts(223.0)));
+        assert_eq!(psu.input_current, Some(Current::from_amperes(8.6)));
+        assert_eq!(psu.fan_rpm, Some(AngularVelocity::from_rpm(6000.0)));
+
+        let v3 = json!({
+            "fanspeed": 6344,
+            "hwversion": "RT0159",
+            "model": "P221B",
+            "sn": "1436B2429603097",
+            "swversion": "230224.220429",
+            "vin": 22400
+        });
+        let psu = parse_psu(&v3).unwrap();
+        assert_eq!(psu.serial_number.as_deref(), Some("1436B2429603097"));
+        assert_eq!(psu.firmware_version.as_deref(), Some("230224.220429"));
+        assert_eq!(psu.input_voltage, Some(Voltage::from_volts(224.0)));
+        assert_eq!(psu.fan_rpm, Some(AngularVelocity::from_rpm(6344.0)));
+
+        assert_eq!(parse_psu(&json!("unavailable")), None);
+    }
+}
@@ -18,6 +18,7 @@ use asic_rs_core::{
         message::{MessageSeverity, MinerMessage},
         miner::TuningTarget,
         pool::{PoolData, PoolGroupData, PoolURL},
+        psu::PsuData,
     },
     traits::{miner::*, model::MinerModel},
 };
@@ -26,9 +27,9 @@ use async_trait::async_trait;
 use macaddr::MacAddr;
 use measurements::{AngularVelocity, Frequency, Power, Temperature};
 use rpc::WhatsMinerRPCAPI;
-use serde_json::Value;
+use serde_json::{Value, json};
 
-use crate::firmware::WhatsMinerFirmware;
+use crate::{backends::psu::parse_psu, firmware::WhatsMinerFirmware};
 
 mod rpc;
 
@@ -41,9 +42,10 @@ pub struct WhatsMinerV1 {
 
 impl WhatsMinerV1 {
     pub fn new(ip: IpAddr, model: impl MinerModel) -> Self {
+        let auth = Self::default_auth();
         WhatsMinerV1 {
             ip,
-            rpc: WhatsMinerRPCAPI::new(ip, None),
+            rpc: WhatsMinerRPCAPI::new(ip, None, auth),
             device_info: DeviceInfo::new(
                 model,
                 WhatsMinerFirmware::default(),
@@ -162,6 +164,14 @@ impl GetDataLocations for WhatsMinerV1 {
                     tag: None,
                 },
             )],
+            DataField::Psus => vec![(
+                RPC_GET_PSU,
+                DataExtractor {
+                    func: get_by_pointer,
+                    key: Some("/Msg"),
+                    tag: None,
+                },
+            )],
             DataField::Hashboards => vec![(
                 RPC_DEVS,
                 DataExtractor {
@@ -265,6 +275,9 @@ impl GetMAC for WhatsMinerV1 {
 
 impl GetSerialNumber for WhatsMinerV1 {}
 impl GetHostname for WhatsMinerV1 {}
+
+impl GetNetwork for WhatsMinerV1 {}
+
 impl GetApiVersion for WhatsMinerV1 {
     fn parse_api_version(&self, data: &HashMap<DataField, Value>) -> Option<String> {
         data.extract::<String>(DataField::ApiVersion)
@@ -288,6 +301,9 @@ impl GetControlBoardVersion for WhatsMinerV1 {
             })
     }
 }
+
+impl GetControlBoard for WhatsMinerV1 {}
+
 impl GetHashboards for WhatsMinerV1 {
     fn parse_hashboards(&self, data: &HashMap<DataField, Value>) -> Vec<BoardData> {
         let mut hashboards: Vec<BoardData> =
@@ -418,6 +434,16 @@ impl GetPsuFans for WhatsMinerV1 {
         psu_fans
     }
 }
+
+impl GetPsus for WhatsMinerV1 {
+    fn parse_psus(&self, data: &HashMap<DataField, Value>) -> Vec<PsuData> {
+        data.get(&DataField::Psus)
+            .and_then(parse_psu)
+            .into_iter()
+            .collect()
+    }
+}
+
 impl GetFluidTemperature for WhatsMinerV1 {
     fn parse_fluid_temperature(&self, data: &HashMap<DataField, Value>) -> Option<Temperature> {
         data.extract_map::<f64, _>(DataField::FluidTemperature, Temperature::from_celsius)
@@ -537,15 +563,32 @@ impl GetPools for WhatsMinerV1 {
 
 #[async_trait]
 impl SetFaultLight for WhatsMinerV1 {
+    async fn set_fault_light(&self, fault: bool) -> anyhow::Result<bool> {
+        let parameters = match fault {
+            false => Some(json!({"param": "auto"})),
+            true => Some(json!({"color": "red", "period": 200, "duration": 100, "start": 0})),
+        };
+
+        let data = self.rpc.send_command("set_led", true, parameters).await;
+        Ok(data.is_ok())
+    }
     fn supports_set_fault_light(&self) -> bool {
-        false
+        true
     }
 }
 
 #[async_trait]
 impl SetPowerLimit for WhatsMinerV1 {
+    async fn set_power_limit(&self, limit: Power) -> anyhow::Result<bool> {
+        let parameters = Some(json!({"power_limit": limit.as_watts().to_string()}));
+        let data = self
+            .rpc
+            .send_command("adjust_power_limit", true, parameters)
+            .await;
+        Ok(data.is_ok())
+    }
     fn supports_set_power_limit(&self) -> bool {
-        false
+        true
     }
 }
 
@@ -560,29 +603,78 @@ impl SupportsPoolsConfig for WhatsMinerV1 {
             .collect())
     }
 
+    async fn set_pools_config(&self, config: Vec<PoolGroupConfig>) -> anyhow::Result<bool> {
+        let group = config
+            .into_iter()
+            .next()
+            .ok_or_else(|| anyhow::anyhow!("No pool groups provided"))?;
+
+        let mut params = serde_json::Map::new();
+        for n in 1..=3 {
+            let pool = group.pools.get(n - 1);
+            params.insert(
+                format!("pool{n}"),
+                json!(pool.map(|p| p.url.to_string()).unwrap_or_default()),
+            );
+            params.insert(
+                format!("worker{n}"),
+                json!(pool.map(|p| p.username.as_str()).unwrap_or_default()),
+            );
+            params.insert(
+                format!("passwd{n}"),
+                json!(pool.map(|p| p.password.as_str()).unwrap_or_default()),
+            );
+        }
+
+        Ok(self
+            .rpc
+            .send_command("update_pools", true, Some(json!(params)))
+            .await
+            .is_ok())
+    }
+
     fn supports_pools_config(&self) -> bool {
-        false
+        true
     }
 }
 
 #[async_trait]
 impl Restart for WhatsMinerV1 {
+    async fn restart(&self) -> anyhow::Result<bool> {
+        // The miner usually reboots before it answers, so errors are expected.
+        let _ = self.rpc.send_command("reboot", true, None).await;
+        Ok(true)
+    }
     fn supports_restart(&self) -> bool {
-        false
+        true
     }
 }
 
 #[async_trait]
 impl Pause for WhatsMinerV1 {
+    #[allow(unused_variables)]
+    async fn pause(&self, at_time: Option<Duration>) -> anyhow::Result<bool> {
+        // Fire-and-forget: miner may power off before responding.
+        let _ = self
+            .rpc
+            .send_command("power_off", true, Some(json!({"respbefore": "true"})))
+            .await;
+        Ok(true)
+    }
     fn supports_pause(&self) -> bool {
-        false
+        true
     }
 }
 
 #[async_trait]
 impl Resume for WhatsMinerV1 {
+    #[allow(unused_variables)]
+    async fn resume(&self, at_time: Option<Duration>) -> anyhow::Result<bool> {
+        let data = self.rpc.send_command("power_on", true, None).await;
+        Ok(data.is_ok())
+    }
     fn supports_resume(&self) -> bool {
-        false
+        true
     }
 }
 
@@ -598,6 +690,8 @@ impl ReadLogs for WhatsMinerV1 {
     }
 }
 
+impl SetSyslogTarget for WhatsMinerV1 {}
+
 impl FactoryReset for WhatsMinerV1 {
     fn supports_factory_reset(&self) -> bool {
         false
@@ -618,8 +712,18 @@ impl UpgradeFirmware for WhatsMinerV1 {
     }
 }
 
-impl HasAuth for WhatsMinerV1 {}
-impl HasDefaultAuth for WhatsMinerV1 {}
+impl HasDefaultAuth for WhatsMinerV1 {
+    fn default_auth() -> MinerAuth {
+        MinerAuth::new("admin", "admin")
+    }
+}
+
+impl HasAuth for WhatsMinerV1 {
+    fn set_auth(&mut self, auth: MinerAuth) {
+        // The btminer write API only knows the "admin" account
+        self.rpc.set_auth(MinerAuth::new("admin", auth.password()));
+    }
+}
 
 #[async_trait]
 impl SupportsTuningConfig for WhatsMinerV1 {
@@ -831,3 +935,6 @@ mod integration_tests {
 impl SupportsTemperatureConfig for WhatsMinerV1 {}
 impl GetTuningPercent for WhatsMinerV1 {}
 impl SetTuningPercent for WhatsMinerV1 {}
+impl GetApplianceConfig for WhatsMinerV1 {}
+impl SetApplianceConfig for WhatsMinerV1 {}
+impl SupportsNetworkConfig for WhatsMinerV1 {}
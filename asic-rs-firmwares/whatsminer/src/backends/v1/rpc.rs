@@ -10,10 +10,13 @@ use asic_rs_core::{
 use async_trait::async_trait;
 use serde_json::{Value, json};
 
+use crate::backends::crypto::{TokenData, aes_ecb_dec, aes_ecb_enc};
+
 #[derive(Debug)]
 pub struct WhatsMinerRPCAPI {
     ip: IpAddr,
     port: u16,
+    auth: MinerAuth,
 }
 
 #[async_trait]
@@ -75,12 +78,12 @@ impl RPCAPIClient for WhatsMinerRPCAPI {
     async fn send_command(
         &self,
         command: &str,
-        _privileged: bool,
+        privileged: bool,
         parameters: Option<Value>,
     ) -> anyhow::Result<Value> {
-        let mut stream = connect_tcp_stream((self.ip, self.port), DEFAULT_RPC_TIMEOUT)
-            .await
-            .map_err(|_| RPCError::ConnectionFailed)?;
+        if privileged {
+            return self.send_privileged_command(command, parameters).await;
+        }
 
         let request = match parameters {
             Some(Value::Object(mut obj)) => {
@@ -97,25 +100,91 @@ impl RPCAPIClient for WhatsMinerRPCAPI {
                 json!({ "command": command })
             }
         };
-        let json_str = request.to_string();
-        let json_bytes = json_str.as_bytes();
-
-        write_all_with_timeout(&mut stream, json_bytes, DEFAULT_RPC_TIMEOUT).await?;
-
-        let response = read_stream_response(&mut stream, DEFAULT_RPC_TIMEOUT).await?;
-        let response = response
-            .replace('\n', "") // Fix for WM V1, can have newlines in version which breaks the json parser
-            .replace(",}", "}"); // Fix for WM V1, can have trailing commas which breaks the json parser
+        let response = self.send_raw(&request).await?;
 
         self.parse_rpc_result(&response)
     }
 }
 
 impl WhatsMinerRPCAPI {
-    pub fn new(ip: IpAddr, port: Option<u16>) -> Self {
+    pub fn new(ip: IpAddr, port: Option<u16>, auth: MinerAuth) -> Self {
         Self {
             ip,
             port: port.unwrap_or(4028),
+            auth,
+        }
+    }
+
+    pub fn set_auth(&mut self, auth: MinerAuth) {
+        self.auth = auth;
+    }
+
+    async fn send_raw(&self, request: &Value) -> anyhow::Result<String> {
+        let mut stream = connect_tcp_stream((self.ip, self.port), DEFAULT_RPC_TIMEOUT)
+            .await
+            .map_err(|_| RPCError::ConnectionFailed)?;
+
+        write_all_with_timeout(
+            &mut stream,
+            request.to_string().as_bytes(),
+            DEFAULT_RPC_TIMEOUT,
+        )
+        .await?;
+
+        let response = read_stream_response(&mut stream, DEFAULT_RPC_TIMEOUT).await?;
+        Ok(response
+            .replace('\n', "") // Fix for WM V1, can have newlines in version which breaks the json parser
+            .replace(",}", "}")) // Fix for WM V1, can have trailing commas which breaks the json parser
+    }
+
+    async fn get_token_data(&self) -> anyhow::Result<TokenData> {
+        let api_token = self.send_command("get_token", false, None).await?;
+        let msg = api_token
+            .get("Msg")
+            .ok_or_else(|| anyhow::anyhow!("Missing Msg in get_token response"))?;
+        let salt = msg["salt"]
+            .as_str()
+            .ok_or_else(|| anyhow::anyhow!("Could not get salt"))?;
+        let new_salt = msg["newsalt"]
+            .as_str()
+            .ok_or_else(|| anyhow::anyhow!("Could not get newsalt"))?;
+        let api_time = msg["time"]
+            .as_str()
+            .ok_or_else(|| anyhow::anyhow!("Could not get time"))?;
+
+        TokenData::derive(self.auth.password(), salt, new_salt, api_time)
+    }
+
+    /// Send a write command, signed with a fresh `get_token` and AES encrypted
+    /// with the md5crypt of the admin password.
+    async fn send_privileged_command(
+        &self,
+        command: &str,
+        parameters: Option<Value>,
+    ) -> anyhow::Result<Value> {
+        let token_data = self.get_token_data().await?;
+
+        let request = match parameters {
+            Some(Value::Object(mut obj)) => {
+                obj.insert("command".to_string(), json!(command));
+                obj.insert("token".to_string(), json!(token_data.host_sign));
+                Value::Object(obj)
+            }
+            Some(other) => {
+                json!({ "command": command, "parameter": other, "token": token_data.host_sign })
+            }
+            None => json!({ "command": command, "token": token_data.host_sign }),
+        };
+        let enc = aes_ecb_enc(&token_data.host_password_md5, &request.to_string())?;
+        let response = self.send_raw(&json!({ "enc": 1, "data": enc })).await?;
+
+        let enc_result = serde_json::from_str::<Value>(&response)?;
+        match enc_result.get("enc").and_then(Value::as_str) {
+            Some(enc_data) => {
+                let result = aes_ecb_dec(&token_data.host_password_md5, enc_data)?;
+                self.parse_rpc_result(result.trim_end_matches('\0'))
+            }
+            None => self.parse_rpc_result(&response),
         }
     }
 
@@ -4,6 +4,8 @@ use anyhow;
 use asic_rs_core::{
     config::{
         collector::{ConfigCollector, ConfigExtractor, ConfigField, ConfigLocation},
+        fan::FanConfig,
+        network::NetworkConfig,
         pools::PoolGroupConfig,
         scaling::ScalingConfig,
         tuning::TuningConfig,
@@ -20,6 +22,7 @@ use asic_rs_core::{
         message::{MessageSeverity, MinerMessage},
         miner::{MiningMode, TuningTarget},
         pool::{PoolData, PoolGroupData, PoolURL},
+        psu::PsuData,
     },
     traits::{miner::*, model::MinerModel},
     util::is_expected_write_error,
@@ -32,7 +35,10 @@ use measurements::{AngularVelocity, Frequency, Power, Temperature};
 use rpc::WhatsMinerRPCAPI;
 use serde_json::{Value, json};
 
-use crate::firmware::WhatsMinerFirmware;
+use crate::{
+    backends::{logs::log_archive_to_text, psu::parse_psu},
+    firmware::WhatsMinerFirmware,
+};
 
 pub(crate) mod rpc;
 
@@ -73,6 +79,17 @@ impl GetConfigsLocations for WhatsMinerV2 {
                     tag: None,
                 },
             )],
+            ConfigField::Network => vec![(
+                MinerCommand::RPC {
+                    command: "get_miner_info",
+                    parameters: Some(json!({ "info": "ip,proto,netmask,gateway,dns,hostname" })),
+                },
+                ConfigExtractor {
+                    func: get_by_pointer,
+                    key: Some("/Msg"),
+                    tag: None,
+                },
+            )],
             _ => vec![],
         }
     }
@@ -204,6 +221,14 @@ impl GetDataLocations for WhatsMinerV2 {
                     tag: None,
                 },
             )],
+            DataField::Psus => vec![(
+                RPC_GET_PSU,
+                DataExtractor {
+                    func: get_by_pointer,
+                    key: Some("/Msg"),
+                    tag: None,
+                },
+            )],
             DataField::Hashboards => vec![(
                 RPC_DEVS,
                 DataExtractor {
@@ -311,6 +336,9 @@ impl GetHostname for WhatsMinerV2 {
         data.extract::<String>(DataField::Hostname)
     }
 }
+
+impl GetNetwork for WhatsMinerV2 {}
+
 impl GetApiVersion for WhatsMinerV2 {
     fn parse_api_version(&self, data: &HashMap<DataField, Value>) -> Option<String> {
         data.extract::<String>(DataField::ApiVersion)
@@ -330,6 +358,9 @@ impl GetControlBoardVersion for WhatsMinerV2 {
             .and_then(|s| WhatsMinerControlBoard::parse(&s).map(|cb| cb.into()))
     }
 }
+
+impl GetControlBoard for WhatsMinerV2 {}
+
 impl GetHashboards for WhatsMinerV2 {
     fn parse_hashboards(&self, data: &HashMap<DataField, Value>) -> Vec<BoardData> {
         let mut hashboards: Vec<BoardData> =
@@ -460,6 +491,16 @@ impl GetPsuFans for WhatsMinerV2 {
         psu_fans
     }
 }
+
+impl GetPsus for WhatsMinerV2 {
+    fn parse_psus(&self, data: &HashMap<DataField, Value>) -> Vec<PsuData> {
+        data.get(&DataField::Psus)
+            .and_then(parse_psu)
+            .into_iter()
+            .collect()
+    }
+}
+
 impl GetFluidTemperature for WhatsMinerV2 {
     fn parse_fluid_temperature(&self, data: &HashMap<DataField, Value>) -> Option<Temperature> {
         data.extract_map::<f64, _>(DataField::FluidTemperature, Temperature::from_celsius)
@@ -728,21 +769,56 @@ impl Resume for WhatsMinerV2 {
     }
 }
 
+#[async_trait]
 impl ChangePassword for WhatsMinerV2 {
+    async fn change_password(&mut self, password: &str) -> anyhow::Result<bool> {
+        validate_password(password)?;
+        let old_password = self.rpc.auth().password().to_string();
+        self.rpc
+            .send_command(
+                "update_pwd",
+                true,
+                Some(json!({"old": old_password, "new": password})),
+            )
+            .await?;
+
+        self.set_auth(MinerAuth::new("admin", password));
+        Ok(true)
+    }
+
     fn supports_change_password(&self) -> bool {
-        false
+        true
     }
 }
 
+#[async_trait]
 impl ReadLogs for WhatsMinerV2 {
+    async fn read_logs(&self) -> anyhow::Result<String> {
+        let archive = self.rpc.download_logs().await?;
+        log_archive_to_text(&archive)
+    }
+
     fn supports_read_logs(&self) -> bool {
-        false
+        true
     }
 }
 
+impl SetSyslogTarget for WhatsMinerV2 {}
+
+#[async_trait]
 impl FactoryReset for WhatsMinerV2 {
+    async fn factory_reset(&self) -> anyhow::Result<bool> {
+        // The miner reboots into the default config and may drop the
+        // connection before answering.
+        match self.rpc.send_command("factory_reset", true, None).await {
+            Ok(_) => Ok(true),
+            Err(e) if is_expected_write_error(&e) => Ok(true),
+            Err(e) => Err(e),
+        }
+    }
+
     fn supports_factory_reset(&self) -> bool {
-        false
+        true
     }
 }
 
@@ -773,6 +849,19 @@ impl HasAuth for WhatsMinerV2 {
     }
 }
 
+/// BTMiner accepts passwords of up to 8 letters, digits and underscores.
+pub(crate) fn validate_password(password: &str) -> anyhow::Result<()> {
+    if password.is_empty()
+        || password.len() > 8
+        || !password
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_')
+    {
+        anyhow::bail!("WhatsMiner passwords must be 1-8 letters, digits or underscores");
+    }
+    Ok(())
+}
+
 /// Maps a TuningConfig to the WhatsMiner V2 RPC command name and parameter.
 fn tuning_config_to_rpc(config: &TuningConfig) -> anyhow::Result<(&'static str, Option<Value>)> {
     match &config.target {
@@ -801,6 +890,7 @@ impl SupportsTuningConfig for WhatsMinerV2 {
         config: TuningConfig,
         _scaling_config: Option<ScalingConfig>,
     ) -> anyhow::Result<bool> {
+        config.ensure_target_only()?;
         let is_power_target = matches!(&config.target, TuningTarget::Power(_));
         let is_mining_mode = matches!(&config.target, TuningTarget::MiningMode(_));
         let (command, param) = tuning_config_to_rpc(&config)?;
@@ -847,9 +937,93 @@ impl SupportsTuningConfig for WhatsMinerV2 {
     }
 }
 
+/// Parse the network fields requested from `get_miner_info`, which V3
+/// reports under the same names in `get.device.info`.
+pub(crate) fn parse_miner_info_network(info: &Value) -> anyhow::Result<NetworkConfig> {
+    let proto = info
+        .get("proto")
+        .and_then(Value::as_str)
+        .ok_or_else(|| anyhow::anyhow!("No proto in WhatsMiner miner info"))?;
+    let addr = |key: &str| info.get(key)?.as_str()?.trim().parse().ok();
+
+    Ok(NetworkConfig {
+        dhcp: proto.eq_ignore_ascii_case("dhcp"),
+        ip: addr("ip"),
+        netmask: addr("netmask"),
+        gateway: addr("gateway"),
+        dns: info
+            .get("dns")
+            .and_then(Value::as_str)
+            .map(|dns| {
+                dns.split([',', ' '])
+                    .filter_map(|server| server.trim().parse().ok())
+                    .collect::<Vec<_>>()
+            })
+            .filter(|dns| !dns.is_empty()),
+        hostname: info
+            .get("hostname")
+            .and_then(Value::as_str)
+            .map(String::from),
+    })
+}
+
+/// Parameters for a static `net_config`. BTMiner takes a single DNS server
+/// and always expects a hostname with a static address, so
+/// `current_hostname` is kept unless the config sets a new one.
+pub(crate) fn static_network_params(config: &NetworkConfig, current_hostname: &str) -> Value {
+    let addr = |addr: Option<IpAddr>| addr.map(|a| a.to_string()).unwrap_or_default();
+    json!({
+        "ip": addr(config.ip),
+        "mask": addr(config.netmask),
+        "gate": addr(config.gateway),
+        "dns": addr(config.dns.iter().flatten().next().copied()),
+        "host": config.hostname.as_deref().unwrap_or(current_hostname),
+    })
+}
+
+/// BTMiner only lets the fans be stopped entirely for immersion cooling,
+/// which maps to a manual speed of 0. Automatic control without a target or
+/// idle speed restores the firmware's own fan control.
+pub(crate) fn fan_zero_speed(config: &FanConfig) -> anyhow::Result<bool> {
+    match config {
+        FanConfig::Auto {
+            target_temp: None,
+            idle_speed: None,
+            ..
+        } => Ok(false),
+        FanConfig::Auto { .. } => {
+            anyhow::bail!(
+                "WhatsMiner automatic fan control has no target temperature or idle speed"
+            )
+        }
+        FanConfig::Manual { fan_speed: 0, .. } => Ok(true),
+        FanConfig::Manual { fan_speed, .. } => {
+            anyhow::bail!("WhatsMiner only supports a manual fan speed of 0, got {fan_speed}")
+        }
+    }
+}
+
 #[async_trait]
 impl SupportsFanConfig for WhatsMinerV2 {
+    async fn set_fan_config(&self, config: FanConfig) -> anyhow::Result<bool> {
+        config.ensure_no_min_fans()?;
+        let zero_speed = fan_zero_speed(&config)?;
+        self.rpc
+            .send_command(
+                "set_fan_zero_speed",
+                true,
+                Some(json!({"fan_zero_speed": if zero_speed { "1" } else { "0" }})),
+            )
+            .await?;
+        Ok(true)
+    }
+
     fn supports_fan_config(&self) -> bool {
+        true
+    }
+
+    /// The V2 API has no command to read the fan setting back.
+    fn supports_get_fan_config(&self) -> bool {
         false
     }
 }
@@ -932,6 +1106,30 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_fan_zero_speed() {
+        assert!(!fan_zero_speed(&FanConfig::auto(None, None)).unwrap());
+        assert!(fan_zero_speed(&FanConfig::auto(60.0, None)).is_err());
+        assert!(fan_zero_speed(&FanConfig::auto(None, Some(30))).is_err());
+        assert!(fan_zero_speed(&FanConfig::manual(0)).unwrap());
+        assert!(fan_zero_speed(&FanConfig::manual(50)).is_err());
+    }
+
+    #[test]
+    fn test_fan_config_is_set_only() {
+        let miner = WhatsMinerV2::new(IpAddr::from([127, 0, 0, 1]), WhatsMinerModel::M30SV10);
+        assert!(miner.supports_fan_config());
+        assert!(!miner.supports_get_fan_config());
+    }
+
+    #[test]
+    fn test_validate_password() {
+        assert!(validate_password("admin_1").is_ok());
+        assert!(validate_password("").is_err());
+        assert!(validate_password("toolongpass").is_err());
+        assert!(validate_password("p@ss").is_err());
+    }
+
     #[test]
     fn test_parse_tuning_config_power_mode() {
         // Arrange
@@ -988,6 +1186,36 @@ mod tests {
             TuningTarget::Power(Power::from_watts(3600.0))
         );
     }
+
+    #[test]
+    fn test_network_config_round_trip() -> anyhow::Result<()> {
+        let info = json!({
+            "ip": "192.168.1.40",
+            "proto": "static",
+            "netmask": "255.255.255.0",
+            "gateway": "192.168.1.1",
+            "dns": "192.168.1.1 8.8.8.8",
+            "hostname": "WhatsMiner",
+        });
+        let config = parse_miner_info_network(&info)?;
+        assert!(!config.dhcp);
+        assert_eq!(config.dns.as_ref().map(Vec::len), Some(2));
+
+        let params = static_network_params(&config, "ignored");
+        assert_eq!(params["mask"], "255.255.255.0");
+        assert_eq!(params["dns"], "192.168.1.1");
+        assert_eq!(params["host"], "WhatsMiner");
+
+        let unnamed = NetworkConfig {
+            hostname: None,
+            ..config
+        };
+        assert_eq!(
+            static_network_params(&unnamed, "current")["host"],
+            "current"
+        );
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -1208,3 +1436,45 @@ mod integration_tests {
 impl SupportsTemperatureConfig for WhatsMinerV2 {}
 impl GetTuningPercent for WhatsMinerV2 {}
 impl SetTuningPercent for WhatsMinerV2 {}
+impl GetApplianceConfig for WhatsMinerV2 {}
+impl SetApplianceConfig for WhatsMinerV2 {}
+
+#[async_trait]
+impl SupportsNetworkConfig for WhatsMinerV2 {
+    async fn apply_network_config(&self, config: NetworkConfig) -> anyhow::Result<bool> {
+        let current_hostname = match (config.dhcp, &config.hostname) {
+            (false, None) => self.get_network_config().await?.hostname,
+            _ => None,
+        };
+        let params = if config.dhcp {
+            json!({ "param": "dhcp" })
+        } else {
+            static_network_params(&config, current_hostname.as_deref().unwrap_or(""))
+        };
+
+        // The miner restarts its network interface and may drop the
+        // connection before answering.
+        match self
+            .rpc
+            .send_command("net_config", true, Some(params))
+            .await
+        {
+            Ok(_) => Ok(true),
+            Err(e) if is_expected_write_error(&e) => Ok(true),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn parse_network_config(
+        &self,
+        data: &HashMap<ConfigField, Value>,
+    ) -> anyhow::Result<NetworkConfig> {
+        data.get(&ConfigField::Network)
+            .ok_or_else(|| anyhow::anyhow!("No network info in miner info"))
+            .and_then(parse_miner_info_network)
+    }
+
+    fn supports_network_config(&self) -> bool {
+        true
+    }
+}
@@ -1,9 +1,5 @@
 use std::{net::IpAddr, string::ToString};
 
-use aes::{
-    Aes256,
-    cipher::{BlockDecryptMut, BlockEncryptMut, KeyInit},
-};
 use anyhow;
 use asic_rs_core::{
     data::command::{MinerCommand, RPCCommandStatus},
@@ -12,32 +8,13 @@ use asic_rs_core::{
     util::{DEFAULT_RPC_TIMEOUT, connect_tcp_stream, read_stream_response, write_all_with_timeout},
 };
 use async_trait::async_trait;
-use base64::prelude::*;
-use ecb::cipher::block_padding::ZeroPadding;
-use md5crypt::md5crypt;
 use serde_json::{Value, json};
-use sha2::{Digest, Sha256};
 use tokio::io::AsyncReadExt;
 
-const UNLOCK_CLIENT: &str = "heatcore";
-const UNLOCK_MAGIC: &str = "3804fe31981418ce711a31d94bc69651";
-
-type Aes256EcbDec = ecb::Decryptor<Aes256>;
-type Aes256EcbEnc = ecb::Encryptor<Aes256>;
-
-struct TokenData {
-    host_password_md5: String,
-    host_sign: String,
-}
-
-impl TokenData {
-    pub fn new(host_password_md5: String, host_sign: String) -> Self {
-        Self {
-            host_password_md5,
-            host_sign,
-        }
-    }
-}
+use crate::backends::{
+    crypto::{TokenData, UNLOCK_CLIENT, aes_ecb_dec, aes_ecb_enc, write_api_unlock_token},
+    logs::{LOG_DOWNLOAD_TIMEOUT, MAX_LOG_FILE_LEN, log_file_data, log_file_len, split_log_header},
+};
 
 #[derive(Debug)]
 pub struct WhatsMinerRPCAPI {
@@ -58,49 +35,6 @@ impl APIClient for WhatsMinerRPCAPI {
         }
     }
 }
-fn add_to_16(input: &str) -> Vec<u8> {
-    let mut bytes = input.as_bytes().to_vec();
-    while !bytes.len().is_multiple_of(16) {
-        bytes.push(0);
-    }
-    bytes
-}
-
-fn aes_ecb_enc(key: &str, data: &str) -> anyhow::Result<String> {
-    let original_message = data.as_bytes(); // no manual padding
-    let mut hasher = Sha256::new();
-    hasher.update(key.as_bytes());
-    let hashed_key = format!("{:x}", hasher.finalize());
-    let aes_key =
-        hex::decode(hashed_key).map_err(|e| anyhow::anyhow!("invalid SHA256 hex output: {e}"))?;
-
-    let mut buffer = add_to_16(data).to_vec();
-
-    let enc = Aes256EcbEnc::new_from_slice(&aes_key)
-        .map_err(|e| anyhow::anyhow!("invalid AES-256 key length: {e:?}"))?
-        .encrypt_padded_mut::<ZeroPadding>(&mut buffer, original_message.len())
-        .map_err(|e| anyhow::anyhow!("AES encryption failed: {e:?}"))?;
-
-    Ok(BASE64_STANDARD.encode(enc).replace('\n', ""))
-}
-
-fn aes_ecb_dec(key: &str, data: &str) -> anyhow::Result<String> {
-    let mut hasher = Sha256::new();
-    hasher.update(key.as_bytes());
-    let hashed_key = format!("{:x}", hasher.finalize());
-    let aes_key =
-        hex::decode(hashed_key).map_err(|e| anyhow::anyhow!("invalid SHA256 hex output: {e}"))?;
-
-    let b64_dec = &mut BASE64_STANDARD.decode(data)?[..];
-
-    let dec = Aes256EcbDec::new_from_slice(aes_key.as_slice())
-        .map_err(|e| anyhow::anyhow!("invalid AES-256 key length: {e:?}"))?
-        .decrypt_padded_mut::<ZeroPadding>(b64_dec)
-        .map_err(|e| anyhow::anyhow!("AES decryption failed: {e:?}"))?;
-
-    Ok(String::from_utf8_lossy(dec).into_owned())
-}
-
 trait StatusFromBTMinerV2 {
     fn from_btminer_v2(response: &str) -> Result<Self, RPCError>
     where
@@ -243,15 +177,7 @@ impl WhatsMinerRPCAPI {
             .as_str()
             .ok_or_else(|| anyhow::anyhow!("Missing time"))?;
 
-        let crypted = md5crypt(self.auth.password().as_bytes(), salt.as_bytes());
-        let full_password = String::from_utf8_lossy(&crypted);
-        let pwd_md5 = full_password
-            .split('$')
-            .nth(3)
-            .ok_or_else(|| anyhow::anyhow!("Failed to extract md5crypt hash"))?;
-
-        let token_data = format!("{}{}{}{}", timestamp, newsalt, UNLOCK_MAGIC, pwd_md5);
-        let token_md5 = format!("{:x}", md5::compute(token_data.as_bytes()));
+        let token_md5 = write_api_unlock_token(self.auth.password(), salt, newsalt, timestamp)?;
 
         let token_json = json!({ "token": token_md5 });
         write_all_with_timeout(
@@ -278,6 +204,10 @@ impl WhatsMinerRPCAPI {
         }
     }
 
+    pub fn auth(&self) -> MinerAuth {
+        self.auth.clone()
+    }
+
     pub fn set_auth(&mut self, auth: MinerAuth) {
         self.auth = auth;
     }
@@ -319,36 +249,14 @@ impl WhatsMinerRPCAPI {
             .and_then(|v| v.as_str())
             .ok_or(anyhow::anyhow!("Could not get time"))?;
 
-        let crypted = md5crypt(self.auth.password().as_bytes(), salt.as_bytes());
-        let full_password = String::from_utf8_lossy(&crypted);
-        let host_password_md5 = full_password
-            .split("$")
-            .nth(3)
-            .ok_or(anyhow::anyhow!("Failed to extract md5crypt hash"))?;
-
-        let new_crypted = md5crypt(
-            format!("{}{}", host_password_md5, api_time).as_bytes(),
-            new_salt.as_bytes(),
-        );
-        let full_host_sign = String::from_utf8_lossy(&new_crypted);
-        let host_sign = full_host_sign
-            .split("$")
-            .nth(3)
-            .ok_or(anyhow::anyhow!("Failed to extract host sign"))?;
-
-        Ok(TokenData::new(
-            host_password_md5.to_owned(),
-            host_sign.to_owned(),
-        ))
+        TokenData::derive(self.auth.password(), salt, new_salt, api_time)
     }
 
-    async fn send_privileged_command(
-        &self,
+    fn privileged_request(
+        token_data: &TokenData,
         command: &str,
         parameters: Option<Value>,
-    ) -> anyhow::Result<Value> {
-        let token_data = self.get_token_data().await?;
-
+    ) -> anyhow::Result<String> {
         let request = match parameters {
             Some(Value::Object(mut obj)) => {
                 // Use the existing object as the base
@@ -366,20 +274,94 @@ impl WhatsMinerRPCAPI {
             }
         };
         let enc = aes_ecb_enc(&token_data.host_password_md5, &request.to_string())?;
-        let command = json!({"enc": 1, "data": enc});
-        let json_str = command.to_string();
-        let json_bytes = json_str.as_bytes();
+        Ok(json!({"enc": 1, "data": enc}).to_string())
+    }
+
+    async fn send_privileged_command(
+        &self,
+        command: &str,
+        parameters: Option<Value>,
+    ) -> anyhow::Result<Value> {
+        let token_data = self.get_token_data().await?;
+        let request = Self::privileged_request(&token_data, command, parameters)?;
 
         let response = {
             let mut stream = connect_tcp_stream((self.ip, self.port), DEFAULT_RPC_TIMEOUT)
                 .await
                 .map_err(|_| RPCError::ConnectionFailed)?;
 
-            write_all_with_timeout(&mut stream, json_bytes, DEFAULT_RPC_TIMEOUT).await?;
+            write_all_with_timeout(&mut stream, request.as_bytes(), DEFAULT_RPC_TIMEOUT).await?;
             read_stream_response(&mut stream, DEFAULT_RPC_TIMEOUT).await
         };
         let response = response?;
 
         self.parse_privileged_rpc_result(&token_data.host_password_md5, &response)
     }
+
+    /// Download the log archive with `download_logs`.
+    ///
+    /// The miner answers with a status holding `logfilelen`, then streams the
+    /// file itself on the same connection.
+    pub async fn download_logs(&self) -> anyhow::Result<Vec<u8>> {
+        match self.download_logs_once().await {
+            Err(e)
+                if e.downcast_ref::<RPCError>()
+                    .is_some_and(|rpc| matches!(rpc, StatusCheckFailed(_))) =>
+            {
+                self.unlock_write_commands().await?;
+                self.download_logs_once().await
+            }
+            result => result,
+        }
+    }
+
+    async fn download_logs_once(&self) -> anyhow::Result<Vec<u8>> {
+        let token_data = self.get_token_data().await?;
+        let request = Self::privileged_request(&token_data, "download_logs", None)?;
+
+        let mut stream = connect_tcp_stream((self.ip, self.port), DEFAULT_RPC_TIMEOUT)
+            .await
+            .map_err(|_| RPCError::ConnectionFailed)?;
+        write_all_with_timeout(&mut stream, request.as_bytes(), DEFAULT_RPC_TIMEOUT).await?;
+
+        tokio::time::timeout(LOG_DOWNLOAD_TIMEOUT, async {
+            let mut buf = Vec::new();
+            let mut chunk = [0u8; 8192];
+            let mut file: Option<(usize, usize)> = None;
+
+            loop {
+                let bytes_read = stream.read(&mut chunk).await.map_err(RPCError::from)?;
+                if bytes_read == 0 {
+                    break;
+                }
+                buf.extend_from_slice(&chunk[..bytes_read]);
+                if buf.len() > MAX_LOG_FILE_LEN + chunk.len() {
+                    anyhow::bail!(
+                        "download_logs response exceeds the {MAX_LOG_FILE_LEN} byte limit"
+                    );
+                }
+
+                if file.is_none()
+                    && let Some((header, offset)) = split_log_header(&buf)
+                {
+                    let status = self.parse_privileged_rpc_result(
+                        &token_data.host_password_md5,
+                        &header.to_string(),
+                    )?;
+                    file = Some((offset, log_file_len(&status["Msg"])?));
+                }
+                if let Some((offset, len)) = file
+                    && buf.len() >= offset + len
+                {
+                    break;
+                }
+            }
+
+            let (offset, len) =
+                file.ok_or_else(|| anyhow::anyhow!("Incomplete download_logs response"))?;
+            log_file_data(&buf, offset, len)
+        })
+        .await
+        .map_err(|_| RPCError::ReadTimeout)?
+    }
 }
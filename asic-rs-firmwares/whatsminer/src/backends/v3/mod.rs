@@ -4,6 +4,8 @@ use anyhow;
 use asic_rs_core::{
     config::{
         collector::{ConfigCollector, ConfigExtractor, ConfigField, ConfigLocation},
+        fan::FanConfig,
+        network::NetworkConfig,
         pools::PoolGroupConfig,
         scaling::ScalingConfig,
         tuning::TuningConfig,
@@ -21,6 +23,7 @@ use asic_rs_core::{
         message::{MessageSeverity, MinerMessage},
         miner::{MiningMode, TuningTarget},
         pool::{PoolData, PoolGroupData, PoolURL},
+        psu::PsuData,
     },
     traits::{miner::*, model::MinerModel},
     util::is_expected_write_error,
@@ -33,7 +36,12 @@ use measurements::{AngularVelocity, Frequency, Power, Temperature};
 pub(crate) use rpc::WhatsMinerRPCAPI;
 use serde_json::{Value, json};
 
+use crate::backends::logs::log_archive_to_text;
+use crate::backends::psu::parse_psu;
 use crate::backends::v2::rpc::WhatsMinerRPCAPI as WhatsMinerV2RPC;
+use crate::backends::v2::{
+    fan_zero_speed, parse_miner_info_network, static_network_params, validate_password,
+};
 use crate::firmware::WhatsMinerFirmware;
 
 mod rpc;
@@ -90,11 +98,31 @@ impl GetConfigsLocations for WhatsMinerV3 {
             command: "get.device.info",
             parameters: None,
         };
+        const RPC_GET_FAN_SETTING: MinerCommand = MinerCommand::RPC {
+            command: "get.fan.setting",
+            parameters: None,
+        };
         let rpc_get_miner_status_summary: MinerCommand = MinerCommand::RPC {
             command: "get.miner.status",
             parameters: Some(json!("summary")),
         };
         match data_field {
+            ConfigField::Fan => vec![(
+                RPC_GET_FAN_SETTING,
+                ConfigExtractor {
+                    func: get_by_pointer,
+                    key: Some("/msg"),
+                    tag: None,
+                },
+            )],
+            ConfigField::Network => vec![(
+                RPC_GET_DEVICE_INFO,
+                ConfigExtractor {
+                    func: get_by_pointer,
+                    key: Some("/msg/network"),
+                    tag: None,
+                },
+            )],
             ConfigField::Tuning => vec![
                 (
                     RPC_GET_DEVICE_INFO,
@@ -234,6 +262,14 @@ impl GetDataLocations for WhatsMinerV3 {
                     tag: None,
                 },
             )],
+            DataField::Psus => vec![(
+                RPC_GET_DEVICE_INFO,
+                DataExtractor {
+                    func: get_by_pointer,
+                    key: Some("/msg/power"),
+                    tag: None,
+                },
+            )],
             DataField::Hashboards => vec![
                 (
                     RPC_GET_DEVICE_INFO,
@@ -351,6 +387,9 @@ impl GetHostname for WhatsMinerV3 {
         data.extract::<String>(DataField::Hostname)
     }
 }
+
+impl GetNetwork for WhatsMinerV3 {}
+
 impl GetApiVersion for WhatsMinerV3 {
     fn parse_api_version(&self, data: &HashMap<DataField, Value>) -> Option<String> {
         data.extract::<String>(DataField::ApiVersion)
@@ -370,6 +409,9 @@ impl GetControlBoardVersion for WhatsMinerV3 {
             .and_then(|s| WhatsMinerControlBoard::parse(&s).map(|cb| cb.into()))
     }
 }
+
+impl GetControlBoard for WhatsMinerV3 {}
+
 impl GetHashboards for WhatsMinerV3 {
     fn parse_hashboards(&self, data: &HashMap<DataField, Value>) -> Vec<BoardData> {
         let mut hashboards: Vec<BoardData> =
@@ -498,6 +540,16 @@ impl GetPsuFans for WhatsMinerV3 {
         psu_fans
     }
 }
+
+impl GetPsus for WhatsMinerV3 {
+    fn parse_psus(&self, data: &HashMap<DataField, Value>) -> Vec<PsuData> {
+        data.get(&DataField::Psus)
+            .and_then(parse_psu)
+            .into_iter()
+            .collect()
+    }
+}
+
 impl GetFluidTemperature for WhatsMinerV3 {
     fn parse_fluid_temperature(&self, data: &HashMap<DataField, Value>) -> Option<Temperature> {
         data.extract_map::<f64, _>(DataField::FluidTemperature, Temperature::from_celsius)
@@ -759,21 +811,65 @@ impl Resume for WhatsMinerV3 {
     }
 }
 
+#[async_trait]
 impl ChangePassword for WhatsMinerV3 {
+    async fn change_password(&mut self, password: &str) -> anyhow::Result<bool> {
+        validate_password(password)?;
+        let auth = self.rpc.auth();
+        self.rpc
+            .send_command(
+                "set.user.change_passwd",
+                true,
+                Some(json!({
+                    "account": auth.username(),
+                    "new": password,
+                    "old": auth.password(),
+                })),
+            )
+            .await?;
+
+        // Only the logged in account changed, any other login is untouched.
+        self.rpc.set_auth(MinerAuth::new(auth.username(), password));
+        Ok(true)
+    }
+
     fn supports_change_password(&self) -> bool {
-        false
+        true
     }
 }
 
+#[async_trait]
 impl ReadLogs for WhatsMinerV3 {
+    async fn read_logs(&self) -> anyhow::Result<String> {
+        let archive = self.rpc.download_logs().await?;
+        log_archive_to_text(&archive)
+    }
+
     fn supports_read_logs(&self) -> bool {
-        false
+        true
     }
 }
 
+impl SetSyslogTarget for WhatsMinerV3 {}
+
+#[async_trait]
 impl FactoryReset for WhatsMinerV3 {
+    async fn factory_reset(&self) -> anyhow::Result<bool> {
+        // The miner reboots into the default config and may drop the
+        // connection before answering.
+        match self
+            .rpc
+            .send_command("set.system.factory_reset", true, None)
+            .await
+        {
+            Ok(_) => Ok(true),
+            Err(e) if is_expected_write_error(&e) => Ok(true),
+            Err(e) => Err(e),
+        }
+    }
+
     fn supports_factory_reset(&self) -> bool {
-        false
+        true
     }
 }
 
@@ -831,6 +927,7 @@ impl SupportsTuningConfig for WhatsMinerV3 {
         config: TuningConfig,
         _scaling_config: Option<ScalingConfig>,
     ) -> anyhow::Result<bool> {
+        config.ensure_target_only()?;
         let is_power_target = matches!(&config.target, TuningTarget::Power(_));
         let (command, param) = tuning_config_to_v3_rpc(&config)?;
         let v3_cmd = MinerCommand::RPC {
@@ -914,8 +1011,39 @@ impl SupportsTuningConfig for WhatsMinerV3 {
 
 #[async_trait]
 impl SupportsFanConfig for WhatsMinerV3 {
+    async fn set_fan_config(&self, config: FanConfig) -> anyhow::Result<bool> {
+        config.ensure_no_min_fans()?;
+        let zero_speed = fan_zero_speed(&config)?;
+        self.rpc
+            .send_command(
+                "set.fan.zero_speed",
+                true,
+                Some(json!(u8::from(zero_speed))),
+            )
+            .await?;
+        Ok(true)
+    }
+
+    fn parse_fan_config(&self, data: &HashMap<ConfigField, Value>) -> anyhow::Result<FanConfig> {
+        let setting = data
+            .get(&ConfigField::Fan)
+            .ok_or_else(|| anyhow::anyhow!("No fan setting data"))?;
+
+        let zero_speed = setting
+            .get("fan-zero-speed")
+            .and_then(|v| v.as_u64().or_else(|| v.as_str()?.parse().ok()))
+            .ok_or_else(|| anyhow::anyhow!("No fan-zero-speed in fan setting"))?;
+
+        if zero_speed == 1 {
+            Ok(FanConfig::manual(0))
+        } else {
+            // The firmware does not report its target temperature.
+            Ok(FanConfig::auto(None, None))
+        }
+    }
+
     fn supports_fan_config(&self) -> bool {
-        false
+        true
     }
 }
 
@@ -1041,6 +1169,45 @@ mod tests {
             TuningTarget::Power(Power::from_watts(3600.0))
         );
     }
+
+    #[test]
+    fn test_parse_fan_config_zero_speed() {
+        // Arrange
+        let miner = WhatsMinerV3::new(IpAddr::from([127, 0, 0, 1]), WhatsMinerModel::M60SVK30);
+        let mut data = HashMap::new();
+        data.insert(
+            ConfigField::Fan,
+            json!({"fan-temp-offset": 0, "fan-poweroff-cool": 1, "fan-zero-speed": 1}),
+        );
+
+        // Act
+        let config = miner.parse_fan_config(&data).unwrap();
+
+        // Assert
+        assert_eq!(config, FanConfig::manual(0));
+
+        data.insert(ConfigField::Fan, json!({"fan-zero-speed": 0}));
+        let config = miner.parse_fan_config(&data).unwrap();
+        assert_eq!(config, FanConfig::auto(None, None));
+    }
+
+    #[test]
+    fn test_parse_network_config_from_device_info() -> anyhow::Result<()> {
+        // Arrange
+        let miner = WhatsMinerV3::new(IpAddr::from([127, 0, 0, 1]), WhatsMinerModel::M60SVK30);
+        let info = Value::from_str(crate::test::json::v3::GET_DEVICE_INFO_COMMAND)?;
+        let mut data = HashMap::new();
+        data.insert(ConfigField::Network, info["msg"]["network"].clone());
+
+        // Act
+        let config = miner.parse_network_config(&data)?;
+
+        // Assert
+        assert!(config.dhcp);
+        assert_eq!(config.ip, Some("172.16.2.58".parse()?));
+        assert_eq!(config.hostname.as_deref(), Some("WhatsMiner"));
+        Ok(())
+    }
 }
 
 #[cfg(test)]
@@ -1204,3 +1371,45 @@ mod integration_tests {
 impl SupportsTemperatureConfig for WhatsMinerV3 {}
 impl GetTuningPercent for WhatsMinerV3 {}
 impl SetTuningPercent for WhatsMinerV3 {}
+impl GetApplianceConfig for WhatsMinerV3 {}
+impl SetApplianceConfig for WhatsMinerV3 {}
+
+#[async_trait]
+impl SupportsNetworkConfig for WhatsMinerV3 {
+    async fn apply_network_config(&self, config: NetworkConfig) -> anyhow::Result<bool> {
+        let param = if config.dhcp {
+            json!("dhcp")
+        } else {
+            let current_hostname = match &config.hostname {
+                None => self.get_network_config().await?.hostname,
+                Some(_) => None,
+            };
+            static_network_params(&config, current_hostname.as_deref().unwrap_or(""))
+        };
+
+        // The miner restarts its network interface and may drop the
+        // connection before answering.
+        match self
+            .rpc
+            .send_command("set.system.net_config", true, Some(param))
+            .await
+        {
+            Ok(_) => Ok(true),
+            Err(e) if is_expected_write_error(&e) => Ok(true),
+            Err(e) => Err(e),
+        }
+    }
+
+    fn parse_network_config(
+        &self,
+        data: &HashMap<ConfigField, Value>,
+    ) -> anyhow::Result<NetworkConfig> {
+        data.get(&ConfigField::Network)
+            .ok_or_else(|| anyhow::anyhow!("No network info in device info"))
+            .and_then(parse_miner_info_network)
+    }
+
+    fn supports_network_config(&self) -> bool {
+        true
+    }
+}
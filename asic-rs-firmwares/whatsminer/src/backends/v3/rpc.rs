@@ -1,9 +1,5 @@
 use std::net::IpAddr;
 
-use aes::{
-    Aes256,
-    cipher::{BlockEncryptMut, KeyInit},
-};
 use anyhow;
 use asic_rs_core::errors::RPCError::StatusCheckFailed;
 use asic_rs_core::{
@@ -17,30 +13,17 @@ use asic_rs_core::{
 use async_trait::async_trait;
 use base64::prelude::*;
 use chrono::Utc;
-use ecb::cipher::block_padding::ZeroPadding;
-use md5crypt::md5crypt;
 use serde_json::{Value, json};
 use sha2::{Digest, Sha256};
-use tokio::io::AsyncReadExt;
-
-const UNLOCK_CLIENT: &str = "heatcore";
-const UNLOCK_MAGIC: &str = "3804fe31981418ce711a31d94bc69651";
-
-type Aes256EcbEnc = ecb::Encryptor<Aes256>;
-
-fn encrypt_param(aes_key: &[u8], data: &str) -> anyhow::Result<String> {
-    let original_len = data.len();
-    let padded_len = (original_len + 15) & !15;
-    let mut buffer = data.as_bytes().to_vec();
-    buffer.resize(padded_len, 0);
+use tokio::{io::AsyncReadExt, net::TcpStream};
 
-    let enc = Aes256EcbEnc::new_from_slice(aes_key)
-        .map_err(|e| anyhow::anyhow!("Invalid AES key length: {e:?}"))?
-        .encrypt_padded_mut::<ZeroPadding>(&mut buffer, original_len)
-        .map_err(|e| anyhow::anyhow!("AES encryption failed: {e:?}"))?;
+use crate::backends::{
+    crypto::{UNLOCK_CLIENT, aes_ecb_encrypt, write_api_unlock_token},
+    logs::{LOG_DOWNLOAD_TIMEOUT, log_file_len},
+};
 
-    Ok(BASE64_STANDARD.encode(enc).replace('\n', ""))
-}
+/// Commands whose `param` must be AES encrypted with the command token.
+const ENCRYPTED_PARAM_COMMANDS: [&str; 2] = ["set.miner.pools", "set.user.change_passwd"];
 
 #[derive(Debug)]
 pub struct WhatsMinerRPCAPI {
@@ -141,21 +124,8 @@ impl RPCAPIClient for WhatsMinerRPCAPI {
                 json!({ "cmd": command })
             }
         };
-        let json_str = request.to_string();
-        let json_bytes = json_str.as_bytes();
-        let length = json_bytes.len() as u32;
-
-        write_all_with_timeout(&mut stream, &length.to_le_bytes(), DEFAULT_RPC_TIMEOUT).await?;
-        write_all_with_timeout(&mut stream, json_bytes, DEFAULT_RPC_TIMEOUT).await?;
-
-        let mut len_buf = [0u8; 4];
-        read_exact_with_timeout(&mut stream, &mut len_buf, DEFAULT_RPC_TIMEOUT).await?;
-        let response_len = u32::from_le_bytes(len_buf) as usize;
-
-        let mut resp_buf = vec![0u8; response_len];
-        read_exact_with_timeout(&mut stream, &mut resp_buf, DEFAULT_RPC_TIMEOUT).await?;
-
-        let response_str = String::from_utf8_lossy(&resp_buf).into_owned();
+        Self::write_request(&mut stream, &request.to_string()).await?;
+        let response_str = Self::read_response(&mut stream).await?;
 
         self.parse_rpc_result(&response_str)
     }
@@ -207,15 +177,7 @@ impl WhatsMinerRPCAPI {
             .as_str()
             .ok_or_else(|| anyhow::anyhow!("Missing time"))?;
 
-        let crypted = md5crypt("admin".as_bytes(), salt.as_bytes());
-        let full_password = String::from_utf8_lossy(&crypted);
-        let pwd_md5 = full_password
-            .split('$')
-            .nth(3)
-            .ok_or_else(|| anyhow::anyhow!("Failed to extract md5crypt hash"))?;
-
-        let token_data = format!("{}{}{}{}", timestamp, newsalt, UNLOCK_MAGIC, pwd_md5);
-        let token_md5 = format!("{:x}", md5::compute(token_data.as_bytes()));
+        let token_md5 = write_api_unlock_token("admin", salt, newsalt, timestamp)?;
 
         let token_json = json!({ "token": token_md5 });
         write_all_with_timeout(
@@ -234,6 +196,10 @@ impl WhatsMinerRPCAPI {
         Ok(())
     }
 
+    pub fn auth(&self) -> MinerAuth {
+        self.auth.clone()
+    }
+
     pub fn set_auth(&mut self, auth: MinerAuth) {
         self.auth = auth;
     }
@@ -246,20 +212,17 @@ impl WhatsMinerRPCAPI {
         }
     }
 
-    async fn send_privileged_command(
+    /// Build a signed request for a privileged command.
+    async fn privileged_request(
         &self,
         command: &str,
         parameters: Option<Value>,
-    ) -> anyhow::Result<Value> {
+    ) -> anyhow::Result<String> {
         let salt = self
             .get_salt()
             .await
             .ok_or_else(|| anyhow::anyhow!("Could not get salt for privileged command"))?;
 
-        let mut stream = connect_tcp_stream((self.ip, self.port), DEFAULT_RPC_TIMEOUT)
-            .await
-            .map_err(|_| RPCError::ConnectionFailed)?;
-
         let timestamp = Utc::now().timestamp();
 
         let tokenized_command = format!("{}{}{}{}", command, self.auth.password(), salt, timestamp);
@@ -277,8 +240,8 @@ impl WhatsMinerRPCAPI {
 
         let request = match parameters {
             Some(other) => {
-                let param = if command == "set.miner.pools" {
-                    json!(encrypt_param(&hashed_command, &other.to_string())?)
+                let param = if ENCRYPTED_PARAM_COMMANDS.contains(&command) {
+                    json!(aes_ecb_encrypt(&hashed_command, &other.to_string())?)
                 } else {
                     other
                 };
@@ -300,25 +263,78 @@ impl WhatsMinerRPCAPI {
                 })
             }
         };
-        let json_str = request.to_string();
-        let json_bytes = json_str.as_bytes();
+        Ok(request.to_string())
+    }
+
+    async fn write_request(stream: &mut TcpStream, request: &str) -> anyhow::Result<()> {
+        let json_bytes = request.as_bytes();
         let length = json_bytes.len() as u32;
 
-        write_all_with_timeout(&mut stream, &length.to_le_bytes(), DEFAULT_RPC_TIMEOUT).await?;
-        write_all_with_timeout(&mut stream, json_bytes, DEFAULT_RPC_TIMEOUT).await?;
+        write_all_with_timeout(stream, &length.to_le_bytes(), DEFAULT_RPC_TIMEOUT).await?;
+        write_all_with_timeout(stream, json_bytes, DEFAULT_RPC_TIMEOUT).await
+    }
 
+    async fn read_response(stream: &mut TcpStream) -> anyhow::Result<String> {
         let mut len_buf = [0u8; 4];
-        read_exact_with_timeout(&mut stream, &mut len_buf, DEFAULT_RPC_TIMEOUT).await?;
+        read_exact_with_timeout(stream, &mut len_buf, DEFAULT_RPC_TIMEOUT).await?;
         let response_len = u32::from_le_bytes(len_buf) as usize;
 
         let mut resp_buf = vec![0u8; response_len];
-        read_exact_with_timeout(&mut stream, &mut resp_buf, DEFAULT_RPC_TIMEOUT).await?;
+        read_exact_with_timeout(stream, &mut resp_buf, DEFAULT_RPC_TIMEOUT).await?;
+
+        Ok(String::from_utf8_lossy(&resp_buf).into_owned())
+    }
+
+    async fn send_privileged_command(
+        &self,
+        command: &str,
+        parameters: Option<Value>,
+    ) -> anyhow::Result<Value> {
+        let request = self.privileged_request(command, parameters).await?;
+
+        let mut stream = connect_tcp_stream((self.ip, self.port), DEFAULT_RPC_TIMEOUT)
+            .await
+            .map_err(|_| RPCError::ConnectionFailed)?;
 
-        let response_str = String::from_utf8_lossy(&resp_buf).into_owned();
+        Self::write_request(&mut stream, &request).await?;
+        let response_str = Self::read_response(&mut stream).await?;
 
         self.parse_rpc_result(&response_str)
     }
 
+    /// Download the log archive with `get.log.download`.
+    ///
+    /// The response holds `logfilelen`, and the file itself follows on the
+    /// same connection.
+    pub async fn download_logs(&self) -> anyhow::Result<Vec<u8>> {
+        match self.download_logs_once().await {
+            Err(e)
+                if e.downcast_ref::<RPCError>()
+                    .is_some_and(|rpc| matches!(rpc, StatusCheckFailed(_))) =>
+            {
+                self.unlock_write_commands().await?;
+                self.download_logs_once().await
+            }
+            result => result,
+        }
+    }
+
+    async fn download_logs_once(&self) -> anyhow::Result<Vec<u8>> {
+        let request = self.privileged_request("get.log.download", None).await?;
+
+        let mut stream = connect_tcp_stream((self.ip, self.port), DEFAULT_RPC_TIMEOUT)
+            .await
+            .map_err(|_| RPCError::ConnectionFailed)?;
+
+        Self::write_request(&mut stream, &request).await?;
+        let status = self.parse_rpc_result(&Self::read_response(&mut stream).await?)?;
+        let len = log_file_len(&status["msg"])?;
+
+        let mut file = vec![0u8; len];
+        read_exact_with_timeout(&mut stream, &mut file, LOG_DOWNLOAD_TIMEOUT).await?;
+        Ok(file)
+    }
+
     async fn get_salt(&self) -> Option<String> {
         self.send_command("get.device.info", false, Some(json!("salt")))
             .await
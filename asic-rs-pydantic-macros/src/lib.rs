@@ -499,9 +499,8 @@ fn pydantic_type_hint(ty: &Type) -> String {
                 "IpAddr" => "IPv4Address | IPv6Address".to_owned(),
                 "MacAddr" => "str".to_owned(),
                 "Duration" => "timedelta | float | int".to_owned(),
-                "AngularVelocity" | "Frequency" | "Power" | "Temperature" | "Voltage" => {
-                    "float".to_owned()
-                }
+                "AngularVelocity" | "Current" | "Frequency" | "Power" | "Temperature"
+                | "Voltage" => "float".to_owned(),
                 ident => ident.to_owned(),
             }
         }
@@ -608,6 +607,7 @@ fn custom_getter_return_type(
     match ident.as_str() {
         "MacAddr" => Some((quote!(::std::string::String), quote!((#value).to_string()))),
         "AngularVelocity" => Some((quote!(f64), quote!((#value).as_rpm()))),
+        "Current" => Some((quote!(f64), quote!((#value).as_amperes()))),
         "Frequency" => Some((quote!(f64), quote!((#value).as_megahertz()))),
         "Power" => Some((quote!(f64), quote!((#value).as_watts()))),
         "Temperature" => Some((quote!(f64), quote!((#value).as_celsius()))),
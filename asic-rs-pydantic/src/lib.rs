@@ -1,7 +1,7 @@
 use std::{fmt::Display, net::IpAddr, str::FromStr, time::Duration};
 
 use macaddr::MacAddr;
-use measurements::{AngularVelocity, Frequency, Power, Temperature, Voltage};
+use measurements::{AngularVelocity, Current, Frequency, Power, Temperature, Voltage};
 use pyo3::{
     PyTypeInfo,
     exceptions::PyValueError,
@@ -282,6 +282,7 @@ macro_rules! impl_pydantic_measurement {
 }
 
 impl_pydantic_measurement!(AngularVelocity, from_rpm, as_rpm);
+impl_pydantic_measurement!(Current, from_amperes, as_amperes);
 impl_pydantic_measurement!(Frequency, from_megahertz, as_megahertz);
 impl_pydantic_measurement!(Power, from_watts, as_watts);
 impl_pydantic_measurement!(Temperature, from_celsius, as_celsius);
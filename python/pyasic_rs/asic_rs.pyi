@@ -4,6 +4,24 @@ from datetime import timedelta
 from ipaddress import IPv4Address, IPv6Address
 from typing import Any, Final, final
 
+@final
+class ApplianceConfig:
+    @classmethod
+    def __get_pydantic_core_schema__(cls, /, _source_type: "object", _handler: "object") -> "object": ...
+    def __new__(cls, /, *, screen_enabled: "bool | None" = None) -> "ApplianceConfig": ...
+    def __repr__(self, /) -> str: ...
+    @staticmethod
+    def _pydantic_serialize(value: "ApplianceConfig") -> "dict[str, object]": ...
+    @classmethod
+    def _pydantic_validate(cls, /, value: "object") -> "ApplianceConfig": ...
+    def model_dump(self, /, **_kwargs: "object") -> "dict[str, object]": ...
+    @classmethod
+    def model_json_schema(cls, /, **kwargs: "object") -> "dict[str, object]": ...
+    @classmethod
+    def model_validate(cls, /, obj: "object", **_kwargs: "object") -> "ApplianceConfig": ...
+    @property
+    def screen_enabled(self, /) -> bool |None: ...
+
 @final
 class BoardData:
     @classmethod
@@ -76,11 +94,44 @@ class ChipData:
     @property
     def working(self, /) -> bool |None: ...
 
+@final
+class ControlBoardData:
+    @classmethod
+    def __get_pydantic_core_schema__(cls, /, _source_type: "object", _handler: "object") -> "object": ...
+    def __repr__(self, /) -> str: ...
+    @staticmethod
+    def _pydantic_serialize(value: "ControlBoardData") -> "dict[str, object]": ...
+    @classmethod
+    def _pydantic_validate(cls, /, value: "object") -> "ControlBoardData": ...
+    @property
+    def cpu_load(self, /) -> float |None: ...
+    @property
+    def kernel_version(self, /) -> str |None: ...
+    @property
+    def memory_total(self, /) -> int |None: ...
+    @property
+    def memory_used(self, /) -> int |None: ...
+    def model_dump(self, /, **_kwargs: "object") -> "dict[str, object]": ...
+    @classmethod
+    def model_json_schema(cls, /, **kwargs: "object") -> "dict[str, object]": ...
+    @classmethod
+    def model_validate(cls, /, obj: "object", **_kwargs: "object") -> "ControlBoardData": ...
+    @property
+    def os_version(self, /) -> str |None: ...
+    @property
+    def storage_total(self, /) -> int |None: ...
+    @property
+    def storage_used(self, /) -> int |None: ...
+    @property
+    def temperature(self, /) -> float |None: ...
+
 @final
 class DataField:
     ApiVersion: Final[DataField]
+    Appliance: Final[DataField]
     AverageTemperature: Final[DataField]
     Chips: Final[DataField]
+    ControlBoard: Final[DataField]
     ControlBoardVersion: Final[DataField]
     DeviceInfo: Final[DataField]
     Efficiency: Final[DataField]
@@ -96,9 +147,11 @@ class DataField:
     LightFlashing: Final[DataField]
     Mac: Final[DataField]
     Messages: Final[DataField]
+    Network: Final[DataField]
     OutletFluidTemperature: Final[DataField]
     Pools: Final[DataField]
     PsuFans: Final[DataField]
+    Psus: Final[DataField]
     SchemaVersion: Final[DataField]
     SerialNumber: Final[DataField]
     Timestamp: Final[DataField]
@@ -156,7 +209,7 @@ class FanConfig:
     @classmethod
     def _pydantic_validate(cls, /, value: "object") -> "FanConfig": ...
     @staticmethod
-    def auto(target_temp: float, idle_speed: int |None = None) -> FanConfig: ...
+    def auto(target_temp: float |None = None, idle_speed: int |None = None) -> FanConfig: ...
     @property
     def fan_speed(self, /) -> int |None: ...
     @property
@@ -164,6 +217,8 @@ class FanConfig:
     @staticmethod
     def manual(fan_speed: int) -> FanConfig: ...
     @property
+    def min_fans(self, /) -> int |None: ...
+    @property
     def mode(self, /) -> str: ...
     def model_dump(self, /, **_kwargs: "object") -> "dict[str, object]": ...
     @classmethod
@@ -172,20 +227,25 @@ class FanConfig:
     def model_validate(cls, /, obj: "object", **_kwargs: "object") -> "FanConfig": ...
     @property
     def target_temp(self, /) -> float |None: ...
+    def with_min_fans(self, /, min_fans: int) -> FanConfig: ...
     @final
     class Auto(FanConfig):
-        __match_args__: Final = ("target_temp", "idle_speed")
-        def __new__(cls, /, target_temp: float, idle_speed: int |None) -> FanConfig.Auto: ...
+        __match_args__: Final = ("target_temp", "idle_speed", "min_fans")
+        def __new__(cls, /, target_temp: float |None, idle_speed: int |None, min_fans: int |None) -> FanConfig.Auto: ...
         @property
         def idle_speed(self, /) -> int |None: ...
         @property
-        def target_temp(self, /) -> float: ...
+        def min_fans(self, /) -> int |None: ...
+        @property
+        def target_temp(self, /) -> float |None: ...
     @final
     class Manual(FanConfig):
-        __match_args__: Final = ("fan_speed",)
-        def __new__(cls, /, fan_speed: int) -> FanConfig.Manual: ...
+        __match_args__: Final = ("fan_speed", "min_fans")
+        def __new__(cls, /, fan_speed: int, min_fans: int |None) -> FanConfig.Manual: ...
         @property
         def fan_speed(self, /) -> int: ...
+        @property
+        def min_fans(self, /) -> int |None: ...
 
 @final
 class FanData:
@@ -362,6 +422,8 @@ class Miner:
     @property
     def firmware(self, /) -> str: ...
     def get_api_version(self, /) -> Awaitable[str |None]: ...
+    def get_appliance_config(self, /) -> Awaitable[ApplianceConfig |None]: ...
+    def get_control_board(self, /) -> Awaitable[ControlBoardData |None]: ...
     def get_control_board_version(self, /) -> Awaitable[str |None]: ...
     def get_data(self, /, exclude: "list[DataField] | None" = None) -> Awaitable[MinerData]: ...
     def get_expected_hashrate(self, /) -> Awaitable[HashRate |None]: ...
@@ -377,10 +439,13 @@ class Miner:
     def get_light_flashing(self, /) -> Awaitable[bool |None]: ...
     def get_mac(self, /) -> Awaitable[str |None]: ...
     def get_messages(self, /) -> Awaitable[list[MinerMessage]]: ...
+    def get_network(self, /) -> Awaitable[NetworkData |None]: ...
+    def get_network_config(self, /) -> Awaitable[NetworkConfig |None]: ...
     def get_outlet_fluid_temperature(self, /) -> Awaitable[float |None]: ...
     def get_pools(self, /) -> Awaitable[list[PoolGroupData]]: ...
     def get_pools_config(self, /) -> Awaitable[list[PoolGroup] |None]: ...
     def get_psu_fans(self, /) -> Awaitable[list[FanData]]: ...
+    def get_psus(self, /) -> Awaitable[list[PsuData]]: ...
     def get_scaling_config(self, /) -> Awaitable[ScalingConfig |None]: ...
     def get_serial_number(self, /) -> Awaitable[str |None]: ...
     def get_temperature_config(self, /) -> Awaitable[TemperatureConfig |None]: ...
@@ -401,13 +466,18 @@ class Miner:
     def read_logs(self, /) -> Awaitable[str |None]: ...
     def restart(self, /) -> Awaitable[bool |None]: ...
     def resume(self, /, at_time: "timedelta | float | int | None" = None) -> Awaitable[bool |None]: ...
+    def set_appliance_config(self, /, config: "ApplianceConfig") -> Awaitable[bool |None]: ...
     def set_auth(self, /, username: str, password: str) -> None: ...
     def set_fan_config(self, /, config: "FanConfig") -> Awaitable[bool |None]: ...
     def set_fault_light(self, /, fault: bool) -> Awaitable[bool |None]: ...
+    def set_key(self, /, username: str, private_key: str, passphrase: str | None = None) -> None: ...
+    def set_network_config(self, /, config: "NetworkConfig", allow_readdress: "bool" = False) -> Awaitable[bool |None]: ...
     def set_pools_config(self, /, groups: "list[PoolGroup]") -> Awaitable[bool |None]: ...
     def set_power_limit(self, /, watts: float) -> Awaitable[bool |None]: ...
     def set_tuning_percent(self, /, percent: int) -> Awaitable[bool |None]: ...
     def set_scaling_config(self, /, config: "ScalingConfig") -> Awaitable[bool |None]: ...
+    def set_syslog_target(self, /, target: "SyslogTarget | None" = None) -> Awaitable[bool |None]: ...
+    def set_ssh_auth(self, /, username: str, password: str) -> None: ...
     def set_token(self, /, token: str) -> None: ...
     def set_tuning_config(self, /, config: "TuningConfig", scaling_config: "ScalingConfig | None" = None) -> Awaitable[bool |None]: ...
     @property
@@ -417,6 +487,10 @@ class Miner:
     @property
     def supports_fan_config(self, /) -> bool: ...
     @property
+    def supports_get_fan_config(self, /) -> bool: ...
+    @property
+    def supports_network_config(self, /) -> bool: ...
+    @property
     def supports_pause(self, /) -> bool: ...
     @property
     def supports_pools_config(self, /) -> bool: ...
@@ -429,10 +503,14 @@ class Miner:
     @property
     def supports_scaling_config(self, /) -> bool: ...
     @property
+    def supports_set_appliance_config(self, /) -> bool: ...
+    @property
     def supports_set_fault_light(self, /) -> bool: ...
     @property
     def supports_set_power_limit(self, /) -> bool: ...
     @property
+    def supports_set_syslog_target(self, /) -> bool: ...
+    @property
     def supports_set_tuning_percent(self, /) -> bool: ...
     @property
     def supports_temperature_config(self, /) -> bool: ...
@@ -521,8 +599,12 @@ class MinerData:
     @property
     def api_version(self, /) -> str |None: ...
     @property
+    def appliance(self, /) -> ApplianceConfig |None: ...
+    @property
     def average_temperature(self, /) -> float |None: ...
     @property
+    def control_board(self, /) -> ControlBoardData |None: ...
+    @property
     def control_board_version(self, /) -> MinerControlBoard |None: ...
     @property
     def device_info(self, /) -> DeviceInfo: ...
@@ -564,12 +646,16 @@ class MinerData:
     @classmethod
     def model_validate(cls, /, obj: "object", **_kwargs: "object") -> "MinerData": ...
     @property
+    def network(self, /) -> NetworkData |None: ...
+    @property
     def outlet_fluid_temperature(self, /) -> float |None: ...
     @property
     def pools(self, /) -> list[PoolGroupData]: ...
     @property
     def psu_fans(self, /) -> list[FanData]: ...
     @property
+    def psus(self, /) -> list[PsuData]: ...
+    @property
     def scaled_tuning_target(self, /) -> TuningTarget |None: ...
     @property
     def schema_version(self, /) -> str: ...
@@ -680,6 +766,73 @@ class MiningMode:
     @classmethod
     def model_validate(cls, /, obj: "object", **_kwargs: "object") -> "MiningMode": ...
 
+@final
+class NetworkConfig:
+    @classmethod
+    def __get_pydantic_core_schema__(cls, /, _source_type: "object", _handler: "object") -> "object": ...
+    def __new__(cls, /, *, dhcp: "bool", ip: "IPv4Address | IPv6Address | None" = None, netmask: "IPv4Address | IPv6Address | None" = None, gateway: "IPv4Address | IPv6Address | None" = None, dns: "list[IPv4Address | IPv6Address] | None" = None, hostname: "str | None" = None) -> "NetworkConfig": ...
+    def __repr__(self, /) -> str: ...
+    @staticmethod
+    def _pydantic_serialize(value: "NetworkConfig") -> "dict[str, object]": ...
+    @classmethod
+    def _pydantic_validate(cls, /, value: "object") -> "NetworkConfig": ...
+    @property
+    def dhcp(self, /) -> bool: ...
+    @property
+    def dns(self, /) -> list[IPv4Address |IPv6Address] |None: ...
+    @property
+    def gateway(self, /) -> IPv4Address |IPv6Address |None: ...
+    @property
+    def hostname(self, /) -> str |None: ...
+    @property
+    def ip(self, /) -> IPv4Address |IPv6Address |None: ...
+    def model_dump(self, /, **_kwargs: "object") -> "dict[str, object]": ...
+    @classmethod
+    def model_json_schema(cls, /, **kwargs: "object") -> "dict[str, object]": ...
+    @classmethod
+    def model_validate(cls, /, obj: "object", **_kwargs: "object") -> "NetworkConfig": ...
+    @property
+    def netmask(self, /) -> IPv4Address |IPv6Address |None: ...
+
+@final
+class NetworkData:
+    @classmethod
+    def __get_pydantic_core_schema__(cls, /, _source_type: "object", _handler: "object") -> "object": ...
+    def __repr__(self, /) -> str: ...
+    @staticmethod
+    def _pydantic_serialize(value: "NetworkData") -> "dict[str, object]": ...
+    @classmethod
+    def _pydantic_validate(cls, /, value: "object") -> "NetworkData": ...
+    @property
+    def dhcp(self, /) -> bool |None: ...
+    @property
+    def dns(self, /) -> list[IPv4Address |IPv6Address]: ...
+    @property
+    def full_duplex(self, /) -> bool |None: ...
+    @property
+    def gateway(self, /) -> IPv4Address |IPv6Address |None: ...
+    @property
+    def interface(self, /) -> str |None: ...
+    @property
+    def ipv6_addresses(self, /) -> list[IPv4Address |IPv6Address]: ...
+    @property
+    def link_speed(self, /) -> int |None: ...
+    def model_dump(self, /, **_kwargs: "object") -> "dict[str, object]": ...
+    @classmethod
+    def model_json_schema(cls, /, **kwargs: "object") -> "dict[str, object]": ...
+    @classmethod
+    def model_validate(cls, /, obj: "object", **_kwargs: "object") -> "NetworkData": ...
+    @property
+    def netmask(self, /) -> IPv4Address |IPv6Address |None: ...
+    @property
+    def rx_dropped(self, /) -> int |None: ...
+    @property
+    def rx_errors(self, /) -> int |None: ...
+    @property
+    def tx_dropped(self, /) -> int |None: ...
+    @property
+    def tx_errors(self, /) -> int |None: ...
+
 @final
 class Pool:
     @classmethod
@@ -857,6 +1010,41 @@ class PresetTuningCapabilities:
     @property
     def presets(self, /) -> list[TuningTarget]: ...
 
+@final
+class PsuData:
+    @classmethod
+    def __get_pydantic_core_schema__(cls, /, _source_type: "object", _handler: "object") -> "object": ...
+    def __repr__(self, /) -> str: ...
+    @staticmethod
+    def _pydantic_serialize(value: "PsuData") -> "dict[str, object]": ...
+    @classmethod
+    def _pydantic_validate(cls, /, value: "object") -> "PsuData": ...
+    @property
+    def fan_rpm(self, /) -> float |None: ...
+    @property
+    def firmware_version(self, /) -> str |None: ...
+    @property
+    def input_current(self, /) -> float |None: ...
+    @property
+    def input_voltage(self, /) -> float |None: ...
+    @property
+    def model(self, /) -> str |None: ...
+    def model_dump(self, /, **_kwargs: "object") -> "dict[str, object]": ...
+    @classmethod
+    def model_json_schema(cls, /, **kwargs: "object") -> "dict[str, object]": ...
+    @classmethod
+    def model_validate(cls, /, obj: "object", **_kwargs: "object") -> "PsuData": ...
+    @property
+    def output_power(self, /) -> float |None: ...
+    @property
+    def output_voltage(self, /) -> float |None: ...
+    @property
+    def position(self, /) -> int: ...
+    @property
+    def serial_number(self, /) -> str |None: ...
+    @property
+    def temperature(self, /) -> float |None: ...
+
 @final
 class ScalingConfig:
     @classmethod
@@ -881,6 +1069,42 @@ class ScalingConfig:
     @property
     def step(self, /) -> int: ...
 
+@final
+class SyslogProtocol:
+    Tcp: Final[SyslogProtocol]
+    Udp: Final[SyslogProtocol]
+    @classmethod
+    def __get_pydantic_core_schema__(cls, /, _source_type: "object", _handler: "object") -> "object": ...
+    def __int__(self, /) -> int: ...
+    def __repr__(self, /) -> str: ...
+    def __str__(self, /) -> str: ...
+    @staticmethod
+    def _pydantic_serialize(value: "SyslogProtocol") -> "object": ...
+    @classmethod
+    def _pydantic_validate(cls, /, value: "object") -> "SyslogProtocol": ...
+
+@final
+class SyslogTarget:
+    @classmethod
+    def __get_pydantic_core_schema__(cls, /, _source_type: "object", _handler: "object") -> "object": ...
+    def __new__(cls, /, *, host: "IPv4Address | IPv6Address", port: "int", protocol: "SyslogProtocol") -> "SyslogTarget": ...
+    def __repr__(self, /) -> str: ...
+    @staticmethod
+    def _pydantic_serialize(value: "SyslogTarget") -> "dict[str, object]": ...
+    @classmethod
+    def _pydantic_validate(cls, /, value: "object") -> "SyslogTarget": ...
+    @property
+    def host(self, /) -> IPv4Address |IPv6Address: ...
+    def model_dump(self, /, **_kwargs: "object") -> "dict[str, object]": ...
+    @classmethod
+    def model_json_schema(cls, /, **kwargs: "object") -> "dict[str, object]": ...
+    @classmethod
+    def model_validate(cls, /, obj: "object", **_kwargs: "object") -> "SyslogTarget": ...
+    @property
+    def port(self, /) -> int: ...
+    @property
+    def protocol(self, /) -> SyslogProtocol: ...
+
 @final
 class TemperatureConfig:
     @classmethod
@@ -935,8 +1159,10 @@ class TuningConfig:
     def _pydantic_validate(cls, /, value: "object") -> "TuningConfig": ...
     @property
     def algorithm(self, /) -> str |None: ...
+    @property
+    def frequency(self, /) -> float |None: ...
     @classmethod
-    def hashrate(cls, /, hashrate: HashRate, algorithm: Any |None = None) -> TuningConfig: ...
+    def hashrate(cls, /, hashrate: HashRate, algorithm: Any |None = None, voltage: float |None = None, frequency: float |None = None) -> TuningConfig: ...
     @classmethod
     def mode(cls, /, mode: MiningMode) -> TuningConfig: ...
     def model_dump(self, /, **_kwargs: "object") -> "dict[str, object]": ...
@@ -945,7 +1171,7 @@ class TuningConfig:
     @classmethod
     def model_validate(cls, /, obj: "object", **_kwargs: "object") -> "TuningConfig": ...
     @classmethod
-    def power(cls, /, watts: float, algorithm: Any |None = None) -> TuningConfig: ...
+    def power(cls, /, watts: float, algorithm: Any |None = None, voltage: float |None = None, frequency: float |None = None) -> TuningConfig: ...
     @property
     def target_hashrate(self, /) -> HashRate |None: ...
     @property
@@ -954,6 +1180,8 @@ class TuningConfig:
     def target_watts(self, /) -> float |None: ...
     @property
     def variant(self, /) -> str: ...
+    @property
+    def voltage(self, /) -> float |None: ...
 
 class TuningTarget:
     def __repr__(self, /) -> str: ...
@@ -4,18 +4,25 @@ These classes are backed by Rust structs and expose Pydantic-compatible
 `model_validate`, `model_dump`, and `model_json_schema` methods.
 """
 
+from pyasic_rs.asic_rs import ApplianceConfig
 from pyasic_rs.asic_rs import FanConfig, FanMode
+from pyasic_rs.asic_rs import NetworkConfig
 from pyasic_rs.asic_rs import Pool, PoolGroup
 from pyasic_rs.asic_rs import ScalingConfig
+from pyasic_rs.asic_rs import SyslogProtocol, SyslogTarget
 from pyasic_rs.asic_rs import TuningConfig
 from pyasic_rs.asic_rs import TemperatureConfig
 
 __all__ = [
+    "ApplianceConfig",
     "FanConfig",
     "FanMode",
+    "NetworkConfig",
     "Pool",
     "PoolGroup",
     "ScalingConfig",
+    "SyslogProtocol",
+    "SyslogTarget",
     "TuningConfig",
     "TemperatureConfig",
 ]
@@ -6,6 +6,7 @@ applicable.
 """
 
 from pyasic_rs.asic_rs import BoardData, ChipData
+from pyasic_rs.asic_rs import ControlBoardData
 from pyasic_rs.asic_rs import (
     HashRateTuningCapabilities,
     PowerTuningCapabilities,
@@ -18,13 +19,16 @@ from pyasic_rs.asic_rs import HashRate, HashRateUnit
 from pyasic_rs.asic_rs import MinerComponent, MinerControlBoard, MinerData, MinerMessage
 from pyasic_rs.asic_rs import MessageSeverity
 from pyasic_rs.asic_rs import MiningMode
+from pyasic_rs.asic_rs import NetworkData
 from pyasic_rs.asic_rs import PoolData, PoolGroupData, PoolScheme, PoolURL
+from pyasic_rs.asic_rs import PsuData
 from pyasic_rs.asic_rs import TuningTarget
 from pyasic_rs.asic_rs import DataField
 
 __all__ = [
     "BoardData",
     "ChipData",
+    "ControlBoardData",
     "DeviceInfo",
     "HashRateTuningCapabilities",
     "PowerTuningCapabilities",
@@ -40,10 +44,12 @@ __all__ = [
     "MinerMessage",
     "MessageSeverity",
     "MiningMode",
+    "NetworkData",
     "PoolData",
     "PoolGroupData",
     "PoolScheme",
     "PoolURL",
+    "PsuData",
     "TuningTarget",
     "DataField",
 ]
@@ -101,9 +101,11 @@ def minimal_miner_data(**overrides: object) -> dict[str, object]:
         },
         "serial_number": None,
         "hostname": None,
+        "network": None,
         "api_version": None,
         "firmware_version": None,
         "control_board_version": None,
+        "control_board": None,
         "expected_hashboards": None,
         "hashboards": [],
         "hashrate": None,
@@ -113,6 +115,7 @@ def minimal_miner_data(**overrides: object) -> dict[str, object]:
         "expected_fans": None,
         "fans": [],
         "psu_fans": [],
+        "psus": [],
         "average_temperature": None,
         "fluid_temperature": None,
         "outlet_fluid_temperature": None,
@@ -123,6 +126,7 @@ def minimal_miner_data(**overrides: object) -> dict[str, object]:
         "tuning_capabilities": None,
         "efficiency": None,
         "light_flashing": None,
+        "appliance": None,
         "messages": [],
         "uptime": None,
         "is_mining": False,
@@ -503,6 +507,8 @@ def test_tuning_config_validates_canonical_target_shape() -> None:
                 },
             },
             "algorithm": "SHA256",
+            "voltage": None,
+            "frequency": None,
         }
     }
 
@@ -527,6 +533,8 @@ def test_tuning_config_accepts_hash_algorithm_enum() -> None:
         "tuning": {
             "target": {"type": "power", "value": 3250.0},
             "algorithm": "Kadena",
+            "voltage": None,
+            "frequency": None,
         }
     }
 
@@ -538,10 +546,47 @@ def test_tuning_config_mode_accepts_mining_mode_enum() -> None:
 
     assert model.tuning.target_mode == MiningMode.High
     assert model.model_dump() == {
-        "tuning": {"target": {"type": "mode", "value": "High"}, "algorithm": None}
+        "tuning": {
+            "target": {"type": "mode", "value": "High"},
+            "algorithm": None,
+            "voltage": None,
+            "frequency": None,
+        }
     }
 
 
+def test_tuning_config_carries_core_voltage() -> None:
+    config = TuningConfig.hashrate(HashRate(1.2, HashRateUnit.TH), voltage=1.166)
+    model = TuningConfigModel.model_validate(
+        {
+            "tuning": {
+                "target": {"type": "power", "value": 20.0},
+                "voltage": 1.2,
+            }
+        }
+    )
+
+    assert config.voltage == 1.166
+    assert model.tuning.voltage == 1.2
+    assert model.model_dump()["tuning"]["voltage"] == 1.2
+
+
+def test_tuning_config_carries_chip_frequency() -> None:
+    config = TuningConfig.power(3250.0, voltage=13.0, frequency=650.0)
+    model = TuningConfigModel.model_validate(
+        {
+            "tuning": {
+                "target": {"type": "power", "value": 3250.0},
+                "frequency": 650.0,
+            }
+        }
+    )
+
+    assert config.frequency == 650.0
+    assert model.tuning.frequency == 650.0
+    assert model.model_dump()["tuning"]["frequency"] == 650.0
+
+
 def test_tuning_config_mode_json_schema_exposes_mining_mode_enum() -> None:
     schema = TuningConfigModel.model_json_schema()
 
@@ -576,7 +621,7 @@ def test_tuning_config_mode_rejects_unknown_values(mode: str) -> None:
     [
         (TuningConfigModel, {"tuning": {"target": {"type": "power", "value": "fast"}}}),
         (FanConfigModel, {"fan": {"mode": "manual", "target_temp": 65.0}}),
-        (FanConfigModel, {"fan": {"mode": "auto", "fan_speed": 75}}),
+        (FanConfigModel, {"fan": {"mode": "manual", "idle_speed": 30}}),
     ],
 )
 def test_tagged_config_models_reject_wrong_discriminators(
@@ -614,6 +659,27 @@ def test_tagged_config_repr_is_readable() -> None:
     assert repr(FanConfig.manual(75)) == "FanConfig.manual(fan_speed=75)"
 
 
+def test_auto_fan_config_target_is_optional() -> None:
+    config = FanConfig.auto()
+    model = FanConfigModel.model_validate({"fan": {"mode": "auto"}})
+
+    assert config.target_temp is None
+    assert repr(config) == "FanConfig.auto(target_temp=None, idle_speed=None)"
+    assert model.fan.target_temp is None
+
+
+def test_fan_config_min_fans_round_trips() -> None:
+    config = FanConfig.manual(75).with_min_fans(2)
+    model = FanConfigModel.model_validate(
+        {"fan": {"mode": "manual", "fan_speed": 75, "min_fans": 2}}
+    )
+
+    assert config.min_fans == 2
+    assert FanConfig.manual(75).min_fans is None
+    assert repr(config) == "FanConfig.manual(fan_speed=75).with_min_fans(2)"
+    assert model.fan.min_fans == 2
+
+
 @pytest.mark.parametrize(
     ("mode", "name"),
     [
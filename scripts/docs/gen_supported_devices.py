@@ -17,6 +17,7 @@ SUPPORT_COLUMNS = [
     ("SupportsScalingConfig", "supports_scaling_config", "Scaling Config"),
     ("SupportsTuningConfig", "supports_tuning_config", "Tuning Config"),
     ("SupportsFanConfig", "supports_fan_config", "Fan Config"),
+    ("SupportsNetworkConfig", "supports_network_config", "Network Config"),
     ("SetFaultLight", "supports_set_fault_light", "Light"),
     ("SetPowerLimit", "supports_set_power_limit", "Power Limit"),
     ("Restart", "supports_restart", "Restart"),
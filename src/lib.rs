@@ -2,6 +2,7 @@
 
 pub use factory::MinerFactory;
 pub use listener::MinerListener;
+pub use syslog::SyslogListener;
 
 #[cfg(feature = "core")]
 pub use asic_rs_core as core;
@@ -37,3 +38,4 @@ pub mod factory;
 pub mod listener;
 #[cfg(feature = "python")]
 mod python;
+pub mod syslog;
@@ -4,18 +4,22 @@ use super::typing::{PyAwaitable, future_into_py};
 use asic_rs_core::data::collector::DataField;
 use asic_rs_core::{
     config::{
-        fan::FanConfig, pools::PoolGroupConfig as PoolGroup, scaling::ScalingConfig,
+        appliance::ApplianceConfig, fan::FanConfig, network::NetworkConfig,
+        pools::PoolGroupConfig as PoolGroup, scaling::ScalingConfig, syslog::SyslogTarget,
         temperature::TemperatureConfig, tuning::TuningConfig,
     },
     data::{
         board::BoardData,
+        control_board::ControlBoardData,
         device::{HashAlgorithm, MinerHardware},
         fan::FanData,
         firmware::FirmwareImage,
         hashrate::HashRate,
         message::MinerMessage,
         miner::{MinerData, TuningTarget},
+        network::NetworkData,
         pool::PoolGroupData,
+        psu::PsuData,
     },
     traits::{auth::MinerAuth, miner::Miner as MinerTrait},
 };
@@ -61,6 +65,14 @@ impl Miner {
             .set_auth(auth);
         Ok(())
     }
+
+    fn apply_ssh_auth(&mut self, auth: MinerAuth) -> PyResult<()> {
+        Arc::get_mut(&mut self.inner)
+            .ok_or_else(|| PyRuntimeError::new_err("cannot set auth while miner is in use"))?
+            .get_mut()
+            .set_ssh_auth(auth);
+        Ok(())
+    }
 }
 
 impl From<Box<dyn MinerTrait>> for Miner {
@@ -183,6 +195,11 @@ impl Miner {
     fn supports_set_tuning_percent(&self, py: Python<'_>) -> bool {
         self.with_miner(py, |miner| miner.supports_set_tuning_percent())
     }
+    /// Whether this miner supports changing home-miner appliance settings.
+    #[getter]
+    fn supports_set_appliance_config(&self, py: Python<'_>) -> bool {
+        self.with_miner(py, |miner| miner.supports_set_appliance_config())
+    }
     /// Whether this miner supports restart commands.
     #[getter]
     fn supports_restart(&self, py: Python<'_>) -> bool {
@@ -208,6 +225,11 @@ impl Miner {
     fn supports_read_logs(&self, py: Python<'_>) -> bool {
         self.with_miner(py, |miner| miner.supports_read_logs())
     }
+    /// Whether this miner supports forwarding its log to a syslog receiver.
+    #[getter]
+    fn supports_set_syslog_target(&self, py: Python<'_>) -> bool {
+        self.with_miner(py, |miner| miner.supports_set_syslog_target())
+    }
     /// Whether this miner supports factory reset.
     #[getter]
     fn supports_factory_reset(&self, py: Python<'_>) -> bool {
@@ -243,6 +265,16 @@ impl Miner {
     fn supports_fan_config(&self, py: Python<'_>) -> bool {
         self.with_miner(py, |miner| miner.supports_fan_config())
     }
+    /// Whether this miner can read its fan configuration back.
+    #[getter]
+    fn supports_get_fan_config(&self, py: Python<'_>) -> bool {
+        self.with_miner(py, |miner| miner.supports_get_fan_config())
+    }
+    /// Whether this miner supports network configuration.
+    #[getter]
+    fn supports_network_config(&self, py: Python<'_>) -> bool {
+        self.with_miner(py, |miner| miner.supports_network_config())
+    }
     /// Set username/password credentials used by subsequent operations on this
     /// miner.
     ///
@@ -262,6 +294,30 @@ impl Miner {
         self.apply_auth(MinerAuth::from_token(token))
     }
 
+    /// Set an SSH password for backends that reach the miner over SSH. Other
+    /// APIs keep the credentials from `set_auth`.
+    ///
+    /// Call this before starting concurrent operations. It raises `RuntimeError`
+    /// if the miner handle is already shared by an active async operation.
+    pub fn set_ssh_auth(&mut self, username: String, password: String) -> PyResult<()> {
+        self.apply_ssh_auth(MinerAuth::new(username, password))
+    }
+
+    /// Set a private key for backends that reach the miner over SSH. The key is
+    /// an OpenSSH or PEM encoded string, not a path.
+    ///
+    /// Call this before starting concurrent operations. It raises `RuntimeError`
+    /// if the miner handle is already shared by an active async operation.
+    #[pyo3(signature = (username, private_key, passphrase = None))]
+    pub fn set_key(
+        &mut self,
+        username: String,
+        private_key: String,
+        passphrase: Option<String>,
+    ) -> PyResult<()> {
+        self.apply_ssh_auth(MinerAuth::from_key(username, private_key, passphrase))
+    }
+
     // Data functions
     /// Await a full telemetry snapshot.
     ///
@@ -309,6 +365,14 @@ impl Miner {
             Ok(data)
         })
     }
+    /// Await link state, addressing and error counters of the network interface.
+    pub fn get_network<'a>(&self, py: Python<'a>) -> PyResult<PyAwaitable<Option<NetworkData>>> {
+        let inner = Arc::clone(&self.inner);
+        future_into_py(py, async move {
+            let inner = inner.read().await;
+            Ok(inner.get_network().await)
+        })
+    }
     /// Await the miner API version, if exposed by the firmware.
     pub fn get_api_version<'a>(&self, py: Python<'a>) -> PyResult<PyAwaitable<Option<String>>> {
         let inner = Arc::clone(&self.inner);
@@ -345,6 +409,17 @@ impl Miner {
             Ok(data)
         })
     }
+    /// Await control board system statistics such as CPU load, memory and OS version.
+    pub fn get_control_board<'a>(
+        &self,
+        py: Python<'a>,
+    ) -> PyResult<PyAwaitable<Option<ControlBoardData>>> {
+        let inner = Arc::clone(&self.inner);
+        future_into_py(py, async move {
+            let inner = inner.read().await;
+            Ok(inner.get_control_board().await)
+        })
+    }
     /// Await per-hashboard data including chip details where available.
     pub fn get_hashboards<'a>(&self, py: Python<'a>) -> PyResult<PyAwaitable<Vec<BoardData>>> {
         let inner = Arc::clone(&self.inner);
@@ -401,6 +476,14 @@ impl Miner {
             Ok(inner.get_psu_fans().await)
         })
     }
+    /// Await power-supply telemetry.
+    pub fn get_psus<'a>(&self, py: Python<'a>) -> PyResult<PyAwaitable<Vec<PsuData>>> {
+        let inner = Arc::clone(&self.inner);
+        future_into_py(py, async move {
+            let inner = inner.read().await;
+            Ok(inner.get_psus().await)
+        })
+    }
     /// Await fluid or ambient temperature in Celsius, if available.
     pub fn get_fluid_temperature<'a>(&self, py: Python<'a>) -> PyResult<PyAwaitable<Option<f64>>> {
         let inner = Arc::clone(&self.inner);
@@ -459,6 +542,17 @@ impl Miner {
             Ok(data)
         })
     }
+    /// Await home-miner appliance settings (screen), if exposed.
+    pub fn get_appliance_config<'a>(
+        &self,
+        py: Python<'a>,
+    ) -> PyResult<PyAwaitable<Option<ApplianceConfig>>> {
+        let inner = Arc::clone(&self.inner);
+        future_into_py(py, async move {
+            let inner = inner.read().await;
+            Ok(inner.get_appliance_config().await)
+        })
+    }
     /// Await current miner messages and errors.
     pub fn get_messages<'a>(&self, py: Python<'a>) -> PyResult<PyAwaitable<Vec<MinerMessage>>> {
         let inner = Arc::clone(&self.inner);
@@ -546,6 +640,17 @@ impl Miner {
             Ok(inner.get_fan_config().await.ok())
         })
     }
+    /// Await network configuration, or `None` when unsupported/unavailable.
+    pub fn get_network_config<'a>(
+        &self,
+        py: Python<'a>,
+    ) -> PyResult<PyAwaitable<Option<NetworkConfig>>> {
+        let inner = Arc::clone(&self.inner);
+        future_into_py(py, async move {
+            let inner = inner.read().await;
+            Ok(inner.get_network_config().await.ok())
+        })
+    }
 
     // Control functions
     /// Set the fault light state.
@@ -667,6 +772,33 @@ impl Miner {
             Ok(inner.set_tuning_percent(percent).await.ok())
         })
     }
+    /// Apply home-miner appliance settings; `None` fields are left unchanged.
+    #[pyo3(signature = (config: "ApplianceConfig"))]
+    pub fn set_appliance_config<'a>(
+        &self,
+        py: Python<'a>,
+        config: ApplianceConfig,
+    ) -> PyResult<PyAwaitable<Option<bool>>> {
+        let inner = Arc::clone(&self.inner);
+        future_into_py(py, async move {
+            let inner = inner.read().await;
+            Ok(inner.set_appliance_config(config).await.ok())
+        })
+    }
+    /// Forward the miner's system log to a syslog receiver, or stop
+    /// forwarding when `target` is `None`.
+    #[pyo3(signature = (target: "SyslogTarget | None" = None))]
+    pub fn set_syslog_target<'a>(
+        &self,
+        py: Python<'a>,
+        target: Option<SyslogTarget>,
+    ) -> PyResult<PyAwaitable<Option<bool>>> {
+        let inner = Arc::clone(&self.inner);
+        future_into_py(py, async move {
+            let inner = inner.read().await;
+            Ok(inner.set_syslog_target(target).await.ok())
+        })
+    }
     /// Replace the configured mining pool groups.
     #[pyo3(signature = (groups: "list[PoolGroup]"))]
     pub fn set_pools_config<'a>(
@@ -720,6 +852,30 @@ impl Miner {
             Ok(inner.set_fan_config(config).await.ok())
         })
     }
+    /// Set network configuration.
+    ///
+    /// Static configurations are validated before anything is sent; an
+    /// incomplete or inconsistent config, or one that moves the miner to a
+    /// new IP or to DHCP without `allow_readdress`, returns `None` and leaves
+    /// the miner untouched.
+    #[pyo3(signature = (config: "NetworkConfig", allow_readdress: "bool" = false))]
+    pub fn set_network_config<'a>(
+        &self,
+        py: Python<'a>,
+        config: NetworkConfig,
+        allow_readdress: bool,
+    ) -> PyResult<PyAwaitable<Option<bool>>> {
+        let inner = Arc::clone(&self.inner);
+        future_into_py(py, async move {
+            let inner = inner.read().await;
+            let result = if allow_readdress {
+                inner.set_network_config_readdress(config).await
+            } else {
+                inner.set_network_config(config).await
+            };
+            Ok(result.ok())
+        })
+    }
     /// Upload and apply a firmware image from a local path.
     pub fn upgrade_firmware<'a>(
         &self,
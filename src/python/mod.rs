@@ -29,9 +29,12 @@ mod asic_rs {
     use super::miner::Miner;
     #[pymodule_export]
     use asic_rs_core::config::{
+        appliance::ApplianceConfig,
         fan::{FanConfig, FanMode},
+        network::NetworkConfig,
         pools::{PoolConfig as Pool, PoolGroupConfig as PoolGroup},
         scaling::ScalingConfig,
+        syslog::{SyslogProtocol, SyslogTarget},
         temperature::TemperatureConfig,
         tuning::TuningConfig,
     };
@@ -42,10 +45,13 @@ mod asic_rs {
             HashRateTuningCapabilities, PowerTuningCapabilities, PresetTuningCapabilities,
             TuningCapabilities,
         },
+        control_board::ControlBoardData,
         device::{DeviceInfo, MinerHardware},
         fan::FanData,
         message::{MessageSeverity, MinerComponent, MinerMessage},
         miner::{MinerData, PyTuningTarget as TuningTarget},
+        network::NetworkData,
         pool::{PoolData, PoolGroupData, PoolScheme, PoolURL},
+        psu::PsuData,
     };
 }
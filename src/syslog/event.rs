@@ -0,0 +1,52 @@
+use std::net::IpAddr;
+
+use asic_rs_core::data::message::{MessageSeverity, MinerComponent};
+use serde::{Deserialize, Serialize};
+
+/// What a firmware log line reports, for the lines asic-rs recognises.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type")]
+pub enum SyslogEventKind {
+    /// The mining process started or restarted.
+    MinerStarted,
+    /// A pool connection was established or the miner switched to a pool.
+    PoolConnected { url: Option<String> },
+    /// A pool stopped responding or its connection dropped.
+    PoolDisconnected { url: Option<String> },
+    /// A share was rejected by the pool.
+    ShareRejected,
+    /// A hashboard reported fewer chips than expected.
+    ChipCountMismatch { found: u16, expected: Option<u16> },
+    /// A hashboard failed or was shut down.
+    HashboardFailure,
+    /// A temperature limit was exceeded.
+    Overheat,
+    /// A fan stopped or could not be read.
+    FanFailure,
+    /// The power supply reported a fault.
+    PowerFailure,
+    /// Any other line.
+    Other,
+}
+
+/// A syslog message received from a miner.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SyslogEvent {
+    /// Address the message was received from.
+    pub ip: IpAddr,
+    /// Unix time the message was received, in seconds.
+    pub timestamp: u64,
+    /// Severity from the syslog priority, or from a level embedded in the
+    /// message when the firmware logs everything at one priority.
+    pub severity: MessageSeverity,
+    /// Hostname from the syslog header, if sent.
+    pub hostname: Option<String>,
+    /// Program that logged the line, e.g. `bmminer`, without the PID.
+    pub program: Option<String>,
+    /// The log message, without the syslog header.
+    pub message: String,
+    /// What the line reports.
+    pub kind: SyslogEventKind,
+    /// Component the line refers to, if any.
+    pub component: Option<MinerComponent>,
+}
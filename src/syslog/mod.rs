@@ -0,0 +1,226 @@
+//! Remote syslog receiver for miner logs.
+//!
+//! Point miners at this host with
+//! [`SetSyslogTarget`](asic_rs_core::traits::miner::SetSyslogTarget), or in
+//! the firmware's web UI, and [`SyslogListener`] turns the lines they forward
+//! into [`SyslogEvent`]s attributed by source address.
+
+use std::io;
+use std::net::{IpAddr, Ipv4Addr};
+use std::pin::Pin;
+
+use asic_rs_core::util::unix_timestamp_secs;
+use async_stream::stream;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt, AsyncReadExt, BufReader};
+use tokio::net::{TcpListener, UdpSocket};
+use tokio::sync::mpsc;
+use tokio_stream::{Stream, StreamExt};
+
+mod event;
+mod parse;
+
+pub use event::{SyslogEvent, SyslogEventKind};
+
+use parse::parse_syslog_line;
+
+/// Longest message accepted over TCP, per RFC 5425 recommendations.
+const MAX_FRAME_LEN: usize = 8192;
+
+pub struct SyslogListener {
+    bind_addr: IpAddr,
+    port: u16,
+}
+
+impl Default for SyslogListener {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SyslogListener {
+    /// Listen on all interfaces on the standard syslog port, `514`. Binding
+    /// it usually needs elevated privileges; see [`with_port`](Self::with_port).
+    pub fn new() -> Self {
+        SyslogListener {
+            bind_addr: IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+            port: 514,
+        }
+    }
+
+    pub fn with_bind_addr(mut self, bind_addr: IpAddr) -> Self {
+        self.bind_addr = bind_addr;
+        self
+    }
+
+    pub fn with_port(mut self, port: u16) -> Self {
+        self.port = port;
+        self
+    }
+
+    /// Receive syslog messages over UDP and TCP on the same port.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use asic_rs::syslog::SyslogListener;
+    /// use futures::pin_mut;
+    /// use tokio_stream::StreamExt;
+    ///
+    /// #[tokio::main]
+    /// async fn main() -> () {
+    ///     let listener = SyslogListener::new().with_port(5514);
+    ///     let stream = listener.listen().await;
+    ///     pin_mut!(stream);
+    ///
+    ///     while let Some(Ok(event)) = stream.next().await {
+    ///         println!("{}: {:?} {}", event.ip, event.kind, event.message)
+    ///     }
+    /// }
+    /// ```
+    pub async fn listen(&self) -> Pin<Box<dyn Stream<Item = anyhow::Result<SyslogEvent>> + '_>> {
+        let udp_stream = self.listen_udp().await;
+        let tcp_stream = self.listen_tcp().await;
+
+        let stream = udp_stream.merge(tcp_stream);
+
+        Box::pin(stream)
+    }
+
+    pub(crate) async fn listen_udp(&self) -> impl Stream<Item = anyhow::Result<SyslogEvent>> {
+        let (bind_addr, port) = (self.bind_addr, self.port);
+        stream! {
+            let sock = match UdpSocket::bind((bind_addr, port)).await {
+                Ok(s) => s,
+                Err(e) => {
+                    yield Err(anyhow::anyhow!("Failed to bind UDP port {port}: {e}"));
+                    return;
+                }
+            };
+            let mut buf = vec![0u8; MAX_FRAME_LEN];
+
+            loop {
+                match sock.recv_from(&mut buf).await {
+                    Ok((len, addr)) => {
+                        let line = String::from_utf8_lossy(&buf[..len]);
+                        yield Ok(parse_syslog_line(addr.ip(), unix_timestamp_secs(), &line));
+                    }
+                    Err(e) => {
+                        tracing::warn!("UDP recv error on syslog port {port}: {e}");
+                        continue;
+                    }
+                }
+            }
+        }
+    }
+
+    pub(crate) async fn listen_tcp(&self) -> impl Stream<Item = anyhow::Result<SyslogEvent>> {
+        let (bind_addr, port) = (self.bind_addr, self.port);
+        stream! {
+            let listener = match TcpListener::bind((bind_addr, port)).await {
+                Ok(l) => l,
+                Err(e) => {
+                    yield Err(anyhow::anyhow!("Failed to bind TCP port {port}: {e}"));
+                    return;
+                }
+            };
+
+            // Each connection is read on its own task; the accept loop stops
+            // once the stream is dropped.
+            let (tx, mut rx) = mpsc::channel::<(IpAddr, String)>(1024);
+            tokio::spawn(async move {
+                loop {
+                    let (socket, addr) = tokio::select! {
+                        _ = tx.closed() => break,
+                        accepted = listener.accept() => match accepted {
+                            Ok(accepted) => accepted,
+                            Err(e) => {
+                                tracing::warn!("TCP accept error on syslog port {port}: {e}");
+                                continue;
+                            }
+                        },
+                    };
+                    let tx = tx.clone();
+                    tokio::spawn(async move {
+                        let mut reader = BufReader::new(socket);
+                        loop {
+                            match read_frame(&mut reader).await {
+                                Ok(Some(line)) => {
+                                    if tx.send((addr.ip(), line)).await.is_err() {
+                                        break;
+                                    }
+                                }
+                                Ok(None) => break,
+                                Err(e) => {
+                                    tracing::debug!(miner_ip = %addr.ip(), "Dropping syslog connection: {e}");
+                                    break;
+                                }
+                            }
+                        }
+                    });
+                }
+            });
+
+            while let Some((ip, line)) = rx.recv().await {
+                yield Ok(parse_syslog_line(ip, unix_timestamp_secs(), &line));
+            }
+        }
+    }
+}
+
+/// Read one message from a TCP syslog stream, framed either by an octet count
+/// (`<len> <message>`, RFC 6587) or by a trailing newline.
+async fn read_frame<R: AsyncBufRead + Unpin>(reader: &mut R) -> io::Result<Option<String>> {
+    loop {
+        let Some(&first) = reader.fill_buf().await?.first() else {
+            return Ok(None);
+        };
+
+        let mut frame = Vec::new();
+        if first.is_ascii_digit() {
+            (&mut *reader).take(8).read_until(b' ', &mut frame).await?;
+            let len = std::str::from_utf8(&frame)
+                .ok()
+                .and_then(|len| len.trim_end().parse::<usize>().ok())
+                .filter(|len| *len <= MAX_FRAME_LEN)
+                .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, "invalid octet count"))?;
+            frame = vec![0; len];
+            reader.read_exact(&mut frame).await?;
+        } else {
+            (&mut *reader)
+                .take(MAX_FRAME_LEN as u64)
+                .read_until(b'\n', &mut frame)
+                .await?;
+        }
+
+        let line = String::from_utf8_lossy(&frame);
+        let line = line.trim_end_matches(['\r', '\n', '\0']);
+        if !line.is_empty() {
+            return Ok(Some(line.to_string()));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn reads_octet_counted_and_newline_frames() {
+        let input: &[u8] = b"17 <14>bmminer: one\n<14>bmminer: two\n\n<14>bmminer: three";
+        let mut reader = BufReader::new(input);
+
+        assert_eq!(
+            read_frame(&mut reader).await.unwrap().as_deref(),
+            Some("<14>bmminer: one")
+        );
+        assert_eq!(
+            read_frame(&mut reader).await.unwrap().as_deref(),
+            Some("<14>bmminer: two")
+        );
+        assert_eq!(
+            read_frame(&mut reader).await.unwrap().as_deref(),
+            Some("<14>bmminer: three")
+        );
+        assert_eq!(read_frame(&mut reader).await.unwrap(), None);
+    }
+}
@@ -0,0 +1,406 @@
+use std::net::IpAddr;
+
+use asic_rs_core::data::message::{MessageSeverity, MinerComponent};
+
+use super::event::{SyslogEvent, SyslogEventKind};
+
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// Programs whose log lines are classified into [`SyslogEventKind`]s.
+const MINING_PROGRAMS: [&str; 4] = ["bmminer", "cgminer", "bosminer", "btminer"];
+
+#[derive(Debug, Default, PartialEq)]
+struct Header<'a> {
+    priority: Option<u8>,
+    hostname: Option<&'a str>,
+    program: Option<&'a str>,
+    message: &'a str,
+}
+
+/// Split off the `<PRI>` prefix.
+fn split_priority(line: &str) -> (Option<u8>, &str) {
+    let Some(rest) = line.strip_prefix('<') else {
+        return (None, line);
+    };
+    match rest.split_once('>') {
+        Some((pri, rest)) if (1..=3).contains(&pri.len()) => match pri.parse() {
+            Ok(pri) => (Some(pri), rest),
+            Err(_) => (None, line),
+        },
+        _ => (None, line),
+    }
+}
+
+/// Split `tag[pid]: message` into the program name and the message.
+fn split_tag(text: &str) -> Option<(&str, &str)> {
+    let (tag, message) = text
+        .split_once(": ")
+        .or_else(|| text.strip_suffix(':').map(|t| (t, "")))?;
+    let program = tag.split_once('[').map_or(tag, |(program, _)| program);
+    let valid = !program.is_empty()
+        && program
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | '/'));
+    valid.then_some((program, message))
+}
+
+/// Skip RFC 5424 structured data: `-` or one or more `[id key="value"]`
+/// elements, where values may contain escaped quotes and brackets.
+fn skip_structured_data(text: &str) -> &str {
+    if let Some(rest) = text.strip_prefix('-') {
+        return rest.trim_start();
+    }
+    let mut in_value = false;
+    let mut escaped = false;
+    let mut depth = 0;
+    for (idx, c) in text.char_indices() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if in_value => escaped = true,
+            '"' => in_value = !in_value,
+            '[' if !in_value => depth += 1,
+            ']' if !in_value => {
+                depth -= 1;
+                if depth == 0 && !text[idx + 1..].starts_with('[') {
+                    return text[idx + 1..].trim_start();
+                }
+            }
+            _ if depth == 0 => return text,
+            _ => {}
+        }
+    }
+    ""
+}
+
+fn nil(field: &str) -> Option<&str> {
+    (field != "-").then_some(field)
+}
+
+/// Parse an RFC 5424 (`<PRI>1 TIMESTAMP HOST APP PROCID MSGID SD MSG`) or
+/// RFC 3164 (`<PRI>Mmm dd hh:mm:ss HOST TAG: MSG`) header. BusyBox and
+/// OpenWrt forwarders may leave out the hostname. Lines without a
+/// recognisable header are kept whole as the message.
+fn parse_header(line: &str) -> Header<'_> {
+    let (priority, rest) = split_priority(line.trim_end_matches(['\r', '\n', '\0']));
+
+    if let Some(rest) = rest.strip_prefix("1 ") {
+        let mut fields = rest.splitn(6, ' ');
+        let (_timestamp, hostname, app, _pid, _msgid) = (
+            fields.next(),
+            fields.next(),
+            fields.next(),
+            fields.next(),
+            fields.next(),
+        );
+        let message = skip_structured_data(fields.next().unwrap_or_default());
+        return Header {
+            priority,
+            hostname: hostname.and_then(nil),
+            program: app.and_then(nil),
+            message: message.trim_start_matches('\u{feff}'),
+        };
+    }
+
+    let has_timestamp = rest.len() > 16
+        && rest.is_char_boundary(15)
+        && MONTHS.contains(&rest.get(..3).unwrap_or_default())
+        && rest.as_bytes()[15] == b' ';
+    if has_timestamp {
+        let rest = &rest[16..];
+        if let Some((program, message)) = split_tag(rest) {
+            return Header {
+                priority,
+                hostname: None,
+                program: Some(program),
+                message,
+            };
+        }
+        if let Some((hostname, rest)) = rest.split_once(' ') {
+            let (program, message) = split_tag(rest).unzip();
+            return Header {
+                priority,
+                hostname: Some(hostname),
+                program,
+                message: message.unwrap_or(rest),
+            };
+        }
+    }
+
+    match split_tag(rest) {
+        Some((program, message)) => Header {
+            priority,
+            hostname: None,
+            program: Some(program),
+            message,
+        },
+        None => Header {
+            priority,
+            message: rest,
+            ..Default::default()
+        },
+    }
+}
+
+fn severity_from_priority(priority: u8) -> MessageSeverity {
+    match priority & 0x07 {
+        0..=3 => MessageSeverity::Error,
+        4 => MessageSeverity::Warning,
+        _ => MessageSeverity::Info,
+    }
+}
+
+/// Level written into the message itself, e.g. `[ERROR]` or ` WARN `. Most
+/// miners log everything at one syslog priority.
+fn embedded_severity(message: &str) -> Option<MessageSeverity> {
+    message.split_whitespace().take(4).find_map(|word| {
+        match word
+            .trim_matches(|c: char| !c.is_ascii_alphabetic())
+            .to_ascii_lowercase()
+            .as_str()
+        {
+            "error" | "err" | "fatal" | "crit" | "critical" => Some(MessageSeverity::Error),
+            "warn" | "warning" => Some(MessageSeverity::Warning),
+            "info" | "notice" | "debug" | "trace" => Some(MessageSeverity::Info),
+            _ => None,
+        }
+    })
+}
+
+/// Number following `marker`, skipping separators such as `[`, `#` or spaces.
+fn number_after(text: &str, marker: &str) -> Option<u16> {
+    text.match_indices(marker).find_map(|(idx, _)| {
+        let rest = text[idx + marker.len()..].trim_start_matches([' ', '[', '#', ':', '_']);
+        let end = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        rest[..end].parse().ok()
+    })
+}
+
+fn hashboard_index(text: &str) -> Option<u16> {
+    ["chain", "hashboard", "hash board", "slot", "sm"]
+        .iter()
+        .find_map(|marker| number_after(text, marker))
+}
+
+fn pool_url(message: &str) -> Option<String> {
+    message
+        .split_whitespace()
+        .find(|word| word.contains("://"))
+        .map(|url| url.trim_end_matches([',', '.', ';']).to_string())
+}
+
+/// Classify a line logged by bmminer, cgminer, bosminer or btminer.
+fn classify(message: &str) -> (SyslogEventKind, Option<MinerComponent>) {
+    let text = message.to_ascii_lowercase();
+    let has = |needles: &[&str]| needles.iter().any(|needle| text.contains(needle));
+    let failed = has(&["fail", "error", "err_", "fault", "lost", "abnormal"]);
+
+    if has(&[
+        "too hot",
+        "overheat",
+        "over max temp",
+        "temp too high",
+        "temperature too high",
+    ]) || (text.contains("temp") && has(&["protect", "exceed"]))
+    {
+        let component = hashboard_index(&text).map(MinerComponent::hashboard);
+        return (SyslogEventKind::Overheat, component);
+    }
+    if text.contains("fan") && (failed || has(&["stopped", "no fan", "speed low"])) {
+        let component = number_after(&text, "fan").map(MinerComponent::fan);
+        return (SyslogEventKind::FanFailure, component);
+    }
+    if has(&["psu", "power supply", "apw"]) && failed {
+        return (
+            SyslogEventKind::PowerFailure,
+            Some(MinerComponent::power_supply(0)),
+        );
+    }
+    if text.contains("asic") && has(&["only find", "only found"]) {
+        let found = number_after(&text, "only find").or_else(|| number_after(&text, "only found"));
+        if let Some(found) = found {
+            let expected =
+                number_after(&text, "expected").or_else(|| number_after(&text, "expect"));
+            let component = hashboard_index(&text).map(MinerComponent::hashboard);
+            return (
+                SyslogEventKind::ChipCountMismatch { found, expected },
+                component,
+            );
+        }
+    }
+    if has(&["hashboard", "hash board", "chain"])
+        && (failed || has(&["power off", "shut down", "disabled"]))
+    {
+        let component = hashboard_index(&text).map(MinerComponent::hashboard);
+        return (SyslogEventKind::HashboardFailure, component);
+    }
+    if text.contains("reject") && text.contains("share") {
+        return (SyslogEventKind::ShareRejected, None);
+    }
+    if text.contains("pool") || text.contains("stratum") {
+        if has(&[
+            "not responding",
+            "interrupted",
+            "disconnected",
+            "connection lost",
+            "dead",
+        ]) {
+            return (
+                SyslogEventKind::PoolDisconnected {
+                    url: pool_url(message),
+                },
+                None,
+            );
+        }
+        if has(&[
+            "alive",
+            "switching to",
+            "connected to",
+            "connection established",
+        ]) {
+            return (
+                SyslogEventKind::PoolConnected {
+                    url: pool_url(message),
+                },
+                None,
+            );
+        }
+    }
+    if has(&[
+        "started cgminer",
+        "started bmminer",
+        "starting bosminer",
+        "start-up",
+        "btminer start",
+    ]) {
+        return (SyslogEventKind::MinerStarted, None);
+    }
+    (SyslogEventKind::Other, None)
+}
+
+/// Parse one syslog line received from `ip` at `timestamp`.
+pub(crate) fn parse_syslog_line(ip: IpAddr, timestamp: u64, line: &str) -> SyslogEvent {
+    let header = parse_header(line);
+    let message = header.message.trim();
+
+    let mining_program = header
+        .program
+        .is_some_and(|program| MINING_PROGRAMS.iter().any(|known| program.ends_with(known)));
+    let (kind, component) = if mining_program {
+        classify(message)
+    } else {
+        (SyslogEventKind::Other, None)
+    };
+
+    let mut severity = embedded_severity(message)
+        .or_else(|| header.priority.map(severity_from_priority))
+        .unwrap_or(MessageSeverity::Info);
+    let is_fault = matches!(
+        kind,
+        SyslogEventKind::ChipCountMismatch { .. }
+            | SyslogEventKind::HashboardFailure
+            | SyslogEventKind::Overheat
+            | SyslogEventKind::FanFailure
+            | SyslogEventKind::PowerFailure
+    );
+    if is_fault && severity == MessageSeverity::Info {
+        severity = MessageSeverity::Warning;
+    }
+
+    SyslogEvent {
+        ip,
+        timestamp,
+        severity,
+        hostname: header.hostname.map(String::from),
+        program: header.program.map(String::from),
+        message: message.to_string(),
+        kind,
+        component,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(line: &str) -> SyslogEvent {
+        parse_syslog_line(IpAddr::from([10, 0, 0, 5]), 0, line)
+    }
+
+    #[test]
+    fn parses_rfc3164_header() {
+        let event = parse("<13>Oct 18 16:38:47 Antminer bmminer[1234]: Started bmminer 1.0.0");
+        assert_eq!(event.hostname.as_deref(), Some("Antminer"));
+        assert_eq!(event.program.as_deref(), Some("bmminer"));
+        assert_eq!(event.message, "Started bmminer 1.0.0");
+        assert_eq!(event.severity, MessageSeverity::Info);
+        assert_eq!(event.kind, SyslogEventKind::MinerStarted);
+
+        // OpenWrt's logd forwards without a hostname.
+        let event = parse(
+            "<11>Oct  3 01:02:03 bosminer[812]: ERROR hashboard 6: chip initialization failed",
+        );
+        assert_eq!(event.hostname, None);
+        assert_eq!(event.program.as_deref(), Some("bosminer"));
+        assert_eq!(event.severity, MessageSeverity::Error);
+        assert_eq!(event.kind, SyslogEventKind::HashboardFailure);
+        assert_eq!(event.component, Some(MinerComponent::hashboard(6)));
+    }
+
+    #[test]
+    fn parses_rfc5424_header() {
+        let event = parse(
+            r#"<12>1 2026-10-18T16:38:47.000Z miner-01 cgminer 99 - [meta sequenceId="1" note="a \"]\" b"] Pool 0 stratum+tcp://pool.example.com:3333 not responding!"#,
+        );
+        assert_eq!(event.hostname.as_deref(), Some("miner-01"));
+        assert_eq!(event.program.as_deref(), Some("cgminer"));
+        assert_eq!(event.severity, MessageSeverity::Warning);
+        assert_eq!(
+            event.kind,
+            SyslogEventKind::PoolDisconnected {
+                url: Some("stratum+tcp://pool.example.com:3333".to_string())
+            }
+        );
+    }
+
+    #[test]
+    fn classifies_firmware_lines() {
+        let event = parse("<14>Oct 18 16:38:47 bmminer: Chain[1] only find 60 asic, expected 76");
+        assert_eq!(
+            event.kind,
+            SyslogEventKind::ChipCountMismatch {
+                found: 60,
+                expected: Some(76)
+            }
+        );
+        assert_eq!(event.component, Some(MinerComponent::hashboard(1)));
+        assert_eq!(event.severity, MessageSeverity::Warning);
+
+        let event = parse("<14>Oct 18 16:38:47 btminer: ERROR_FAN_LOST fan 2 speed 0");
+        assert_eq!(event.kind, SyslogEventKind::FanFailure);
+        assert_eq!(event.component, Some(MinerComponent::fan(2)));
+
+        let event = parse("<14>Oct 18 16:38:47 btminer: SM1 temp too high, power off");
+        assert_eq!(event.kind, SyslogEventKind::Overheat);
+        assert_eq!(event.component, Some(MinerComponent::hashboard(1)));
+
+        let event =
+            parse("<14>Oct 18 16:38:47 cgminer: Rejected untracked stratum share from pool 0");
+        assert_eq!(event.kind, SyslogEventKind::ShareRejected);
+
+        // Lines from other programs are not classified.
+        let event = parse("<86>Oct 18 16:38:47 dropbear[99]: Exit (root): Disconnect received");
+        assert_eq!(event.kind, SyslogEventKind::Other);
+    }
+
+    #[test]
+    fn keeps_lines_without_header() {
+        let event = parse("just some text");
+        assert_eq!(event.program, None);
+        assert_eq!(event.message, "just some text");
+        assert_eq!(event.kind, SyslogEventKind::Other);
+    }
+}